@@ -0,0 +1,380 @@
+## Engine
+engine-starting-banner = STARTING BOOSTER (No Admin)
+engine-cycle-done = ⏱️  Cycle complete (next: { $seconds }s)
+engine-started = 🚀 Auto Booster is ON
+engine-interval = ⏱️  Boost interval: { $seconds } seconds
+engine-mode-no-admin = ℹ️  Mode: No Admin required
+engine-stopped = ⏸️  Auto Booster is OFF
+engine-watching-config = 👀 Watching config: { $path }
+engine-no-runtime = No tokio runtime to run the boost loop: { $error }
+engine-already-running = The booster is already running
+engine-not-running = The booster is not running
+
+## Active optimizations
+opt-timer-resolution = ⏱️ Timer Resolution 1ms
+opt-memory-cleanup = 🧹 Memory Cleanup ({ $seconds }s)
+opt-auto-detection = 🔍 Auto-Detection
+opt-paused-count = ⏸️ { $count } process(es) paused
+opt-boosted-count = 🚀 { $count } process(es) boosted
+opt-session-count = 🎮 Roblox session: { $count } processes tuned
+
+## Timer resolution
+timer-winmm-failed = ✗ Failed to load winmm.dll
+timer-applied = ✓ Timer Resolution: 1ms (system-wide)
+timer-applied-benefit = ℹ️  Benefit: every app runs smoother
+timer-apply-failed = ✗ Failed to set timer resolution (error: { $code })
+timer-restored = ✓ Timer Resolution restored
+
+## Roblox detection
+detect-none = 🔍 Status: Roblox not detected
+detect-found = 🎮 Roblox detected:
+detect-optimizing = ℹ️  System is optimized for gaming
+detect-session = #{ $instance } { $name } (PID: { $pid }): { $count } processes
+detect-primary = ⭐ primary
+detect-focused = 🎯 focused
+
+## Memory cleanup
+cleanup-done = ✓ Memory Cleanup: RAM freed
+cleanup-done-benefit = ℹ️  More RAM available for Roblox
+cleanup-failed = ✗ Memory cleanup failed: { $error }
+
+## Pause list
+pause-resumed = ▶️  Resumed { $name } (PID: { $pid })
+pause-paused = ⏸️  Paused { $name } (PID: { $pid })
+pause-failed = ✗ Failed to pause { $name } (PID: { $pid })
+
+## Boost / revert
+boost-applied = 🚀 Boosted PID { $pid } (nice { $from } → { $to })
+boost-reverted = ↩️  Reverted PID { $pid } (nice → { $to })
+
+## Process
+process-setpriority-failed = setpriority({ $pid }, { $nice }) failed: { $error }
+process-open-failed = OpenProcess({ $pid }) failed: { $error }
+process-set-class-failed = SetPriorityClass({ $pid }) failed: { $error }
+process-resume-failed = Failed to resume PID { $pid }: { $error }
+process-invalid-cpu-list = Invalid CPU list: '{ $list }' (e.g. 0-3,6)
+process-setaffinity-failed = sched_setaffinity({ $pid }, { $cpus }) failed: { $error }
+thread-invalid-nice = Invalid nice: { $nice } (-20..19)
+thread-invalid-timer-slack = Invalid timer slack: { $value } (1..{ $max } ns)
+thread-timerslack-failed = Failed to set timer slack { $value }ns for TID { $tid }: { $error }
+
+## Dashboard
+dashboard-sampling = 📊 Sampling...
+dashboard-system-cpu = System { $value }%
+dashboard-temperature = Temperature
+dashboard-roblox = 🎮 Roblox:
+dashboard-no-roblox = Roblox not detected
+dashboard-active = ⚡ Active:
+dashboard-booster-off = (booster is off)
+
+## Process explorer
+explorer-filter-placeholder = 🔎 Filter by name or PID...
+explorer-column-name = Name
+explorer-total = { $count } processes (showing at most { $max })
+explorer-tag-roblox = 🎮 Roblox
+explorer-tag-paused = ⏸️ Paused
+explorer-tag-pause-list = ⏸️ Pause list
+explorer-tag-boosted = 🚀 Boosted
+explorer-revert = ↩️ Revert
+explorer-boost = 🚀 Boost
+explorer-boosted = 🚀 Boosted PID { $pid }
+explorer-reverted = ↩️ Reverted PID { $pid }
+explorer-added = ✓ Added { $name } to the { $list } list
+explorer-boost-planned = 🧪 Dry-run, nothing changed: { $action }
+explorer-boost-unavailable = ⚠️  Boosting unavailable: { $reason }
+explorer-tag-session = 🧩 Roblox #{ $instance } (PID { $pid })
+
+## Main screen
+ui-watch-config-failed = ✗ Failed to watch config.json: { $error }
+ui-window-title = Roblox Booster - No Admin Required
+ui-button-stop = STOP AUTO BOOSTER
+ui-button-start = START AUTO BOOSTER
+ui-tip = 💡 Tip: Open Roblox and the app optimizes automatically
+ui-status = AUTO BOOSTER: { $status }
+main-banner-disabled = off
+ui-dry-run-badge = 🧪 DRY-RUN: only logging planned actions, the system is not modified
+opt-thread-count = 🧵 Roblox threads: policy applied to { $count } threads
+opt-instance-count = 🎮 { $instances } Roblox instances (primary: #{ $primary }): { $count } processes tuned
+opt-power-saving = 🔋 Power saving: { $count } Roblox processes, CPU ≤ { $limit }% of one core
+
+## Log panel
+logs-copied = 📋 Copied to clipboard
+logs-saved = 💾 Saved: { $path }
+logs-save-failed = ✗ Failed to save log: { $error }
+logs-filter-placeholder = 🔎 Filter logs...
+logs-level-all = All
+logs-button-save = 💾 SAVE FILE
+
+## Settings
+settings-title = ⚙️ SETTINGS
+settings-general = General:
+settings-auto-start = Auto Start when the app opens
+settings-language = Language:
+settings-features = Features:
+settings-info-no-admin = ℹ️  Features missing a system permission are marked ⚠️ with the reason (see Environment / `roblox_booster doctor`)
+settings-info-system-wide = ℹ️  System-wide optimization for every app
+settings-button-save = 💾 SAVE
+settings-button-cancel = ❌ CANCEL
+settings-login-autostart = Run at login (XDG autostart)
+settings-interval = Boost interval (seconds, 0 = 60):
+settings-interval-invalid = Interval must be a non-negative integer, got: "{ $value }"
+settings-matchers = Process lists:
+settings-roblox-names = 🎮 Treat as Roblox
+settings-pause-names = ⏸️ Pause while playing
+settings-matcher-placeholder = Process name, e.g. sober
+settings-matcher-add = + Add
+settings-matcher-empty = Process name must not be empty
+settings-matcher-duplicate = { $name } is already in the list
+settings-reset-section = ↺ Defaults
+settings-unsaved = ✏️ Unsaved changes
+settings-save-failed = ✗ Failed to save config: { $error }
+settings-dry-run = 🧪 Dry-run (only log planned actions, do not modify the system)
+settings-environment = 🩺 Environment
+settings-profiles-file-only = 📁 Profiles ({ $count }): { $names } (edit `profiles` in config.json, not in Settings)
+settings-session = Roblox session (player, launcher, wineserver):
+settings-roblox-affinity = CPUs for the whole session (empty = unchanged):
+settings-follow-focus = 🎯 Follow the focused window (X11/XWayland): unfocused Roblox counts as a background instance
+settings-multi-instance = Multiple clients at once:
+settings-primary-affinity = CPUs for the primary instance (`fastest` = fastest cores, empty = session CPUs):
+settings-background-affinity = CPUs for background instances (empty = session CPUs):
+settings-background-nice = Nice for background instances (-20..19, empty = same as primary):
+settings-threads = Roblox threads (Linux, empty = unchanged, CPU `fastest` = fastest cores):
+settings-number-invalid = Not a valid number: "{ $value }"
+settings-power-saving = 🔋 Power saving:
+settings-power-enabled = Cap CPU and lower priority of Roblox while unfocused or idle
+settings-power-when-unfocused = When the Roblox window is not focused (needs focus following)
+settings-power-idle-minutes = Idle minutes without input (0 = off):
+settings-power-cpu-limit = CPU limit (% of one core):
+settings-power-nice = Nice while saving power (-20..19):
+settings-value-required = A value is required
+
+## Feature summary
+summary-none = ❌ No features enabled
+summary-features = Features: { $list }
+
+## Language
+language-auto = Automatic (system)
+language-vi = Tiếng Việt
+language-en = English
+
+## Startup
+main-create-dirs-failed = ✗ Failed to create data directories: { $error }
+main-config-migrated = ✓ Copied the old config.json to { $path }
+main-config-migrate-failed = ✗ Failed to migrate the old config.json: { $error }
+main-banner-subtitle = Roblox optimizer for Windows
+main-banner-features = Features:
+main-banner-admin-hint = ⚠️  Recommended: run as Administrator
+
+## Command line
+cli-missing-value = Missing value for { $arg }
+cli-invalid-config-command = Invalid config command: { $command }
+cli-invalid-argument = Invalid argument: { $arg }
+cli-invalid-set = --set expects field=value, got: { $value }
+cli-usage = Usage: roblox_booster [--config <path>] [--set field=value]... [--dry-run] [config show | schema [path] | autostart [status | install [--systemd] | remove] | handler [status | install | remove] | wine [status | apply [profile] | restore] | doctor | plan | recover | launch <uri> | focus [--watch]]
+cli-schema-written = ✓ Schema written: { $path }
+cli-write-failed = ✗ Failed to write { $path }: { $error }
+cli-file-missing = (missing)
+cli-column-source = SOURCE
+cli-column-value = VALUE
+cli-invalid-autostart-command = Invalid autostart command: { $command }
+cli-plan-header = 🧪 Dry-run: actions the booster would take with the current config
+cli-plan-no-change = (no changes)
+cli-doctor-environment = 🩺 Environment
+cli-doctor-capabilities = 🔐 System permissions
+cli-doctor-optimizations = ⚙️  Optimizations
+cli-invalid-handler-command = Invalid handler command: { $command }
+cli-invalid-wine-command = Invalid wine command: { $command }
+
+## Config
+config-interval-too-large = boost_interval_seconds must be <= { $max } (current: { $value })
+config-layer-default = default
+config-layer-error = Config { $layer }: { $error }
+config-layer-skipped = ✗ Ignoring config { $layer }: { $error }
+config-unknown-field = ⚠️  Config { $layer }: ignoring unknown field '{ $field }'
+config-unknown-override = Unknown field: { $field } (valid: { $known })
+config-read-failed = Failed to read { $path }: { $error }
+config-not-object = { $path }: must be a JSON object
+config-invalid-json = { $path }: invalid JSON: { $error }
+config-unknown-field-name = Unknown field: { $field }
+config-profile-unknown-key = profile '{ $profile }': unknown optimization '{ $key }'
+
+## Engine events
+event-config-changed = 🔄 Config updated:
+event-config-rejected = ✗ New config rejected: { $reason }
+
+## Login autostart
+autostart-comment = Start the booster at login
+autostart-unsupported = Login autostart is only supported on Linux (XDG/systemd)
+autostart-status-installed = ✓ Installed:
+autostart-status-missing = ✗ Not installed:
+autostart-installed = ✓ Registered to run at login: { $path }
+autostart-removed = ✓ Login autostart removed
+autostart-failed = ✗ Autostart failed: { $error }
+autostart-booster-on-launch = 🚀 auto_start: starting the booster on launch
+
+## Pages
+page-dashboard = 📊 Dashboard
+page-processes = 🔎 Processes
+page-sessions = 🕹️ Sessions
+page-logs = 📜 Logs
+page-logs-errors = 📜 Logs ({ $count } errors)
+page-settings = ⚙️ Settings
+
+## Sessions page
+sessions-count = { $count } Roblox sessions running
+sessions-empty = No Roblox session is running.
+sessions-title = #{ $instance } { $client } (PID { $pid })
+sessions-primary = ⭐ Primary instance
+sessions-members = { $count } processes: { $members }
+sessions-not-boosted = Not boosted
+session-state-primary = primary instance, { $count } processes
+session-state-background = background instance, { $count } processes
+session-state-power-saving = power saving, { $count } processes
+
+## Booster status
+status-stopped = ⏸️ OFF
+status-starting = ⏳ STARTING...
+status-running = 🚀 RUNNING
+status-stopping = ⏳ STOPPING...
+
+## Optimizations
+optimization-timer_resolution = ⏱️  Timer Resolution (1ms)
+optimization-timer_resolution-hint = Less lag, smoother games
+optimization-memory_cleanup = 🧹 Memory Cleanup (every cycle)
+optimization-memory_cleanup-hint = Frees RAM for Roblox
+optimization-auto_detection = 🔍 Auto-Detection
+optimization-auto_detection-hint = Detects Roblox automatically
+optimization-pause_list = ⏸️  Pause List
+optimization-pause_list-hint = Suspends pause-list apps while Roblox runs
+optimization-windows-only = Windows only
+optimization-unix-only = Linux/macOS only
+optimization-cpu_governor = 🚀 CPU Governor: performance
+optimization-cpu_governor-hint = Switches the cpufreq governor to performance while Roblox runs (needs root via pkexec)
+optimization-sched_autogroup = 🧵 Disable sched autogroup
+optimization-sched_autogroup-hint = Sets kernel.sched_autogroup_enabled = 0 while Roblox runs so nice works across sessions (needs root via pkexec)
+optimization-linux-only = Linux only
+optimization-session_boost = 🎮 Session Boost
+optimization-session_boost-hint = Raise priority (plus affinity/cgroup when available) for the whole Roblox session: player, launcher, wineserver
+optimization-thread_tuning = 🧵 Thread Tuning
+optimization-thread_tuning-hint = Classify Roblox threads (render, worker, audio, network) and set nice/affinity/timer slack from thread_policies, e.g. render on the fastest cores
+
+## Journal
+journal-read-failed = Failed to read journal: { $error }
+journal-write-failed = Failed to write journal: { $error }
+journal-change-nice = nice of { $name } (PID { $pid }) → { $original }
+journal-change-paused = { $name } (PID { $pid }) is suspended
+journal-change-timer = Timer resolution { $period }ms
+journal-process-gone = process has exited
+journal-timer-auto-reverted = Windows reverts it when the process exits
+journal-empty = ✓ Journal is empty, nothing to recover
+journal-recovering = 🔁 { $count } change(s) left over from the previous run, restoring...
+journal-restored = ✓ Restored: { $change }
+journal-skipped = ℹ️  Skipped { $change }: { $reason }
+journal-failed = ✗ Failed to restore { $change }: { $error }
+journal-kept = ⚠️  { $count } change(s) kept in the journal, run `roblox_booster recover` to retry
+journal-change-setting = { $setting } → { $previous }
+journal-setting-unchanged = already at the previous value
+journal-change-affinity = CPU affinity of { $name } (PID { $pid }) → { $original }
+journal-change-cgroup = { $name } (PID { $pid }) → cgroup { $original }
+journal-change-thread = { $knob } of thread { $name } (PID { $pid }, TID { $tid }) = { $original }
+journal-locked = Another booster instance is running (holding { $path })
+journal-lock-failed = Failed to create the booster lock: { $error }
+journal-recover-skipped = Skipping journal recovery: { $reason }
+journal-not-owner = Another booster is running and owns the journal, not applying changes
+journal-launch-skipped = Skipping pre-launch optimizations: { $reason }
+
+## Dry-run
+dryrun-enabled = 🧪 DRY-RUN mode: only logging planned actions, the system is not modified
+dryrun-planned = 🧪 [DRY-RUN] { $name }: { $action }
+dryrun-no-change = 🧪 [DRY-RUN] { $name }: no changes
+dryrun-manual-boost = Manual boost
+plan-timer-target = Timer resolution (system-wide)
+plan-timer-default = Windows default (~15.6ms)
+plan-cleanup-after = working set trimmed (EmptyWorkingSet)
+plan-detect-untracked = not tracked
+plan-detect-tracked = tracked
+plan-process-running = running
+plan-process-paused = suspended (SIGSTOP)
+plan-session-tuned = tuned
+plan-session-restored = restored
+
+## Capabilities
+cap-sys-nice = Raise priority (nice < 0)
+cap-kill = Signal other users' processes
+cap-cgroup = cgroup v2 delegation (cpu)
+cap-cpufreq = Write cpufreq governor (sysfs)
+cap-unknown = unknown capability
+cap-linux-only = only probed on Linux
+cap-sys-nice-missing = missing CAP_SYS_NICE, RLIMIT_NICE = { $limit } (needs >= 25, see /etc/security/limits.conf)
+cap-kill-missing = missing CAP_KILL, only the current user's processes can be signalled
+cap-cgroup-no-v2 = cgroup v2 not mounted (/sys/fs/cgroup/cgroup.controllers)
+cap-cgroup-unknown = cannot read the process cgroup
+cap-cgroup-no-cpu = cpu controller not delegated to { $path }
+cap-cpufreq-missing = cpufreq not available (scaling_governor)
+cap-not-writable = { $path } is not writable
+cap-requirement-missing = missing { $capability }: { $reason }
+cap-helper = Privileged helper (pkexec)
+cap-focus = Focus tracking (X11/XWayland)
+
+## Privileged helper
+helper-not-installed = roblox_booster_helper is not installed (/usr/libexec/roblox_booster/ or next to the app binary)
+helper-no-pkexec = pkexec (polkit) not found
+helper-failed = Privileged helper failed: { $error }
+helper-auth-failed = Polkit authentication was cancelled or denied
+setting-applied = 🚀 { $setting }: { $from } → { $to }
+setting-restored = ↩️  { $setting }: restored to { $value }
+
+## Launcher
+launch-invalid-uri = Invalid URI (expected roblox-player: or roblox://): { $uri }
+launch-request = 🚀 Launch: { $request }
+launch-profile = 🎛️ Using profile: { $profile }
+launch-client = 🎮 Client: { $client }
+launch-no-client = No Roblox client found (Sober via flatpak, Wine or the Windows client)
+launch-studio-unsupported = Roblox Studio (launchmode:edit) is not supported
+launch-spawn-failed = Failed to start { $client }: { $error }
+launch-client-exited = ℹ️ Roblox client exited
+
+## URI handler
+handler-comment = Open Roblox through the booster (applies boosts before the game starts)
+handler-unsupported = Registering as URI handler is only supported on Linux (XDG)
+handler-installed = ✓ Registered as roblox-player: / roblox: handler: { $path }
+handler-removed = ✓ URI handler removed, previous handler restored
+handler-failed = ✗ URI handler failed: { $error }
+handler-forward-to = → forwards to: { $handler }
+
+## Wine
+wine-unknown-registry = unsupported registry setting '{ $name }' (csmt, renderer, video_memory_size, mouse_warp_override, grab_fullscreen, use_take_focus)
+wine-invalid-value = invalid value for '{ $name }': '{ $value }'
+wine-write-failed = Failed to write { $path }: { $error }
+wine-server-running = wineserver of prefix { $path } is running, close Roblox/Wine before editing the prefix
+wine-prefix-no-roblox = No Roblox install found in Wine prefix { $path }
+wine-no-prefix = ℹ️ No Wine prefix with Roblox found
+wine-profile-not-found = Profile not found
+wine-status-modified = ✏️ Modified by the booster (backed up, undo with `wine restore`)
+wine-no-change = ✓ Already up to date
+wine-file-updated = ✓ Updated { $path }
+wine-file-restored = ✓ Restored { $path }
+
+## Session
+session-tuned = 🎮 Tuned instance #{ $instance } { $name } (PID { $pid }): { $count } processes
+session-reverted = ↩️  Restored priority/affinity/cgroup of the Roblox session
+cgroup-failed = cgroup { $path }: { $error }
+cgroup-move-failed = Failed to move PID { $pid } into { $path }: { $error }
+threads-tuned = 🧵 Applied thread policy to { $count } threads of PID { $pid }
+threads-reverted = ↩️  Restored nice/affinity/timer slack of Roblox threads
+session-tuned-background = 🌙 Background instance #{ $instance } { $name } (PID { $pid }): lowered priority of { $count } processes
+session-tuned-power-saving = 🔋 Power saving for instance #{ $instance } { $name } (PID { $pid }): { $count } processes, CPU ≤ { $limit }% of one core
+power-invalid-cpu-limit = Invalid CPU limit: { $value } (must be ≥ 1%)
+power-idle = 💤 No input for { $minutes } minutes → switching Roblox to power saving
+power-active = ⌨️  User is back → restoring Roblox
+
+## Focus
+focus-unavailable = Focus tracking unavailable: { $error }
+focus-lost = Lost connection to the X server, focus tracking stopped: { $error }
+focus-unsupported = Focus tracking is only supported on X11/XWayland
+focus-unknown = ❔ Focused window unknown (no X11 or a native Wayland window)
+focus-no-window = ⬜ No window is focused
+focus-window-no-pid = ⬜ Window { $window } has no _NET_WM_PID
+focus-roblox = 🎯 Window { $window }: { $name } (PID { $pid }) → Roblox instance #{ $instance }
+focus-other = ⬜ Window { $window }: { $name } (PID { $pid }), not Roblox
+idle-unavailable = Cannot read idle time (MIT-SCREEN-SAVER): { $error }
@@ -0,0 +1,380 @@
+## Engine
+engine-starting-banner = ĐANG KHỞI ĐỘNG BOOSTER (No Admin)
+engine-cycle-done = ⏱️  Cycle hoàn tất (next: { $seconds }s)
+engine-started = 🚀 Auto Booster đã BẬT
+engine-interval = ⏱️  Boost interval: { $seconds } giây
+engine-mode-no-admin = ℹ️  Chế độ: Không cần Admin
+engine-stopped = ⏸️  Auto Booster đã TẮT
+engine-watching-config = 👀 Đang theo dõi config: { $path }
+engine-no-runtime = Không có tokio runtime để chạy boost loop: { $error }
+engine-already-running = Booster đang chạy
+engine-not-running = Booster không chạy
+
+## Tối ưu đang áp dụng
+opt-timer-resolution = ⏱️ Timer Resolution 1ms
+opt-memory-cleanup = 🧹 Memory Cleanup ({ $seconds }s)
+opt-auto-detection = 🔍 Auto-Detection
+opt-paused-count = ⏸️ Tạm dừng { $count } process
+opt-boosted-count = 🚀 Boost { $count } process
+opt-session-count = 🎮 Session Roblox: đã tối ưu { $count } process
+
+## Timer resolution
+timer-winmm-failed = ✗ Không load được winmm.dll
+timer-applied = ✓ Timer Resolution: 1ms (system-wide)
+timer-applied-benefit = ℹ️  Benefit: Mọi app đều mượt hơn
+timer-apply-failed = ✗ Không set được timer resolution (error: { $code })
+timer-restored = ✓ Timer Resolution đã restore
+
+## Phát hiện Roblox
+detect-none = 🔍 Status: Không phát hiện Roblox
+detect-found = 🎮 Phát hiện Roblox:
+detect-optimizing = ℹ️  System đang được tối ưu cho gaming
+detect-session = #{ $instance } { $name } (PID: { $pid }): { $count } process
+detect-primary = ⭐ chính
+detect-focused = 🎯 đang focus
+
+## Memory cleanup
+cleanup-done = ✓ Memory Cleanup: Đã giải phóng RAM
+cleanup-done-benefit = ℹ️  RAM available tăng lên cho Roblox
+cleanup-failed = ✗ Memory cleanup thất bại: { $error }
+
+## Pause list
+pause-resumed = ▶️  Chạy lại { $name } (PID: { $pid })
+pause-paused = ⏸️  Tạm dừng { $name } (PID: { $pid })
+pause-failed = ✗ Không tạm dừng được { $name } (PID: { $pid })
+
+## Boost / revert
+boost-applied = 🚀 Boost PID { $pid } (nice { $from } → { $to })
+boost-reverted = ↩️  Revert PID { $pid } (nice → { $to })
+
+## Process
+process-setpriority-failed = setpriority({ $pid }, { $nice }) thất bại: { $error }
+process-open-failed = OpenProcess({ $pid }) thất bại: { $error }
+process-set-class-failed = SetPriorityClass({ $pid }) thất bại: { $error }
+process-resume-failed = Không chạy lại được PID { $pid }: { $error }
+process-invalid-cpu-list = Danh sách CPU không hợp lệ: '{ $list }' (vd 0-3,6)
+process-setaffinity-failed = sched_setaffinity({ $pid }, { $cpus }) thất bại: { $error }
+thread-invalid-nice = Nice không hợp lệ: { $nice } (-20..19)
+thread-invalid-timer-slack = Timer slack không hợp lệ: { $value } (1..{ $max } ns)
+thread-timerslack-failed = Không đặt được timer slack { $value }ns cho TID { $tid }: { $error }
+
+## Dashboard
+dashboard-sampling = 📊 Đang lấy mẫu...
+dashboard-system-cpu = Hệ thống { $value }%
+dashboard-temperature = Nhiệt độ
+dashboard-roblox = 🎮 Roblox:
+dashboard-no-roblox = Không phát hiện Roblox
+dashboard-active = ⚡ Đang áp dụng:
+dashboard-booster-off = (booster đang tắt)
+
+## Process explorer
+explorer-filter-placeholder = 🔎 Lọc theo tên hoặc PID...
+explorer-column-name = Tên
+explorer-total = { $count } process (hiển thị tối đa { $max })
+explorer-tag-roblox = 🎮 Roblox
+explorer-tag-paused = ⏸️ Đang dừng
+explorer-tag-pause-list = ⏸️ Pause list
+explorer-tag-boosted = 🚀 Boosted
+explorer-revert = ↩️ Revert
+explorer-boost = 🚀 Boost
+explorer-boosted = 🚀 Đã boost PID { $pid }
+explorer-reverted = ↩️ Đã revert PID { $pid }
+explorer-added = ✓ Đã thêm { $name } vào list { $list }
+explorer-boost-planned = 🧪 Dry-run, không thay đổi: { $action }
+explorer-boost-unavailable = ⚠️  Không boost được: { $reason }
+explorer-tag-session = 🧩 Roblox #{ $instance } (PID { $pid })
+
+## Màn hình chính
+ui-watch-config-failed = ✗ Không theo dõi được config.json: { $error }
+ui-window-title = Roblox Booster - Không cần Admin
+ui-button-stop = TẮT AUTO BOOSTER
+ui-button-start = BẬT AUTO BOOSTER
+ui-tip = 💡 Tip: Mở Roblox và app sẽ tự tối ưu
+ui-status = AUTO BOOSTER: { $status }
+main-banner-disabled = tắt
+ui-dry-run-badge = 🧪 DRY-RUN: chỉ log hành động dự kiến, không thay đổi hệ thống
+opt-thread-count = 🧵 Thread Roblox: đã áp dụng policy cho { $count } thread
+opt-instance-count = 🎮 { $instances } instance Roblox (chính: #{ $primary }): đã tối ưu { $count } process
+opt-power-saving = 🔋 Tiết kiệm pin: { $count } process của Roblox, CPU ≤ { $limit }% một core
+
+## Log panel
+logs-copied = 📋 Đã copy vào clipboard
+logs-saved = 💾 Đã lưu: { $path }
+logs-save-failed = ✗ Không lưu được log: { $error }
+logs-filter-placeholder = 🔎 Lọc log...
+logs-level-all = Tất cả
+logs-button-save = 💾 LƯU FILE
+
+## Settings
+settings-title = ⚙️ CÀI ĐẶT
+settings-general = Chung:
+settings-auto-start = Auto Start khi mở app
+settings-language = Ngôn ngữ:
+settings-features = Tính Năng:
+settings-info-no-admin = ℹ️  Tính năng thiếu quyền hệ thống được đánh dấu ⚠️ kèm lý do (xem Môi trường / `roblox_booster doctor`)
+settings-info-system-wide = ℹ️  Tối ưu system-wide cho mọi app
+settings-button-save = 💾 LƯU
+settings-button-cancel = ❌ HỦY
+settings-login-autostart = Chạy khi đăng nhập (XDG autostart)
+settings-interval = Chu kỳ boost (giây, 0 = 60):
+settings-interval-invalid = Chu kỳ phải là số nguyên không âm, nhận được: "{ $value }"
+settings-matchers = Danh sách process:
+settings-roblox-names = 🎮 Coi như Roblox
+settings-pause-names = ⏸️ Tạm dừng khi chơi
+settings-matcher-placeholder = Tên process, vd sober
+settings-matcher-add = + Thêm
+settings-matcher-empty = Tên process không được rỗng
+settings-matcher-duplicate = { $name } đã có trong list
+settings-reset-section = ↺ Mặc định
+settings-unsaved = ✏️ Có thay đổi chưa lưu
+settings-save-failed = ✗ Không lưu được config: { $error }
+settings-dry-run = 🧪 Dry-run (chỉ log hành động dự kiến, không thay đổi hệ thống)
+settings-environment = 🩺 Môi trường
+settings-profiles-file-only = 📁 Profile ({ $count }): { $names } (sửa `profiles` trong config.json, không sửa trong Cài đặt)
+settings-session = Session Roblox (player, launcher, wineserver):
+settings-roblox-affinity = CPU cho cả session (rỗng = không đổi):
+settings-follow-focus = 🎯 Theo cửa sổ đang focus (X11/XWayland): Roblox không focus bị coi là instance nền
+settings-multi-instance = Nhiều client cùng lúc:
+settings-primary-affinity = CPU cho instance chính (`fastest` = core nhanh nhất, rỗng = CPU của session):
+settings-background-affinity = CPU cho instance nền (rỗng = CPU của session):
+settings-background-nice = Nice cho instance nền (-20..19, rỗng = như instance chính):
+settings-threads = Thread Roblox (Linux, rỗng = không đổi, CPU `fastest` = core nhanh nhất):
+settings-number-invalid = Không phải số hợp lệ: "{ $value }"
+settings-power-saving = 🔋 Tiết kiệm pin:
+settings-power-enabled = Giới hạn CPU và hạ priority của Roblox khi không focus hoặc AFK
+settings-power-when-unfocused = Khi cửa sổ Roblox không được focus (cần theo dõi focus)
+settings-power-idle-minutes = Số phút không thao tác (0 = tắt):
+settings-power-cpu-limit = Giới hạn CPU (% một core):
+settings-power-nice = Nice khi tiết kiệm pin (-20..19):
+settings-value-required = Không được để trống
+
+## Tóm tắt tính năng
+summary-none = ❌ Không có feature nào được bật
+summary-features = Features: { $list }
+
+## Ngôn ngữ
+language-auto = Tự động (theo hệ thống)
+language-vi = Tiếng Việt
+language-en = English
+
+## Khởi động
+main-create-dirs-failed = ✗ Không tạo được thư mục dữ liệu: { $error }
+main-config-migrated = ✓ Đã copy config.json cũ sang { $path }
+main-config-migrate-failed = ✗ Không migrate được config.json cũ: { $error }
+main-banner-subtitle = Tối ưu hóa Roblox cho Windows
+main-banner-features = Tính năng:
+main-banner-admin-hint = ⚠️  Khuyến nghị: Chạy với quyền Administrator
+
+## Dòng lệnh
+cli-missing-value = Thiếu giá trị cho { $arg }
+cli-invalid-config-command = Lệnh config không hợp lệ: { $command }
+cli-invalid-argument = Tham số không hợp lệ: { $arg }
+cli-invalid-set = --set cần dạng field=value, nhận được: { $value }
+cli-usage = Cách dùng: roblox_booster [--config <path>] [--set field=value]... [--dry-run] [config show | schema [path] | autostart [status | install [--systemd] | remove] | handler [status | install | remove] | wine [status | apply [profile] | restore] | doctor | plan | recover | launch <uri> | focus [--watch]]
+cli-schema-written = ✓ Đã ghi schema: { $path }
+cli-write-failed = ✗ Không ghi được { $path }: { $error }
+cli-file-missing = (không có)
+cli-column-source = NGUỒN
+cli-column-value = GIÁ TRỊ
+cli-invalid-autostart-command = Lệnh autostart không hợp lệ: { $command }
+cli-plan-header = 🧪 Dry-run: các hành động booster sẽ làm với config hiện tại
+cli-plan-no-change = (không thay đổi gì)
+cli-doctor-environment = 🩺 Môi trường
+cli-doctor-capabilities = 🔐 Quyền hệ thống
+cli-doctor-optimizations = ⚙️  Optimization
+cli-invalid-handler-command = Lệnh handler không hợp lệ: { $command }
+cli-invalid-wine-command = Lệnh wine không hợp lệ: { $command }
+
+## Config
+config-interval-too-large = boost_interval_seconds phải <= { $max } (hiện tại: { $value })
+config-layer-default = mặc định
+config-layer-error = Config { $layer }: { $error }
+config-layer-skipped = ✗ Bỏ qua config { $layer }: { $error }
+config-unknown-field = ⚠️  Config { $layer }: bỏ qua field không biết '{ $field }'
+config-unknown-override = Field không tồn tại: { $field } (hợp lệ: { $known })
+config-read-failed = Không đọc được { $path }: { $error }
+config-not-object = { $path }: phải là JSON object
+config-invalid-json = { $path }: JSON không hợp lệ: { $error }
+config-unknown-field-name = Field không tồn tại: { $field }
+config-profile-unknown-key = profile '{ $profile }': không có optimization '{ $key }'
+
+## Sự kiện engine
+event-config-changed = 🔄 Config đã cập nhật:
+event-config-rejected = ✗ Config mới bị từ chối: { $reason }
+
+## Chạy khi đăng nhập
+autostart-comment = Tự bật booster khi đăng nhập
+autostart-unsupported = Autostart khi đăng nhập chỉ hỗ trợ Linux (XDG/systemd)
+autostart-status-installed = ✓ Đã đăng ký:
+autostart-status-missing = ✗ Chưa đăng ký:
+autostart-installed = ✓ Đã đăng ký chạy khi đăng nhập: { $path }
+autostart-removed = ✓ Đã gỡ autostart khi đăng nhập
+autostart-failed = ✗ Autostart thất bại: { $error }
+autostart-booster-on-launch = 🚀 auto_start: bật booster ngay khi mở app
+
+## Trang
+page-dashboard = 📊 Dashboard
+page-processes = 🔎 Process
+page-sessions = 🕹️ Phiên chơi
+page-logs = 📜 Log
+page-logs-errors = 📜 Log ({ $count } lỗi)
+page-settings = ⚙️ Cài đặt
+
+## Trang Phiên chơi
+sessions-count = { $count } phiên Roblox đang chạy
+sessions-empty = Không có phiên Roblox nào đang chạy.
+sessions-title = #{ $instance } { $client } (PID { $pid })
+sessions-primary = ⭐ Instance chính
+sessions-members = { $count } process: { $members }
+sessions-not-boosted = Chưa được boost
+session-state-primary = instance chính, { $count } process
+session-state-background = instance nền, { $count } process
+session-state-power-saving = tiết kiệm pin, { $count } process
+
+## Trạng thái booster
+status-stopped = ⏸️ TẮT
+status-starting = ⏳ ĐANG BẬT...
+status-running = 🚀 ĐANG CHẠY
+status-stopping = ⏳ ĐANG TẮT...
+
+## Optimization
+optimization-timer_resolution = ⏱️  Timer Resolution (1ms)
+optimization-timer_resolution-hint = Giảm lag, game mượt hơn
+optimization-memory_cleanup = 🧹 Memory Cleanup (mỗi chu kỳ)
+optimization-memory_cleanup-hint = Giải phóng RAM cho Roblox
+optimization-auto_detection = 🔍 Auto-Detection
+optimization-auto_detection-hint = Tự động phát hiện Roblox
+optimization-pause_list = ⏸️  Pause List
+optimization-pause_list-hint = Tạm dừng app trong pause list khi Roblox chạy
+optimization-windows-only = chỉ hỗ trợ Windows
+optimization-unix-only = chỉ hỗ trợ Linux/macOS
+optimization-cpu_governor = 🚀 CPU Governor: performance
+optimization-cpu_governor-hint = Chuyển cpufreq governor sang performance khi Roblox chạy (cần root qua pkexec)
+optimization-sched_autogroup = 🧵 Tắt sched autogroup
+optimization-sched_autogroup-hint = kernel.sched_autogroup_enabled = 0 khi Roblox chạy để nice có tác dụng giữa các session (cần root qua pkexec)
+optimization-linux-only = chỉ hỗ trợ Linux
+optimization-session_boost = 🎮 Session Boost
+optimization-session_boost-hint = Tăng priority (và affinity/cgroup nếu có) cho cả session Roblox: player, launcher, wineserver
+optimization-thread_tuning = 🧵 Thread Tuning
+optimization-thread_tuning-hint = Phân loại thread của Roblox (render, worker, audio, network) và đặt nice/affinity/timer slack theo thread_policies, vd render ở core nhanh nhất
+
+## Journal
+journal-read-failed = Không đọc được journal: { $error }
+journal-write-failed = Không ghi được journal: { $error }
+journal-change-nice = nice của { $name } (PID { $pid }) → { $original }
+journal-change-paused = { $name } (PID { $pid }) đang bị tạm dừng
+journal-change-timer = Timer resolution { $period }ms
+journal-process-gone = process đã thoát
+journal-timer-auto-reverted = Windows tự hoàn tác khi process thoát
+journal-empty = ✓ Journal trống, không có gì cần khôi phục
+journal-recovering = 🔁 Còn { $count } thay đổi chưa hoàn tác từ lần chạy trước, đang khôi phục...
+journal-restored = ✓ Đã khôi phục: { $change }
+journal-skipped = ℹ️  Bỏ qua { $change }: { $reason }
+journal-failed = ✗ Không khôi phục được { $change }: { $error }
+journal-kept = ⚠️  { $count } thay đổi vẫn còn trong journal, chạy `roblox_booster recover` để thử lại
+journal-change-setting = { $setting } → { $previous }
+journal-setting-unchanged = đã đúng giá trị cũ
+journal-change-affinity = CPU affinity của { $name } (PID { $pid }) → { $original }
+journal-change-cgroup = { $name } (PID { $pid }) → cgroup { $original }
+journal-change-thread = { $knob } thread { $name } (PID { $pid }, TID { $tid }) = { $original }
+journal-locked = Booster khác đang chạy (đang giữ { $path })
+journal-lock-failed = Không tạo được khóa booster: { $error }
+journal-recover-skipped = Bỏ qua khôi phục journal: { $reason }
+journal-not-owner = Booster khác đang chạy và giữ journal, không áp dụng thay đổi
+journal-launch-skipped = Bỏ qua tối ưu trước khi launch: { $reason }
+
+## Dry-run
+dryrun-enabled = 🧪 Chế độ DRY-RUN: chỉ log hành động dự kiến, không thay đổi hệ thống
+dryrun-planned = 🧪 [DRY-RUN] { $name }: { $action }
+dryrun-no-change = 🧪 [DRY-RUN] { $name }: không thay đổi gì
+dryrun-manual-boost = Boost thủ công
+plan-timer-target = Timer resolution (toàn hệ thống)
+plan-timer-default = mặc định của Windows (~15.6ms)
+plan-cleanup-after = dọn working set (EmptyWorkingSet)
+plan-detect-untracked = chưa theo dõi
+plan-detect-tracked = theo dõi
+plan-process-running = đang chạy
+plan-process-paused = tạm dừng (SIGSTOP)
+plan-session-tuned = đã tối ưu
+plan-session-restored = khôi phục như cũ
+
+## Quyền hệ thống
+cap-sys-nice = Tăng priority (nice < 0)
+cap-kill = Signal process của user khác
+cap-cgroup = cgroup v2 delegation (cpu)
+cap-cpufreq = Ghi cpufreq governor (sysfs)
+cap-unknown = capability không xác định
+cap-linux-only = chỉ kiểm tra được trên Linux
+cap-sys-nice-missing = thiếu CAP_SYS_NICE, RLIMIT_NICE = { $limit } (cần >= 25, xem /etc/security/limits.conf)
+cap-kill-missing = thiếu CAP_KILL, chỉ signal được process của user hiện tại
+cap-cgroup-no-v2 = không có cgroup v2 (/sys/fs/cgroup/cgroup.controllers)
+cap-cgroup-unknown = không đọc được cgroup của process
+cap-cgroup-no-cpu = controller cpu chưa được delegate cho { $path }
+cap-cpufreq-missing = không có cpufreq (scaling_governor)
+cap-not-writable = không có quyền ghi { $path }
+cap-requirement-missing = thiếu { $capability }: { $reason }
+cap-helper = Privileged helper (pkexec)
+cap-focus = Theo dõi cửa sổ focus (X11/XWayland)
+
+## Privileged helper
+helper-not-installed = chưa cài roblox_booster_helper (/usr/libexec/roblox_booster/ hoặc cạnh binary app)
+helper-no-pkexec = không tìm thấy pkexec (polkit)
+helper-failed = Privileged helper lỗi: { $error }
+helper-auth-failed = Xác thực polkit bị hủy hoặc không được phép
+setting-applied = 🚀 { $setting }: { $from } → { $to }
+setting-restored = ↩️  { $setting }: trả về { $value }
+
+## Launcher
+launch-invalid-uri = URI không hợp lệ (cần roblox-player: hoặc roblox://): { $uri }
+launch-request = 🚀 Launch: { $request }
+launch-profile = 🎛️ Dùng profile: { $profile }
+launch-client = 🎮 Client: { $client }
+launch-no-client = Không tìm thấy Roblox client (Sober qua flatpak, Wine hoặc bản Windows)
+launch-studio-unsupported = Roblox Studio (launchmode:edit) chưa được hỗ trợ
+launch-spawn-failed = Không chạy được { $client }: { $error }
+launch-client-exited = ℹ️ Roblox client đã thoát
+
+## URI handler
+handler-comment = Mở Roblox qua booster (áp dụng boost trước khi game chạy)
+handler-unsupported = Đăng ký URI handler chỉ hỗ trợ Linux (XDG)
+handler-installed = ✓ Đã đăng ký handler cho roblox-player: / roblox: { $path }
+handler-removed = ✓ Đã gỡ URI handler, khôi phục handler cũ
+handler-failed = ✗ URI handler lỗi: { $error }
+handler-forward-to = → forward tới: { $handler }
+
+## Wine
+wine-unknown-registry = registry '{ $name }' không được hỗ trợ (csmt, renderer, video_memory_size, mouse_warp_override, grab_fullscreen, use_take_focus)
+wine-invalid-value = giá trị không hợp lệ cho '{ $name }': '{ $value }'
+wine-write-failed = Không ghi được { $path }: { $error }
+wine-server-running = wineserver của prefix { $path } đang chạy, đóng Roblox/Wine trước khi sửa prefix
+wine-prefix-no-roblox = Không tìm thấy Roblox trong Wine prefix { $path }
+wine-no-prefix = ℹ️ Không tìm thấy Wine prefix nào có Roblox
+wine-profile-not-found = Không tìm thấy profile
+wine-status-modified = ✏️ Đã được booster chỉnh (có backup, khôi phục bằng `wine restore`)
+wine-no-change = ✓ Không cần thay đổi
+wine-file-updated = ✓ Đã cập nhật { $path }
+wine-file-restored = ✓ Đã khôi phục { $path }
+
+## Session
+session-tuned = 🎮 Đã tối ưu instance #{ $instance } { $name } (PID { $pid }): { $count } process
+session-reverted = ↩️  Đã khôi phục priority/affinity/cgroup của session Roblox
+cgroup-failed = cgroup { $path }: { $error }
+cgroup-move-failed = Không chuyển được PID { $pid } vào { $path }: { $error }
+threads-tuned = 🧵 Đã áp dụng policy cho { $count } thread của PID { $pid }
+threads-reverted = ↩️  Đã khôi phục nice/affinity/timer slack của thread Roblox
+session-tuned-background = 🌙 Instance nền #{ $instance } { $name } (PID { $pid }): đã hạ priority { $count } process
+session-tuned-power-saving = 🔋 Tiết kiệm pin instance #{ $instance } { $name } (PID { $pid }): { $count } process, CPU ≤ { $limit }% một core
+power-invalid-cpu-limit = Giới hạn CPU không hợp lệ: { $value } (phải ≥ 1%)
+power-idle = 💤 Không thao tác { $minutes } phút → chuyển Roblox sang chế độ tiết kiệm pin
+power-active = ⌨️  Người dùng đã quay lại → khôi phục Roblox
+
+## Focus
+focus-unavailable = Không theo dõi được cửa sổ focus: { $error }
+focus-lost = Mất kết nối X server, ngừng theo dõi focus: { $error }
+focus-unsupported = Theo dõi focus chỉ hỗ trợ X11/XWayland
+focus-unknown = ❔ Không biết cửa sổ nào đang focus (không có X11 hoặc cửa sổ Wayland native)
+focus-no-window = ⬜ Không có cửa sổ nào đang focus
+focus-window-no-pid = ⬜ Cửa sổ { $window } không có _NET_WM_PID
+focus-roblox = 🎯 Cửa sổ { $window }: { $name } (PID { $pid }) → Roblox instance #{ $instance }
+focus-other = ⬜ Cửa sổ { $window }: { $name } (PID { $pid }), không phải Roblox
+idle-unavailable = Không đọc được thời gian không thao tác (MIT-SCREEN-SAVER): { $error }
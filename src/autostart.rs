@@ -0,0 +1,265 @@
+use crate::i18n::t;
+use crate::paths::AppPaths;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const DESKTOP_FILE_NAME: &str = "roblox_booster.desktop";
+const SYSTEMD_UNIT_NAME: &str = "roblox_booster.service";
+/// Target mà systemd user unit gắn vào (chỉ chạy khi có phiên đồ họa)
+const SYSTEMD_TARGET: &str = "graphical-session.target";
+
+/// Cách đăng ký chạy khi đăng nhập
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutostartKind {
+    /// XDG autostart: `~/.config/autostart/*.desktop` (mọi desktop environment)
+    Desktop,
+    /// systemd user unit gắn vào `graphical-session.target`
+    Systemd,
+}
+
+/// Vị trí các file autostart (Linux/XDG)
+///
+/// Chỉ ghi một trong hai loại, tránh app bị mở hai lần khi đăng nhập.
+#[derive(Debug, Clone)]
+pub struct Autostart {
+    pub desktop_file: PathBuf,
+    pub systemd_unit: PathBuf,
+    /// Symlink trong `<target>.wants/` (tương đương `systemctl --user enable`)
+    pub systemd_wants_link: PathBuf,
+    /// Binary được chạy khi đăng nhập
+    pub exec: PathBuf,
+}
+
+impl Autostart {
+    /// Paths theo thư mục config của user (`$XDG_CONFIG_HOME`) và binary đang chạy
+    pub fn resolve(app_paths: &AppPaths) -> io::Result<Self> {
+        Ok(Self::with_exec(app_paths, std::env::current_exe()?))
+    }
+
+    /// `app_paths` có thể resolve từ HOME tạm (xem `AppPaths::resolve_with`)
+    pub fn with_exec(app_paths: &AppPaths, exec: PathBuf) -> Self {
+        // config_dir = <config base>/roblox_booster
+        let config_base = app_paths
+            .config_dir
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| app_paths.config_dir.clone());
+        let systemd_dir = config_base.join("systemd").join("user");
+
+        Self {
+            desktop_file: config_base.join("autostart").join(DESKTOP_FILE_NAME),
+            systemd_unit: systemd_dir.join(SYSTEMD_UNIT_NAME),
+            systemd_wants_link: systemd_dir
+                .join(format!("{}.wants", SYSTEMD_TARGET))
+                .join(SYSTEMD_UNIT_NAME),
+            exec,
+        }
+    }
+
+    /// Các loại autostart đang được đăng ký
+    pub fn installed(&self) -> Vec<AutostartKind> {
+        let mut kinds = Vec::new();
+        if self.desktop_file.is_file() {
+            kinds.push(AutostartKind::Desktop);
+        }
+        if self.systemd_unit.is_file() {
+            kinds.push(AutostartKind::Systemd);
+        }
+        kinds
+    }
+
+    pub fn is_installed(&self) -> bool {
+        !self.installed().is_empty()
+    }
+
+    /// Ghi file autostart, gỡ loại còn lại nếu có
+    pub fn install(&self, kind: AutostartKind) -> io::Result<()> {
+        if !cfg!(all(unix, not(target_os = "macos"))) {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, t!("autostart-unsupported")));
+        }
+        self.remove()?;
+        match kind {
+            AutostartKind::Desktop => write_file(&self.desktop_file, &self.desktop_entry()),
+            AutostartKind::Systemd => {
+                write_file(&self.systemd_unit, &self.systemd_unit_contents())?;
+                link_unit(&self.systemd_unit, &self.systemd_wants_link)
+            }
+        }
+    }
+
+    /// Gỡ mọi file autostart (file không tồn tại → bỏ qua)
+    pub fn remove(&self) -> io::Result<()> {
+        for path in [&self.desktop_file, &self.systemd_wants_link, &self.systemd_unit] {
+            match fs::remove_file(path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Nội dung file `.desktop` (XDG Desktop Entry spec)
+    pub fn desktop_entry(&self) -> String {
+        format!(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Roblox Booster\n\
+             Comment={}\n\
+             Exec={}\n\
+             Terminal=false\n\
+             X-GNOME-Autostart-enabled=true\n",
+            t!("autostart-comment"),
+            quote_exec(&self.exec)
+        )
+    }
+
+    /// Nội dung systemd user unit
+    pub fn systemd_unit_contents(&self) -> String {
+        format!(
+            "[Unit]\n\
+             Description=Roblox Booster\n\
+             PartOf={target}\n\
+             After={target}\n\
+             \n\
+             [Service]\n\
+             ExecStart={exec}\n\
+             Restart=on-failure\n\
+             \n\
+             [Install]\n\
+             WantedBy={target}\n",
+            target = SYSTEMD_TARGET,
+            exec = quote_exec(&self.exec)
+        )
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(unix)]
+fn link_unit(unit: &Path, link: &Path) -> io::Result<()> {
+    if let Some(parent) = link.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    std::os::unix::fs::symlink(unit, link)
+}
+
+#[cfg(not(unix))]
+fn link_unit(_unit: &Path, _link: &Path) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, t!("autostart-unsupported")))
+}
+
+/// Quote đường dẫn cho `Exec=`/`ExecStart=`: bọc trong `"..."` và escape `"`, `` ` ``, `$`, `\`
+///
+/// `%` luôn được nhân đôi (`%%`) vì cả Desktop Entry lẫn systemd coi `%` là field code/specifier.
+pub fn quote_exec(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('%', "%%");
+    if !path.contains(|c: char| c.is_whitespace() || "\"`$\\'".contains(c)) {
+        return path;
+    }
+
+    let mut quoted = String::from("\"");
+    for c in path.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn autostart(home: &Path, exec: &str) -> Autostart {
+        let app_paths = AppPaths::resolve_with(None, |key| (key == "HOME").then(|| home.to_path_buf()));
+        Autostart::with_exec(&app_paths, PathBuf::from(exec))
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn desktop_install_and_remove() {
+        let home = tempfile::tempdir().unwrap();
+        let autostart = autostart(home.path(), "/opt/Roblox Booster/roblox_booster");
+
+        autostart.install(AutostartKind::Desktop).unwrap();
+        assert_eq!(
+            autostart.desktop_file,
+            home.path().join(".config/autostart").join(DESKTOP_FILE_NAME)
+        );
+        let entry = fs::read_to_string(&autostart.desktop_file).unwrap();
+        assert!(entry.contains("Exec=\"/opt/Roblox Booster/roblox_booster\"\n"));
+        assert_eq!(autostart.installed(), vec![AutostartKind::Desktop]);
+
+        autostart.remove().unwrap();
+        assert!(!autostart.desktop_file.exists());
+        assert!(!autostart.is_installed());
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn systemd_install_links_unit_and_remove_deletes_link() {
+        let home = tempfile::tempdir().unwrap();
+        let autostart = autostart(home.path(), "/usr/bin/roblox_booster");
+
+        autostart.install(AutostartKind::Systemd).unwrap();
+        let unit_dir = home.path().join(".config/systemd/user");
+        assert_eq!(autostart.systemd_unit, unit_dir.join(SYSTEMD_UNIT_NAME));
+        let unit = fs::read_to_string(&autostart.systemd_unit).unwrap();
+        assert!(unit.contains("ExecStart=/usr/bin/roblox_booster\n"));
+        assert!(unit.contains("WantedBy=graphical-session.target\n"));
+        assert_eq!(
+            fs::read_link(&autostart.systemd_wants_link).unwrap(),
+            autostart.systemd_unit
+        );
+        assert_eq!(autostart.installed(), vec![AutostartKind::Systemd]);
+
+        autostart.remove().unwrap();
+        assert!(!autostart.systemd_unit.exists());
+        assert!(fs::symlink_metadata(&autostart.systemd_wants_link).is_err());
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn install_replaces_other_kind() {
+        let home = tempfile::tempdir().unwrap();
+        let autostart = autostart(home.path(), "/usr/bin/roblox_booster");
+
+        autostart.install(AutostartKind::Systemd).unwrap();
+        autostart.install(AutostartKind::Desktop).unwrap();
+        assert_eq!(autostart.installed(), vec![AutostartKind::Desktop]);
+        assert!(fs::symlink_metadata(&autostart.systemd_wants_link).is_err());
+    }
+
+    #[test]
+    fn remove_without_files_is_ok() {
+        let home = tempfile::tempdir().unwrap();
+        autostart(home.path(), "/usr/bin/roblox_booster").remove().unwrap();
+    }
+
+    #[test]
+    fn quote_exec_leaves_plain_paths_alone() {
+        assert_eq!(quote_exec(Path::new("/usr/bin/roblox_booster")), "/usr/bin/roblox_booster");
+    }
+
+    #[test]
+    fn quote_exec_quotes_spaces_and_escapes_specials() {
+        assert_eq!(
+            quote_exec(Path::new("/home/me/My Apps/booster")),
+            "\"/home/me/My Apps/booster\""
+        );
+        assert_eq!(
+            quote_exec(Path::new("/opt/a\"b/$HOME/`x`\\y")),
+            "\"/opt/a\\\"b/\\$HOME/\\`x\\`\\\\y\""
+        );
+        assert_eq!(quote_exec(Path::new("/opt/it's/booster")), "\"/opt/it's/booster\"");
+        assert_eq!(quote_exec(Path::new("/opt/100%/booster")), "/opt/100%%/booster");
+    }
+}
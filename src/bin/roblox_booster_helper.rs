@@ -0,0 +1,109 @@
+// Helper chạy bằng root (qua pkexec) cho các tuning cần quyền cao
+//
+// Đọc đúng một `HelperRequest` từ stdin, validate theo whitelist, thực hiện rồi
+// in `HelperResponse` ra stdout. Không nhận tham số dòng lệnh, không đọc config.
+
+#[path = "../helper_protocol.rs"]
+#[allow(dead_code)]
+mod helper_protocol;
+
+use helper_protocol::{HelperRequest, HelperResponse};
+use std::io::{Read, Write};
+
+fn main() {
+    let response = match read_request().and_then(handle) {
+        Ok(previous) => HelperResponse::Ok { previous },
+        Err(message) => HelperResponse::Error { message },
+    };
+    let failed = matches!(response, HelperResponse::Error { .. });
+
+    let data = serde_json::to_string(&response).unwrap_or_default();
+    let mut stdout = std::io::stdout();
+    let _ = writeln!(stdout, "{}", data);
+    let _ = stdout.flush();
+    std::process::exit(if failed { 1 } else { 0 });
+}
+
+fn read_request() -> Result<HelperRequest, String> {
+    let mut data = String::new();
+    std::io::stdin()
+        .take(helper_protocol::MAX_REQUEST_LEN)
+        .read_to_string(&mut data)
+        .map_err(|err| format!("failed to read request: {}", err))?;
+    let request: HelperRequest =
+        serde_json::from_str(data.trim()).map_err(|err| format!("invalid request: {}", err))?;
+    helper_protocol::validate(&request)?;
+    Ok(request)
+}
+
+#[cfg(target_os = "linux")]
+fn handle(request: HelperRequest) -> Result<String, String> {
+    use helper_protocol::Setting;
+
+    // SAFETY: geteuid không có tham số, không thể lỗi
+    if unsafe { libc::geteuid() } != 0 {
+        return Err("helper must run as root (pkexec)".to_string());
+    }
+
+    match request {
+        HelperRequest::Set { setting, value } => {
+            if setting == Setting::Governor {
+                let available = std::fs::read_to_string(
+                    "/sys/devices/system/cpu/cpu0/cpufreq/scaling_available_governors",
+                )
+                .unwrap_or_default();
+                if !available.split_whitespace().any(|governor| governor == value) {
+                    return Err(format!("governor '{}' is not available on this system", value));
+                }
+            }
+
+            let previous = setting.read_current()?;
+            for path in setting.paths() {
+                std::fs::write(&path, &value)
+                    .map_err(|err| format!("{}: {}", path.display(), err))?;
+            }
+            Ok(previous)
+        }
+        HelperRequest::SetNice { pid, nice } => {
+            // Kernel thread không có cmdline
+            let cmdline = std::fs::read(format!("/proc/{}/cmdline", pid))
+                .map_err(|err| format!("pid {}: {}", pid, err))?;
+            if cmdline.is_empty() {
+                return Err(format!("pid {} is a kernel thread", pid));
+            }
+            helper_protocol::check_nice_target(pid, caller_uid()?)?;
+
+            let previous = read_nice(pid).ok_or_else(|| format!("pid {}: cannot read nice", pid))?;
+            // SAFETY: setpriority chỉ đọc các tham số số nguyên
+            let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+            if result != 0 {
+                return Err(format!("pid {}: {}", pid, std::io::Error::last_os_error()));
+            }
+            Ok(previous.to_string())
+        }
+    }
+}
+
+/// UID của user đã gọi pkexec (`PKEXEC_UID`), chạy trực tiếp bằng root → UID thật
+#[cfg(target_os = "linux")]
+fn caller_uid() -> Result<u32, String> {
+    match std::env::var("PKEXEC_UID") {
+        Ok(uid) => uid
+            .parse()
+            .map_err(|_| format!("invalid PKEXEC_UID '{}'", uid)),
+        // SAFETY: getuid không có tham số, không thể lỗi
+        Err(_) => Ok(unsafe { libc::getuid() }),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn handle(_request: HelperRequest) -> Result<String, String> {
+    Err("helper is only supported on Linux".to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn read_nice(pid: u32) -> Option<i32> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    fields.get(16)?.parse().ok()
+}
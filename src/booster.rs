@@ -1,373 +1,727 @@
 
-use sysinfo::{ProcessExt, System, SystemExt, PidExt};
-use sysinfo::System;
+use sysinfo::{Pid, System};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
 use std::time::Duration;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::{broadcast, Notify};
 use tokio::time;
-use tokio::time;
-use crate::config::Config;
-use crate::config::Config;
-
-
-#[cfg(target_os = "windows")]
-#[cfg(target_os = "windows")]
-use windows::{
-use windows::{
-    core::*,
-    Win32::System::ProcessStatus::EmptyWorkingSet,
-    Win32::Foundation::*,
-    Win32::System::Threading::GetCurrentProcess,
-    Win32::System::Memory::*,
-};
-};
-
+use crate::config::{Config, ConfigChange, LayeredConfig};
+use crate::events::{EngineEvent, EngineStatus};
+use crate::focus::{self, Focus};
+use crate::i18n::{self, t};
+use crate::journal::{self, Change};
+use crate::logging::{log_error, log_info, log_warn};
+use crate::metrics::Sampler;
+use crate::optimizations::{self, Optimization, OptimizationContext, PauseList, PlannedAction};
+use crate::process::{self, ProcessEntry};
+use crate::session;
+use crate::session_panel::SessionEntry;
+
+/// Đợi editor ghi xong file trước khi đọc lại config
+const CONFIG_RELOAD_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Chu kỳ lấy mẫu cho dashboard
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Tên process có phải Roblox không
+pub fn is_roblox_process(name: &str) -> bool {
+    let name = name.to_lowercase();
+    // Chính booster (`roblox_booster`, `roblox_booster_helper`) không phải Roblox
+    if name.starts_with("roblox_booster") {
+        return false;
+    }
+    name.contains("roblox")
+}
 
-/// Struct chính quản lý việc boost - KHÔNG CẦN ADMIN
 /// Struct chính quản lý việc boost - KHÔNG CẦN ADMIN
 pub struct RobloxBoosterEngine {
-pub struct RobloxBoosterEngine {
-    is_running: Arc<Mutex<bool>>,
     is_running: Arc<Mutex<bool>>,
+    /// Tăng mỗi lần start → loop cũ tự thoát khi start/stop liên tục
+    run_generation: Arc<AtomicU64>,
     sys: Arc<Mutex<System>>,
-    sys: Arc<Mutex<System>>,
+    /// `System` riêng của sampler (dashboard, process explorer), chỉ sampler refresh
+    sampled: Arc<Mutex<System>>,
     config: Arc<Mutex<Config>>,
-    config: Arc<Mutex<Config>>,
-    timer_resolution_active: Arc<Mutex<bool>>,
-    timer_resolution_active: Arc<Mutex<bool>>,
-}
+    /// Optimization đã đăng ký (xem `optimizations::registry`)
+    optimizations: Vec<Arc<dyn Optimization>>,
+    /// PID đã boost thủ công → nice ban đầu để revert + entry trong journal
+    boosted: Mutex<HashMap<u32, (i32, Change)>>,
+    events: broadcast::Sender<EngineEvent>,
+    config_watcher: Mutex<Option<RecommendedWatcher>>,
+    /// Cửa sổ đang focus, cập nhật bởi `watch_focus`
+    focus: Arc<Mutex<Focus>>,
+    /// Thời gian người dùng không thao tác, cập nhật bởi `watch_idle`
+    idle: Arc<Mutex<Option<Duration>>>,
+    /// Đánh thức boost loop (focus đổi, bắt đầu/hết AFK) thay vì đợi hết chu kỳ
+    wake: Arc<Notify>,
+    /// Khóa per-user giữ suốt vòng đời engine, `Err` = booster khác đang chạy
+    instance_lock: std::result::Result<journal::InstanceLock, String>,
 }
 
-
 impl RobloxBoosterEngine {
-impl RobloxBoosterEngine {
-    pub fn new(config: Config) -> Self {
     pub fn new(config: Config) -> Self {
+        let (events, _) = broadcast::channel(64);
+
         Self {
-        Self {
-            is_running: Arc::new(Mutex::new(false)),
             is_running: Arc::new(Mutex::new(false)),
+            run_generation: Arc::new(AtomicU64::new(0)),
             sys: Arc::new(Mutex::new(System::new_all())),
-            sys: Arc::new(Mutex::new(System::new_all())),
+            sampled: Arc::new(Mutex::new(System::new())),
             config: Arc::new(Mutex::new(config)),
-            config: Arc::new(Mutex::new(config)),
-            timer_resolution_active: Arc::new(Mutex::new(false)),
-            timer_resolution_active: Arc::new(Mutex::new(false)),
-        }
+            optimizations: optimizations::registry(),
+            boosted: Mutex::new(HashMap::new()),
+            events,
+            config_watcher: Mutex::new(None),
+            focus: Arc::new(Mutex::new(Focus::Unknown)),
+            idle: Arc::new(Mutex::new(None)),
+            wake: Arc::new(Notify::new()),
+            instance_lock: journal::lock_instance(),
         }
     }
+
+    /// Booster lần trước bị kill → hoàn tác thay đổi còn sót trước khi boost lại
+    ///
+    /// Chỉ chạy khi engine giữ được `InstanceLock`: journal của booster khác đang
+    /// chạy không được hoàn tác.
+    pub fn recover_journal(&self) {
+        if let Err(err) = self.owns_journal() {
+            log_warn!("⚠️  {}", t!("journal-recover-skipped", reason = err));
+            return;
+        }
+        if journal::pending().is_ok_and(|changes| !changes.is_empty()) {
+            journal::recover_logged();
+        }
     }
 
+    /// `Err` nếu engine không giữ `InstanceLock`: booster khác đang chạy và ghi cùng journal
+    ///
+    /// Khi đó engine không được áp dụng thay đổi nào (trừ dry-run): crash sẽ để lại thay
+    /// đổi không ai hoàn tác.
+    fn owns_journal(&self) -> std::result::Result<(), String> {
+        self.instance_lock.as_ref().map(|_| ()).map_err(Clone::clone)
+    }
 
     /// Bắt đầu auto boost - KHÔNG CẦN ADMIN
-    /// Bắt đầu auto boost - KHÔNG CẦN ADMIN
-    pub async fn start(&self) {
-    pub async fn start(&self) {
-        let mut is_running = self.is_running.lock().unwrap();
-        let mut is_running = self.is_running.lock().unwrap();
-        *is_running = true;
-        *is_running = true;
-        drop(is_running);
-        drop(is_running);
+    ///
+    /// Boost loop chạy trên tokio runtime hiện tại (executor của iced); không có
+    /// runtime hoặc booster đang chạy → `Err`.
+    pub async fn start(&self) -> std::result::Result<(), String> {
+        let runtime = tokio::runtime::Handle::try_current()
+            .map_err(|err| t!("engine-no-runtime", error = err))?;
+        if !self.config().dry_run {
+            self.owns_journal()?;
+        }
 
+        {
+            let mut is_running = self.is_running.lock().unwrap();
+            if *is_running {
+                return Err(t!("engine-already-running"));
+            }
+            *is_running = true;
+        }
+        self.publish(EngineEvent::StatusChanged(EngineStatus::Starting));
+        let generation = self.run_generation.fetch_add(1, Ordering::SeqCst) + 1;
 
         println!("\n╔══════════════════════════════════════╗");
-        println!("\n╔══════════════════════════════════════╗");
-        println!("║  ĐANG KHỞI ĐỘNG BOOSTER (No Admin)  ║");
-        println!("║  ĐANG KHỞI ĐỘNG BOOSTER (No Admin)  ║");
-        println!("╚══════════════════════════════════════╝\n");
+        println!("║  {:<36}║", t!("engine-starting-banner"));
         println!("╚══════════════════════════════════════╝\n");
 
+        let config = self.config();
+        for optimization in &self.optimizations {
+            if let optimizations::Availability::Unavailable(reason) = optimization.availability() {
+                if config.is_enabled(optimization.id()) {
+                    log_warn!("⚠️  {}: {}", optimization.name(), reason);
+                }
+            }
+        }
 
-        // Áp dụng Timer Resolution (system-wide, không cần admin)
-        // Áp dụng Timer Resolution (system-wide, không cần admin)
-        self.apply_timer_resolution();
-        self.apply_timer_resolution();
-
-
-        let is_running_clone = Arc::clone(&self.is_running);
         let is_running_clone = Arc::clone(&self.is_running);
+        let run_generation = Arc::clone(&self.run_generation);
         let sys_clone = Arc::clone(&self.sys);
-        let sys_clone = Arc::clone(&self.sys);
-        let config_clone = Arc::clone(&self.config);
         let config_clone = Arc::clone(&self.config);
+        let focus_clone = Arc::clone(&self.focus);
+        let idle_clone = Arc::clone(&self.idle);
+        let wake = Arc::clone(&self.wake);
+        let optimizations = self.optimizations.clone();
 
-
-        tokio::spawn(async move {
-        tokio::spawn(async move {
-            // MỖI 60 GIÂY
-            let interval_seconds = {
-            let mut interval = time::interval(Duration::from_secs(60));
-                let config = config_clone.lock().unwrap().clone();
-                if config.boost_interval_seconds == 0 {
-                    60
-                } else {
-                    config.boost_interval_seconds
-                }
-            };
-            let mut interval = time::interval(Duration::from_secs(interval_seconds));
-
-
-            loop {
+        runtime.spawn(async move {
             loop {
-                interval.tick().await;
-                interval.tick().await;
-
-
-                let running = *is_running_clone.lock().unwrap();
                 let running = *is_running_clone.lock().unwrap();
-                if !running {
-                if !running {
-                    break;
+                if !running || run_generation.load(Ordering::SeqCst) != generation {
                     break;
                 }
-                }
-
-
-                // Refresh system info
-                // Refresh system info
-                let mut sys = sys_clone.lock().unwrap();
-                let mut sys = sys_clone.lock().unwrap();
-                sys.refresh_all();
-                sys.refresh_all();
 
-
-                let config = config_clone.lock().unwrap().clone();
+                // Đọc config mỗi cycle → hot reload có hiệu lực ngay cycle sau
                 let config = config_clone.lock().unwrap().clone();
-                
+                let interval_seconds = config.effective_interval_seconds();
+                let focus = if config.follow_focus {
+                    *focus_clone.lock().unwrap()
+                } else {
+                    Focus::Unknown
+                };
+                let idle = *idle_clone.lock().unwrap();
 
-                // Detect Roblox (chỉ để hiển thị status)
-                // Detect Roblox (chỉ để hiển thị status)
-                Self::detect_roblox(&sys);
-                if config.enable_auto_detection {
-                    Self::detect_roblox(&sys);
-                }
-                
-                
-                // Memory cleanup (dọn RAM của app này)
-                // Memory cleanup (dọn RAM của app này)
-                if config.enable_memory_cleanup {
-                if config.enable_memory_cleanup {
-                    Self::cleanup_memory();
-                    Self::cleanup_memory();
-                }
+                {
+                    // Refresh system info
+                    let mut sys = sys_clone.lock().unwrap();
+                    sys.refresh_all();
+                    Self::run_optimizations(&optimizations, &Self::context(&sys, &config, focus, idle));
                 }
-                
 
-                println!("⏱️  Cycle hoàn tất (next: 60s)\n");
-                println!("⏱️  Cycle hoàn tất (next: {}s)\n", interval_seconds);
-            }
+                log_info!("{}\n", t!("engine-cycle-done", seconds = interval_seconds));
+                // Focus đổi, bắt đầu/hết AFK → chạy cycle ngay
+                tokio::select! {
+                    _ = time::sleep(Duration::from_secs(interval_seconds)) => {}
+                    _ = wake.notified() => {}
+                }
             }
         });
-        });
-        
-        
-        println!("🚀 Auto Booster đã BẬT");
-        println!("🚀 Auto Booster đã BẬT");
-        println!("⏱️  Boost interval: 60 giây");
-        let interval_seconds = self.config.lock().unwrap().boost_interval_seconds;
-        let interval_seconds = if interval_seconds == 0 { 60 } else { interval_seconds };
-        println!("⏱️  Boost interval: {} giây", interval_seconds);
-        println!("ℹ️  Chế độ: Không cần Admin\n");
-        println!("ℹ️  Chế độ: Không cần Admin\n");
-    }
+
+        log_info!("{}", t!("engine-started"));
+        let interval_seconds = self.config.lock().unwrap().effective_interval_seconds();
+        log_info!("{}", t!("engine-interval", seconds = interval_seconds));
+        log_info!("{}\n", t!("engine-mode-no-admin"));
+        if self.config().dry_run {
+            log_warn!("{}\n", t!("dryrun-enabled"));
+        }
+        self.publish(EngineEvent::StatusChanged(EngineStatus::Running));
+        Ok(())
     }
 
+    /// Dừng auto boost; booster không chạy → `Err`
+    pub fn stop(&self) -> std::result::Result<(), String> {
+        {
+            let mut is_running = self.is_running.lock().unwrap();
+            if !*is_running {
+                return Err(t!("engine-not-running"));
+            }
+            *is_running = false;
+        }
+        self.publish(EngineEvent::StatusChanged(EngineStatus::Stopping));
 
-    /// Dừng auto boost
-    /// Dừng auto boost
-    pub fn stop(&self) {
-    pub fn stop(&self) {
-        let mut is_running = self.is_running.lock().unwrap();
-        let mut is_running = self.is_running.lock().unwrap();
-        *is_running = false;
-        *is_running = false;
-        
-        
-        // Restore timer resolution
-        // Restore timer resolution
-        self.restore_timer_resolution();
-        self.restore_timer_resolution();
-        
-        
-        println!("\n⏸️  Auto Booster đã TẮT\n");
-        println!("\n⏸️  Auto Booster đã TẮT\n");
-    }
-    }
+        self.revert_optimizations();
 
+        log_info!("\n{}\n", t!("engine-stopped"));
+        self.publish(EngineEvent::StatusChanged(EngineStatus::Stopped));
+        Ok(())
+    }
 
     /// Kiểm tra trạng thái
-    /// Kiểm tra trạng thái
-    pub fn is_running(&self) -> bool {
     pub fn is_running(&self) -> bool {
         *self.is_running.lock().unwrap()
-        *self.is_running.lock().unwrap()
     }
+
+    /// Config hiện tại của engine
+    pub fn config(&self) -> Config {
+        self.config.lock().unwrap().clone()
+    }
+
+    /// Nhận event từ engine
+    pub fn subscribe(&self) -> broadcast::Receiver<EngineEvent> {
+        self.events.subscribe()
+    }
+
+    fn emit(&self, event: EngineEvent) {
+        match event {
+            EngineEvent::ConfigRejected { .. } => log_warn!("{}", event),
+            _ => log_info!("{}", event),
+        }
+        self.publish(event);
     }
 
+    /// Gửi event cho subscribers mà không in ra console (vd metrics mỗi giây)
+    fn publish(&self, event: EngineEvent) {
+        // Không có subscriber cũng không sao
+        let _ = self.events.send(event);
+    }
+
+    /// Các tối ưu đang thực sự được áp dụng
+    pub fn active_optimizations(&self) -> Vec<String> {
+        if !self.is_running() {
+            return Vec::new();
+        }
+
+        let config = self.config();
+        let mut active: Vec<String> = self
+            .optimizations
+            .iter()
+            .filter(|optimization| {
+                config.is_enabled(optimization.id()) && optimization.availability().is_available()
+            })
+            .filter_map(|optimization| optimization.status(&config))
+            .collect();
+        let boosted = self.boosted.lock().unwrap().len();
+        if boosted > 0 {
+            active.push(t!("opt-boosted-count", count = boosted));
+        }
+        active
+    }
 
     // ========================================
+    // SAMPLING CHO DASHBOARD
     // ========================================
-    // TÍNH NĂNG 1: TIMER RESOLUTION (System-wide)
-    // TÍNH NĂNG 1: TIMER RESOLUTION (System-wide)
+
+    /// Lấy mẫu CPU/RAM/PSI/nhiệt độ mỗi giây, gửi qua `EngineEvent::Metrics`
+    ///
+    /// Chạy trên thread riêng nên không phụ thuộc tokio runtime; thread tự
+    /// dừng khi engine bị drop. Sampler dùng `System` riêng: CPU% là delta giữa
+    /// hai lần refresh nên refresh xen kẽ với boost loop làm số liệu nhảy lung tung.
+    pub fn start_sampling(self: &Arc<Self>) {
+        let engine = Arc::downgrade(self);
+
+        std::thread::spawn(move || {
+            let mut sampler = Sampler::new();
+
+            loop {
+                let Some(engine) = engine.upgrade() else {
+                    break;
+                };
+
+                let config = engine.config();
+                let mut sample = {
+                    let mut sys = engine.sampled.lock().unwrap();
+                    sampler.sample(&mut sys, |name| config.is_roblox_process(name))
+                };
+                sample.active_optimizations = engine.active_optimizations();
+                engine.publish(EngineEvent::Metrics(sample));
+
+                drop(engine);
+                std::thread::sleep(SAMPLE_INTERVAL);
+            }
+        });
+    }
+
     // ========================================
+    // HOT RELOAD CONFIG
     // ========================================
-    
-    
-    /// Set timer resolution to 1ms - KHÔNG CẦN ADMIN
-    /// Set timer resolution to 1ms - KHÔNG CẦN ADMIN
-    /// Áp dụng cho toàn hệ thống, benefit cho tất cả apps
-    /// Áp dụng cho toàn hệ thống, benefit cho tất cả apps
-    
-    fn apply_timer_resolution(&self) {
-        let config = self.config.lock().unwrap();
-        
-        if !config.enable_timer_resolution {
-            println!("⏱️  Timer Resolution: TẮT");
-            return;
+
+    /// Áp dụng config mới cho engine (đang chạy hoặc không), không cần stop/start
+    pub fn apply_config(&self, new_config: Config) -> Vec<ConfigChange> {
+        let changes = {
+            let mut config = self.config.lock().unwrap();
+            let changes = config.diff(&new_config);
+            *config = new_config;
+            changes
+        };
+
+        if changes.is_empty() {
+            return changes;
         }
-        
-        #[cfg(target_os = "windows")]
-        {
-            use std::ffi::CString;
-            
-            unsafe {
-                let winmm = match windows::Win32::System::LibraryLoader::LoadLibraryA(
-                    windows::core::PCSTR("winmm.dll\0".as_ptr())
-                ) {
-                    Ok(lib) => lib,
-                    Err(_) => {
-                        eprintln!("✗ Không load được winmm.dll");
-                        return;
-                    }
-                };
-                
-                let func_name = CString::new("timeBeginPeriod").unwrap();
-                let time_begin_period = windows::Win32::System::LibraryLoader::GetProcAddress(
-                    winmm,
-                    windows::core::PCSTR(func_name.as_ptr() as *const u8)
-                );
-                
-                if let Some(func) = time_begin_period {
-                    let time_begin: extern "system" fn(u32) -> u32 = std::mem::transmute(func);
-                    let result = time_begin(1); // 1ms resolution
-                    
-                    if result == 0 {
-                        println!("✓ Timer Resolution: 1ms (system-wide)");
-                        println!("  ℹ️  Benefit: Mọi app đều mượt hơn");
-                        let mut active = self.timer_resolution_active.lock().unwrap();
-                        *active = true;
-                    } else {
-                        eprintln!("✗ Không set được timer resolution (error: {})", result);
-                    }
-                }
-            }
+
+        // Bật/tắt optimization có hiệu lực ngay, không đợi chu kỳ sau
+        let toggled: Vec<Arc<dyn Optimization>> = self
+            .optimizations
+            .iter()
+            .filter(|optimization| {
+                let key = optimizations::config_key(optimization.id());
+                changes.iter().any(|change| change.field == key)
+            })
+            .cloned()
+            .collect();
+        if self.is_running() && !toggled.is_empty() {
+            let config = self.config();
+            let focus = self.current_focus(&config);
+            let sys = self.sys.lock().unwrap();
+            let idle = *self.idle.lock().unwrap();
+            Self::run_optimizations(&toggled, &Self::context(&sys, &config, focus, idle));
         }
-        
-        #[cfg(not(target_os = "windows"))]
-        {
-            println!("⚠️  Timer Resolution chỉ hỗ trợ Windows");
+        // Bật dry-run khi đang chạy → hoàn tác những gì đã áp dụng thật
+        if self.config().dry_run && changes.iter().any(|change| change.field == "dry_run") {
+            self.revert_optimizations();
+            self.revert_all_boosted();
         }
+        if changes.iter().any(|change| change.field == "language") {
+            i18n::set_language(self.config().language);
+        }
+
+        self.emit(EngineEvent::ConfigChanged {
+            changes: changes.clone(),
+        });
+        changes
     }
-    
-    /// Restore timer resolution - KHÔNG CẦN ADMIN
-    fn restore_timer_resolution(&self) {
-        let active = *self.timer_resolution_active.lock().unwrap();
-        
-        if !active {
-            return;
+
+    /// Load lại tất cả config layers, validate rồi áp dụng
+    pub fn reload_config(&self) -> std::result::Result<Vec<ConfigChange>, String> {
+        match LayeredConfig::load_strict() {
+            Ok(layered) => Ok(self.apply_config(layered.config)),
+            Err(reason) => {
+                self.emit(EngineEvent::ConfigRejected {
+                    reason: reason.clone(),
+                });
+                Err(reason)
+            }
         }
-        
-        #[cfg(target_os = "windows")]
-        {
-            use std::ffi::CString;
-            
-            unsafe {
-                if let Ok(winmm) = windows::Win32::System::LibraryLoader::LoadLibraryA(
-                    windows::core::PCSTR("winmm.dll\0".as_ptr())
-                ) {
-                    let func_name = CString::new("timeEndPeriod").unwrap();
-                    if let Some(func) = windows::Win32::System::LibraryLoader::GetProcAddress(
-                        winmm,
-                        windows::core::PCSTR(func_name.as_ptr() as *const u8)
-                    ) {
-                        let time_end: extern "system" fn(u32) -> u32 = std::mem::transmute(func);
-                        time_end(1);
-                        println!("✓ Timer Resolution đã restore");
-                    }
-                }
+    }
+
+    /// Theo dõi các file config (inotify trên Linux) và tự reload khi file thay đổi
+    ///
+    /// Watch thư mục cha vì editor thường ghi file mới rồi rename đè lên.
+    pub fn watch_config(self: &Arc<Self>, files: &[PathBuf]) -> notify::Result<()> {
+        let engine = Arc::downgrade(self);
+        // notify trả về đường dẫn tuyệt đối
+        let watched: Vec<PathBuf> = files
+            .iter()
+            .map(|file| std::path::absolute(file).unwrap_or_else(|_| file.clone()))
+            .collect();
+        let engine_files = watched.clone();
+
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            let Ok(event) = result else {
+                return;
+            };
+            if !(event.kind.is_modify() || event.kind.is_create()) {
+                return;
+            }
+            let is_config = event.paths.iter().any(|changed| {
+                engine_files
+                    .iter()
+                    .any(|file| changed.file_name() == file.file_name() && changed.parent() == file.parent())
+            });
+            if !is_config {
+                return;
+            }
+
+            std::thread::sleep(CONFIG_RELOAD_DEBOUNCE);
+            if let Some(engine) = engine.upgrade() {
+                let _ = engine.reload_config();
             }
+        })?;
+
+        let mut watching_any = false;
+        for file in &watched {
+            let dir = file
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            // System config có thể không tồn tại → bỏ qua
+            if !dir.is_dir() {
+                continue;
+            }
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+            watching_any = true;
+            log_info!("{}", t!("engine-watching-config", path = file.display()));
+        }
+
+        if watching_any {
+            *self.config_watcher.lock().unwrap() = Some(watcher);
         }
-        
-        let mut active = self.timer_resolution_active.lock().unwrap();
-        *active = false;
+        Ok(())
     }
 
-    // ========================================
-    // TÍNH NĂNG 2: ROBLOX DETECTION (Chỉ hiển thị)
-    // ========================================
-    
-    /// Phát hiện Roblox để hiển thị status - KHÔNG CẦN ADMIN
-    fn detect_roblox(sys: &System) {
-        let mut found_processes = Vec::new();
-        
-        for (pid, process) in sys.processes() {
-            let name = process.name().to_lowercase();
-            
-            if name.contains("roblox") || name.contains("robloxplayerbeta") {
-                found_processes.push((process.name().to_string(), pid.as_u32()));
+    /// Theo dõi cửa sổ đang focus (X11/XWayland), focus đổi → boost loop chạy ngay một cycle
+    ///
+    /// Không có X server → `Err`, engine coi như không biết focus.
+    pub fn watch_focus(self: &Arc<Self>) -> std::result::Result<(), String> {
+        let engine = Arc::downgrade(self);
+        focus::watch(move |focus| {
+            let Some(engine) = engine.upgrade() else {
+                return;
+            };
+            *engine.focus.lock().unwrap() = focus;
+            if engine.is_running() && engine.config().follow_focus {
+                engine.wake.notify_one();
+            }
+        })?;
+        // Không có MIT-SCREEN-SAVER → tiết kiệm pin chỉ theo focus
+        if let Err(err) = self.watch_idle() {
+            let power = self.config().power_saving;
+            if power.enabled && power.idle_minutes > 0 {
+                log_warn!("⚠️  {}", err);
             }
         }
-        
-        if found_processes.is_empty() {
-            println!("🔍 Status: Không phát hiện Roblox");
-        } else {
-            println!("🎮 Phát hiện Roblox:");
-            for (name, pid) in found_processes {
-                println!("   • {} (PID: {})", name, pid);
+        Ok(())
+    }
+
+    /// Theo dõi thời gian không thao tác, bắt đầu/hết AFK (theo `power_saving`) → chạy cycle ngay
+    fn watch_idle(self: &Arc<Self>) -> std::result::Result<(), String> {
+        let engine = Arc::downgrade(self);
+        focus::watch_idle(move |idle| {
+            let Some(engine) = engine.upgrade() else {
+                return;
+            };
+            let power = engine.config.lock().unwrap().power_saving.clone();
+            let was_idle = power.is_idle(std::mem::replace(&mut *engine.idle.lock().unwrap(), idle));
+            let is_idle = power.is_idle(idle);
+            if was_idle == is_idle || !engine.is_running() {
+                return;
             }
-            println!("   ℹ️  System đang được tối ưu cho gaming");
+            if is_idle {
+                log_info!("{}", t!("power-idle", minutes = power.idle_minutes));
+            } else {
+                log_info!("{}", t!("power-active"));
+            }
+            engine.wake.notify_one();
+        })
+    }
+
+    /// Focus dùng cho cycle: tắt `follow_focus` → `Unknown`; chưa có watcher → hỏi X server một lần
+    fn current_focus(&self, config: &Config) -> Focus {
+        if !config.follow_focus {
+            return Focus::Unknown;
+        }
+        match *self.focus.lock().unwrap() {
+            Focus::Unknown => focus::current(),
+            focus => focus,
         }
     }
 
     // ========================================
-    // TÍNH NĂNG 3: MEMORY CLEANUP (Current process)
+    // OPTIMIZATIONS
     // ========================================
-    
-    /// Dọn RAM của chính app này - KHÔNG CẦN ADMIN
-    /// Giải phóng RAM cho Roblox sử dụng
-    #[cfg(target_os = "windows")]
-    fn cleanup_memory() {
-        unsafe {
-            match GetCurrentProcess() {
-                Ok(handle) => {
-                    match EmptyWorkingSet(handle) {
-                        Ok(_) => {
-                            println!("✓ Memory Cleanup: Đã giải phóng RAM");
-                            println!("  ℹ️  RAM available tăng lên cho Roblox");
-                        }
-                        Err(e) => {
-                            eprintln!("✗ Memory cleanup thất bại: {:?}", e);
-                        }
-                    }
+
+    /// Một chu kỳ: apply optimization đang bật, revert optimization đã tắt
+    ///
+    /// Dry-run → chỉ log các hành động dự kiến.
+    fn run_optimizations(optimizations: &[Arc<dyn Optimization>], ctx: &OptimizationContext) {
+        let config = ctx.config;
+        if config.dry_run {
+            for (optimization, actions) in Self::plan_optimizations(optimizations, ctx) {
+                if actions.is_empty() {
+                    log_info!("{}", t!("dryrun-no-change", name = optimization.name()));
                 }
-                Err(e) => {
-                    eprintln!("✗ GetCurrentProcess thất bại: {:?}", e);
+                for action in actions {
+                    log_info!("{}", t!("dryrun-planned", name = optimization.name(), action = action));
                 }
             }
+            return;
+        }
+
+        for optimization in optimizations {
+            if !optimization.availability().is_available() {
+                continue;
+            }
+            let result = if config.is_enabled(optimization.id()) {
+                optimization.apply(ctx)
+            } else {
+                optimization.revert(ctx.sys)
+            };
+            if let Err(err) = result {
+                log_error!("✗ {}: {}", optimization.name(), err);
+            }
         }
     }
-    
-    #[cfg(not(target_os = "windows"))]
-    fn cleanup_memory() {
-        println!("⚠️  Memory cleanup chỉ hỗ trợ Windows");
+
+    fn context<'a>(
+        sys: &'a System,
+        config: &'a Config,
+        focus: Focus,
+        idle: Option<Duration>,
+    ) -> OptimizationContext<'a> {
+        OptimizationContext {
+            sys,
+            config,
+            focus,
+            idle,
+            roblox_running: sys
+                .processes()
+                .values()
+                .any(|process| config.is_roblox_process(process.name())),
+        }
+    }
+
+    /// Hành động dự kiến của các optimization đang bật (và chạy được trên máy này)
+    fn plan_optimizations(
+        optimizations: &[Arc<dyn Optimization>],
+        ctx: &OptimizationContext,
+    ) -> Vec<(Arc<dyn Optimization>, Vec<PlannedAction>)> {
+        optimizations
+            .iter()
+            .filter(|optimization| {
+                optimization.availability().is_available() && ctx.config.is_enabled(optimization.id())
+            })
+            .map(|optimization| (Arc::clone(optimization), optimization.plan(ctx)))
+            .collect()
+    }
+
+    /// Một chu kỳ dry-run trên dữ liệu process mới nhất (dùng cho `plan` CLI)
+    pub fn plan(&self) -> Vec<(Arc<dyn Optimization>, Vec<PlannedAction>)> {
+        let mut sys = self.sys.lock().unwrap();
+        sys.refresh_all();
+        let config = self.config();
+        let focus = self.current_focus(&config);
+        let idle = *self.idle.lock().unwrap();
+        Self::plan_optimizations(&self.optimizations, &Self::context(&sys, &config, focus, idle))
+    }
+
+    /// Một chu kỳ ngay trước khi launch client: coi như Roblox đang chạy để
+    /// các tuning hệ thống (timer, governor...) có hiệu lực trước khi game load
+    pub fn prepare_launch(&self) {
+        let config = self.config();
+        match self.owns_journal() {
+            Err(err) if !config.dry_run => {
+                log_warn!("⚠️  {}", t!("journal-launch-skipped", reason = err));
+                return;
+            }
+            _ => {}
+        }
+        let focus = self.current_focus(&config);
+        let idle = *self.idle.lock().unwrap();
+        let mut sys = self.sys.lock().unwrap();
+        sys.refresh_all();
+        let ctx = OptimizationContext {
+            roblox_running: true,
+            ..Self::context(&sys, &config, focus, idle)
+        };
+        Self::run_optimizations(&self.optimizations, &ctx);
+    }
+
+    /// Revert mọi optimization theo thứ tự ngược với lúc apply
+    pub fn revert_optimizations(&self) {
+        let sys = self.sys.lock().unwrap();
+        for optimization in self.optimizations.iter().rev() {
+            if let Err(err) = optimization.revert(&sys) {
+                log_error!("✗ {}: {}", optimization.name(), err);
+            }
+        }
+    }
+
+    /// Snapshot tất cả process từ lần lấy mẫu gần nhất của sampler (mới nhất 1 giây trước)
+    ///
+    /// Nice/affinity/cgroup chưa được đọc (xem `ProcessEntry::load_details`) vì
+    /// đọc `/proc` của mọi process mỗi giây quá tốn.
+    pub fn process_snapshot(&self) -> Vec<ProcessEntry> {
+        let config = self.config();
+        let paused: HashSet<u32> = self
+            .optimizations
+            .iter()
+            .filter(|optimization| optimization.id() == PauseList::ID)
+            .flat_map(|optimization| optimization.affected_pids())
+            .collect();
+        let boosted: HashSet<u32> = self.boosted.lock().unwrap().keys().copied().collect();
+        let sys = self.sampled.lock().unwrap();
+        let sessions: HashMap<u32, (u32, usize)> = session::detect(&sys, &config)
+            .iter()
+            .enumerate()
+            .flat_map(|(index, session)| session.pids().map(move |pid| (pid, (session.root, index + 1))))
+            .collect();
+
+        sys.processes()
+            .iter()
+            .map(|(pid, process)| {
+                let pid = pid.as_u32();
+                ProcessEntry {
+                    pid,
+                    name: process.name().to_string(),
+                    cpu: process.cpu_usage(),
+                    memory: process.memory(),
+                    nice: None,
+                    affinity: None,
+                    cgroup: None,
+                    roblox_match: config.is_roblox_process(process.name()),
+                    session: sessions.get(&pid).map(|(root, _)| *root),
+                    instance: sessions.get(&pid).map(|(_, instance)| *instance),
+                    in_pause_list: config.is_pause_process(process.name()),
+                    paused: paused.contains(&pid),
+                    boosted: boosted.contains(&pid),
+                }
+            })
+            .collect()
+    }
+
+    /// Các session Roblox trong snapshot của sampler kèm trạng thái boost (trang Phiên chơi)
+    pub fn session_snapshot(&self) -> Vec<SessionEntry> {
+        let config = self.config();
+        let focus = if config.follow_focus {
+            *self.focus.lock().unwrap()
+        } else {
+            Focus::Unknown
+        };
+        let sessions = session::detect(&self.sampled.lock().unwrap(), &config);
+        let primary = session::primary(&sessions, focus);
+
+        sessions
+            .iter()
+            .enumerate()
+            .map(|(index, session)| SessionEntry {
+                instance: index + 1,
+                root: session.root,
+                client: session.client().to_string(),
+                primary: Some(session.root) == primary,
+                members: session.members.clone(),
+                boost: self
+                    .optimizations
+                    .iter()
+                    .filter_map(|optimization| {
+                        let status = optimization.session_status(session)?;
+                        Some(format!("{}: {}", optimization.name(), status))
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Tăng priority của một process (nice -5), lưu nice cũ để revert
+    ///
+    /// Dry-run → không đổi gì, trả về hành động dự kiến.
+    pub fn boost_process(&self, pid: u32) -> std::result::Result<Option<PlannedAction>, String> {
+        let mut boosted = self.boosted.lock().unwrap();
+        if boosted.contains_key(&pid) {
+            return Ok(None);
+        }
+
+        let original = process::read_nice(pid).unwrap_or(0);
+        let name = self
+            .sampled
+            .lock()
+            .unwrap()
+            .process(Pid::from_u32(pid))
+            .map(|process| process.name().to_string())
+            .unwrap_or_default();
+        if self.config().dry_run {
+            let action = PlannedAction {
+                target: format!("nice {} (PID {})", name, pid),
+                before: original.to_string(),
+                after: process::BOOST_NICE.to_string(),
+            };
+            log_info!("{}", t!("dryrun-planned", name = t!("dryrun-manual-boost"), action = &action));
+            return Ok(Some(action));
+        }
+
+        self.owns_journal()?;
+        let change = Change::Nice { pid, name, original };
+        journal::record(change.clone())?;
+        if let Err(err) = process::set_nice_privileged(pid, process::BOOST_NICE) {
+            journal::complete(&change);
+            return Err(err);
+        }
+        boosted.insert(pid, (original, change));
+        log_info!(
+            "{}",
+            t!("boost-applied", pid = pid, from = original, to = process::BOOST_NICE)
+        );
+        Ok(None)
+    }
+
+    /// Trả priority của process về như trước khi boost
+    pub fn revert_process(&self, pid: u32) -> std::result::Result<(), String> {
+        let mut boosted = self.boosted.lock().unwrap();
+        let Some((original, change)) = boosted.get(&pid).cloned() else {
+            return Ok(());
+        };
+        process::set_nice_privileged(pid, original)?;
+        boosted.remove(&pid);
+        journal::complete(&change);
+        log_info!("{}", t!("boost-reverted", pid = pid, to = original));
+        Ok(())
+    }
+
+    fn revert_all_boosted(&self) {
+        let pids: Vec<u32> = self.boosted.lock().unwrap().keys().copied().collect();
+        for pid in pids {
+            if let Err(err) = self.revert_process(pid) {
+                log_error!("✗ {}", err);
+            }
+        }
     }
 }
 
 impl Drop for RobloxBoosterEngine {
     fn drop(&mut self) {
-        // Ensure timer resolution được restore
-        self.restore_timer_resolution();
+        // Ensure timer resolution được restore, process bị dừng được chạy lại
+        self.revert_optimizations();
+        self.revert_all_boosted();
     }
 }
\ No newline at end of file
@@ -0,0 +1,227 @@
+use crate::i18n::t;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Kết quả probe: `Ok(chi tiết)` hoặc `Err(lý do)`
+pub type ProbeResult = Result<String, String>;
+
+/// Quyền/tính năng hệ thống mà một số tuning cần (probe lúc khởi động)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// Giảm nice (tăng priority): CAP_SYS_NICE, root hoặc `RLIMIT_NICE` đủ cao
+    SysNice,
+    /// Gửi signal cho process của user khác: CAP_KILL hoặc root
+    Kill,
+    /// cgroup v2 được delegate cho user, có controller `cpu`
+    CgroupDelegation,
+    /// Ghi được `scaling_governor` trong sysfs (cpufreq)
+    CpufreqWrite,
+    /// `roblox_booster_helper` đã cài và chạy được qua pkexec (tuning cần root)
+    PrivilegedHelper,
+    /// Kết nối được X server (X11/XWayland) để theo dõi cửa sổ đang focus
+    FocusTracking,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 6] = [
+        Capability::SysNice,
+        Capability::Kill,
+        Capability::CgroupDelegation,
+        Capability::CpufreqWrite,
+        Capability::PrivilegedHelper,
+        Capability::FocusTracking,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::SysNice => "cap-sys-nice",
+            Self::Kill => "cap-kill",
+            Self::CgroupDelegation => "cap-cgroup",
+            Self::CpufreqWrite => "cap-cpufreq",
+            Self::PrivilegedHelper => "cap-helper",
+            Self::FocusTracking => "cap-focus",
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&t!(self.key()))
+    }
+}
+
+static REPORT: OnceLock<Vec<(Capability, ProbeResult)>> = OnceLock::new();
+
+/// Kết quả probe của mọi capability (probe một lần, cache cho cả phiên)
+pub fn report() -> &'static [(Capability, ProbeResult)] {
+    REPORT.get_or_init(probe_all)
+}
+
+/// Kết quả đã cache của một capability
+pub fn probe(capability: Capability) -> ProbeResult {
+    report()
+        .iter()
+        .find(|(cap, _)| *cap == capability)
+        .map(|(_, result)| result.clone())
+        .unwrap_or_else(|| Err(t!("cap-unknown")))
+}
+
+/// Nice thấp nhất booster tự đặt được (cache cho cả phiên)
+///
+/// Root/CAP_SYS_NICE: -20; không thì `20 - RLIMIT_NICE`. Policy đòi nice thấp hơn
+/// (vd -10 của thread render khi chỉ được phép -5) được clamp về giá trị này.
+pub fn nice_floor() -> i32 {
+    static FLOOR: OnceLock<i32> = OnceLock::new();
+    *FLOOR.get_or_init(nice_floor_uncached)
+}
+
+#[cfg(target_os = "linux")]
+fn nice_floor_uncached() -> i32 {
+    if is_root() || has_effective_cap(CAP_SYS_NICE) {
+        return -20;
+    }
+    20_i64.saturating_sub(rlimit_nice()).clamp(-20, 20) as i32
+}
+
+// Windows: nice chỉ được map sang priority class, không có giới hạn kiểu RLIMIT_NICE
+#[cfg(not(target_os = "linux"))]
+fn nice_floor_uncached() -> i32 {
+    -20
+}
+
+/// Probe lại mọi capability (không dùng cache)
+pub fn probe_all() -> Vec<(Capability, ProbeResult)> {
+    Capability::ALL
+        .iter()
+        .map(|&capability| (capability, probe_uncached(capability)))
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn probe_uncached(capability: Capability) -> ProbeResult {
+    match capability {
+        Capability::SysNice => {
+            if is_root() {
+                return Ok("root".to_string());
+            }
+            if has_effective_cap(CAP_SYS_NICE) {
+                return Ok("CAP_SYS_NICE".to_string());
+            }
+            // nice thấp nhất được phép = 20 - RLIMIT_NICE
+            let limit = rlimit_nice();
+            if nice_floor_uncached() <= crate::process::BOOST_NICE {
+                Ok(format!("RLIMIT_NICE = {}", limit))
+            } else {
+                Err(t!("cap-sys-nice-missing", limit = limit))
+            }
+        }
+        Capability::Kill => {
+            if is_root() {
+                Ok("root".to_string())
+            } else if has_effective_cap(CAP_KILL) {
+                Ok("CAP_KILL".to_string())
+            } else {
+                Err(t!("cap-kill-missing"))
+            }
+        }
+        Capability::CgroupDelegation => {
+            let root = std::path::Path::new("/sys/fs/cgroup");
+            if !root.join("cgroup.controllers").is_file() {
+                return Err(t!("cap-cgroup-no-v2"));
+            }
+            let own = crate::process::read_cgroup(std::process::id())
+                .ok_or_else(|| t!("cap-cgroup-unknown"))?;
+            let dir = root.join(own.trim_start_matches('/'));
+            let controllers = std::fs::read_to_string(dir.join("cgroup.controllers")).unwrap_or_default();
+            if !controllers.split_whitespace().any(|controller| controller == "cpu") {
+                return Err(t!("cap-cgroup-no-cpu", path = dir.display()));
+            }
+            if !is_writable(&dir) {
+                return Err(t!("cap-not-writable", path = dir.display()));
+            }
+            Ok(dir.display().to_string())
+        }
+        Capability::CpufreqWrite => {
+            let path = std::path::Path::new("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor");
+            if !path.is_file() {
+                return Err(t!("cap-cpufreq-missing"));
+            }
+            if !is_writable(path) {
+                return Err(t!("cap-not-writable", path = path.display()));
+            }
+            Ok(path.display().to_string())
+        }
+        Capability::PrivilegedHelper => crate::helper::availability(),
+        Capability::FocusTracking => crate::focus::probe(),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn probe_uncached(capability: Capability) -> ProbeResult {
+    match capability {
+        // ABOVE_NORMAL_PRIORITY_CLASS không cần admin với process cùng user
+        Capability::SysNice if cfg!(target_os = "windows") => Ok("SetPriorityClass".to_string()),
+        _ => Err(t!("cap-linux-only")),
+    }
+}
+
+#[cfg(target_os = "linux")]
+const CAP_KILL: u32 = 5;
+#[cfg(target_os = "linux")]
+const CAP_SYS_NICE: u32 = 23;
+
+#[cfg(target_os = "linux")]
+fn is_root() -> bool {
+    // SAFETY: geteuid không có tham số, không thể lỗi
+    unsafe { libc::geteuid() == 0 }
+}
+
+/// Bit `cap` trong `CapEff` của `/proc/self/status`
+#[cfg(target_os = "linux")]
+fn has_effective_cap(cap: u32) -> bool {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("CapEff:"))
+                .and_then(|mask| u64::from_str_radix(mask.trim(), 16).ok())
+        })
+        .is_some_and(|mask| mask & (1 << cap) != 0)
+}
+
+#[cfg(target_os = "linux")]
+fn rlimit_nice() -> i64 {
+    let mut limit = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    // SAFETY: getrlimit chỉ ghi vào struct truyền vào
+    if unsafe { libc::getrlimit(libc::RLIMIT_NICE, &mut limit) } != 0 {
+        return 0;
+    }
+    if limit.rlim_cur == libc::RLIM_INFINITY {
+        i64::MAX
+    } else {
+        limit.rlim_cur as i64
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn is_writable(path: &std::path::Path) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(path) = std::ffi::CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+    // SAFETY: path là C string hợp lệ, sống tới hết lời gọi
+    unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 }
+}
+
+/// Một dòng báo cáo: `✓ <capability>: <chi tiết>` / `✗ <capability>: <lý do>`
+pub fn describe(capability: Capability, result: &ProbeResult) -> String {
+    match result {
+        Ok(detail) => format!("✓ {}: {}", capability, detail),
+        Err(reason) => format!("✗ {}: {}", capability, reason),
+    }
+}
@@ -0,0 +1,49 @@
+use crate::i18n::t;
+use crate::process;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Gốc cgroup v2
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// Đường dẫn tuyệt đối của cgroup (`/user.slice/...` → `/sys/fs/cgroup/user.slice/...`)
+pub fn absolute(cgroup: &str) -> PathBuf {
+    Path::new(CGROUP_ROOT).join(cgroup.trim_start_matches('/'))
+}
+
+/// cgroup `name` cạnh cgroup của booster (cùng cha → cùng quyền delegate), tạo nếu chưa có
+pub fn sibling(name: &str) -> Result<PathBuf, String> {
+    let own = process::read_cgroup(std::process::id()).ok_or_else(|| t!("cap-cgroup-unknown"))?;
+    let own = absolute(&own);
+    let parent = own.parent().ok_or_else(|| t!("cap-cgroup-unknown"))?;
+    let dir = parent.join(name);
+    if !dir.is_dir() {
+        fs::create_dir(&dir).map_err(|err| t!("cgroup-failed", path = dir.display(), error = err))?;
+    }
+    Ok(dir)
+}
+
+/// Chuyển process vào cgroup `dir`
+pub fn move_pid(pid: u32, dir: &Path) -> Result<(), String> {
+    let procs = dir.join("cgroup.procs");
+    fs::write(&procs, pid.to_string()).map_err(|err| t!("cgroup-move-failed", pid = pid, path = dir.display(), error = err))
+}
+
+/// Ghi một file interface của cgroup, vd `cpu.weight`
+pub fn write(dir: &Path, file: &str, value: &str) -> Result<(), String> {
+    let path = dir.join(file);
+    fs::write(&path, value).map_err(|err| t!("cgroup-failed", path = path.display(), error = err))
+}
+
+/// Đọc một file interface của cgroup, `None` nếu không có (controller chưa bật)
+pub fn read(dir: &Path, file: &str) -> Option<String> {
+    fs::read_to_string(dir.join(file)).ok().map(|value| value.trim().to_string())
+}
+
+/// Xóa cgroup nếu không còn process nào (rmdir báo lỗi khi còn process → bỏ qua)
+pub fn remove_if_empty(dir: &Path) {
+    let empty = fs::read_to_string(dir.join("cgroup.procs")).is_ok_and(|procs| procs.trim().is_empty());
+    if empty {
+        let _ = fs::remove_dir(dir);
+    }
+}
@@ -0,0 +1,675 @@
+use crate::autostart::{Autostart, AutostartKind};
+use crate::booster::RobloxBoosterEngine;
+use crate::capabilities;
+use crate::config::{self, Config, LayeredConfig};
+use crate::focus::{self, Focus};
+use crate::i18n::t;
+use crate::journal;
+use crate::launcher::{Client, LaunchRequest};
+use crate::logging::{log_error, log_info, log_warn};
+use crate::optimizations::{self, Availability};
+use crate::paths;
+use crate::session;
+use crate::uri_handler::{self, UriHandler};
+use crate::wine::{self, WinePrefix};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use sysinfo::{Pid, System};
+
+/// Thời gian tối đa chờ process Roblox xuất hiện sau khi launch (giây)
+const LAUNCH_WAIT_SECONDS: u32 = 30;
+
+/// Subcommand (không có → chạy GUI)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// `config show`: in config hiệu lực và nguồn của từng giá trị
+    ShowConfig,
+    /// `schema [path]`: in JSON Schema của config.json (hoặc ghi ra file)
+    Schema { output: Option<PathBuf> },
+    /// `autostart [status | install [--systemd] | remove]`: chạy booster khi đăng nhập
+    Autostart(AutostartAction),
+    /// `doctor`: kiểm tra môi trường, quyền hệ thống và optimization nào chạy được
+    Doctor,
+    /// `plan`: chạy một chu kỳ dry-run, in các hành động dự kiến rồi thoát
+    Plan,
+    /// `recover`: hoàn tác các thay đổi hệ thống còn trong journal (sau khi booster bị kill)
+    Recover,
+    /// `launch <uri>`: áp dụng profile theo place rồi mở Roblox với URI `roblox-player:` / `roblox://`
+    Launch { uri: String },
+    /// `handler [status | install | remove]`: đăng ký booster làm handler URI `roblox-player:` / `roblox:`
+    Handler(HandlerAction),
+    /// `wine [status | apply [profile] | restore]`: tuning Wine prefix có Roblox
+    Wine(WineAction),
+    /// `focus [--watch]`: cửa sổ đang focus và instance Roblox tương ứng (thử với Xvfb)
+    Focus { watch: bool },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WineAction {
+    Status,
+    /// Áp dụng `wine` của profile (không có tên → profile mặc định)
+    Apply { profile: Option<String> },
+    Restore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandlerAction {
+    Status,
+    Install,
+    Remove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutostartAction {
+    Status,
+    Install(AutostartKind),
+    Remove,
+}
+
+/// Tham số dòng lệnh
+#[derive(Debug, Clone, Default)]
+pub struct Cli {
+    /// `--config <path>`: dùng file config khác thay vì vị trí chuẩn
+    pub config_path: Option<PathBuf>,
+    /// `--set field=value` (có thể lặp lại), override mọi layer khác
+    pub overrides: Vec<(String, String)>,
+    pub command: Option<CliCommand>,
+}
+
+impl Cli {
+    pub fn parse() -> Result<Self, String> {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    pub fn parse_from<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut cli = Self::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            if let Some(value) = arg.strip_prefix("--config=") {
+                cli.config_path = Some(PathBuf::from(value));
+                continue;
+            }
+            if let Some(value) = arg.strip_prefix("--set=") {
+                cli.overrides.push(parse_override(value)?);
+                continue;
+            }
+
+            match arg.as_str() {
+                "--config" | "-c" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| t!("cli-missing-value", arg = arg))?;
+                    cli.config_path = Some(PathBuf::from(value));
+                }
+                "--set" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| t!("cli-missing-value", arg = arg))?;
+                    cli.overrides.push(parse_override(&value)?);
+                }
+                "config" if cli.command.is_none() => {
+                    match args.next().as_deref() {
+                        Some("show") | None => cli.command = Some(CliCommand::ShowConfig),
+                        Some(other) => return Err(t!("cli-invalid-config-command", command = other)),
+                    }
+                }
+                "schema" if cli.command.is_none() => {
+                    cli.command = Some(CliCommand::Schema {
+                        output: args.next().map(PathBuf::from),
+                    });
+                }
+                "autostart" if cli.command.is_none() => {
+                    let action = match args.next().as_deref() {
+                        Some("status") | None => AutostartAction::Status,
+                        Some("install") => {
+                            if args.next_if(|arg| arg == "--systemd").is_some() {
+                                AutostartAction::Install(AutostartKind::Systemd)
+                            } else {
+                                AutostartAction::Install(AutostartKind::Desktop)
+                            }
+                        }
+                        Some("remove") => AutostartAction::Remove,
+                        Some(other) => {
+                            return Err(t!("cli-invalid-autostart-command", command = other))
+                        }
+                    };
+                    cli.command = Some(CliCommand::Autostart(action));
+                }
+                "--dry-run" => cli.overrides.push(("dry_run".to_string(), "true".to_string())),
+                "doctor" if cli.command.is_none() => cli.command = Some(CliCommand::Doctor),
+                "plan" if cli.command.is_none() => cli.command = Some(CliCommand::Plan),
+                "recover" if cli.command.is_none() => cli.command = Some(CliCommand::Recover),
+                "launch" if cli.command.is_none() => {
+                    let uri = args
+                        .next()
+                        .ok_or_else(|| t!("cli-missing-value", arg = arg))?;
+                    cli.command = Some(CliCommand::Launch { uri });
+                }
+                "handler" if cli.command.is_none() => {
+                    let action = match args.next().as_deref() {
+                        Some("status") | None => HandlerAction::Status,
+                        Some("install") => HandlerAction::Install,
+                        Some("remove") => HandlerAction::Remove,
+                        Some(other) => return Err(t!("cli-invalid-handler-command", command = other)),
+                    };
+                    cli.command = Some(CliCommand::Handler(action));
+                }
+                "wine" if cli.command.is_none() => {
+                    let action = match args.next().as_deref() {
+                        Some("status") | None => WineAction::Status,
+                        Some("apply") => WineAction::Apply {
+                            profile: args.next_if(|arg| !arg.starts_with('-')),
+                        },
+                        Some("restore") => WineAction::Restore,
+                        Some(other) => return Err(t!("cli-invalid-wine-command", command = other)),
+                    };
+                    cli.command = Some(CliCommand::Wine(action));
+                }
+                "focus" if cli.command.is_none() => {
+                    cli.command = Some(CliCommand::Focus {
+                        watch: args.next_if(|arg| arg == "--watch").is_some(),
+                    });
+                }
+                other => return Err(t!("cli-invalid-argument", arg = other)),
+            }
+        }
+
+        Ok(cli)
+    }
+}
+
+fn parse_override(value: &str) -> Result<(String, String), String> {
+    let (field, raw) = value
+        .split_once('=')
+        .ok_or_else(|| t!("cli-invalid-set", value = value))?;
+    Ok((field.trim().to_string(), raw.to_string()))
+}
+
+pub fn usage() -> String {
+    t!("cli-usage")
+}
+
+/// Chạy subcommand, trả về exit code
+pub fn run(command: &CliCommand) -> i32 {
+    match command {
+        CliCommand::ShowConfig => show_config(),
+        CliCommand::Schema { output } => schema(output.as_deref()),
+        CliCommand::Autostart(action) => autostart(*action),
+        CliCommand::Doctor => doctor(),
+        CliCommand::Plan => plan(),
+        CliCommand::Recover => {
+            // Giữ khóa tới khi recover xong để booster khác không khởi động giữa chừng
+            let _lock = match journal::lock_instance() {
+                Ok(lock) => lock,
+                Err(err) => {
+                    eprintln!("✗ {}", err);
+                    return 1;
+                }
+            };
+            if journal::recover_logged() {
+                0
+            } else {
+                1
+            }
+        }
+        CliCommand::Launch { uri } => launch(uri),
+        CliCommand::Handler(action) => handler(*action),
+        CliCommand::Wine(action) => wine_command(action),
+        CliCommand::Focus { watch } => focus_command(*watch),
+    }
+}
+
+fn autostart(action: AutostartAction) -> i32 {
+    let autostart = match Autostart::resolve(paths::get()) {
+        Ok(autostart) => autostart,
+        Err(err) => {
+            eprintln!("✗ {}", err);
+            return 1;
+        }
+    };
+
+    let result = match action {
+        AutostartAction::Status => {
+            for (kind, path) in [
+                (AutostartKind::Desktop, &autostart.desktop_file),
+                (AutostartKind::Systemd, &autostart.systemd_unit),
+            ] {
+                let status = if autostart.installed().contains(&kind) {
+                    t!("autostart-status-installed")
+                } else {
+                    t!("autostart-status-missing")
+                };
+                println!("{} {}", status, path.display());
+            }
+            return 0;
+        }
+        AutostartAction::Install(kind) => autostart.install(kind).map(|_| {
+            let path = match kind {
+                AutostartKind::Desktop => &autostart.desktop_file,
+                AutostartKind::Systemd => &autostart.systemd_unit,
+            };
+            t!("autostart-installed", path = path.display())
+        }),
+        AutostartAction::Remove => autostart.remove().map(|_| t!("autostart-removed")),
+    };
+
+    match result {
+        Ok(message) => {
+            println!("{}", message);
+            0
+        }
+        Err(err) => {
+            eprintln!("{}", t!("autostart-failed", error = err));
+            1
+        }
+    }
+}
+
+fn wine_command(action: &WineAction) -> i32 {
+    let config = Config::load();
+    let prefixes = wine::discover();
+
+    match action {
+        WineAction::Status => {
+            if prefixes.is_empty() {
+                println!("{}", t!("wine-no-prefix"));
+            }
+            for prefix in &prefixes {
+                println!("🍷 {}", prefix.path.display());
+                println!("   {}", prefix.player.display());
+                if prefix.has_backup() {
+                    println!("   {}", t!("wine-status-modified"));
+                }
+            }
+            0
+        }
+        WineAction::Apply { profile } => {
+            let profile = match profile {
+                Some(name) => config.profiles.iter().find(|profile| &profile.name == name),
+                None => config.profile_for(None),
+            };
+            let Some(profile) = profile else {
+                eprintln!("✗ {}", t!("wine-profile-not-found"));
+                return 1;
+            };
+            let prefixes = match wine::target_prefixes(&profile.wine) {
+                Ok(prefixes) => prefixes,
+                Err(err) => {
+                    eprintln!("✗ {}", err);
+                    return 1;
+                }
+            };
+            if prefixes.is_empty() {
+                eprintln!("{}", t!("wine-no-prefix"));
+                return 1;
+            }
+
+            let mut code = 0;
+            for prefix in prefixes {
+                println!("🍷 {} ({})", prefix.path.display(), profile.name);
+                match prefix.apply(&profile.wine) {
+                    Ok(changed) if changed.is_empty() => println!("   {}", t!("wine-no-change")),
+                    Ok(changed) => {
+                        for path in changed {
+                            println!("   {}", t!("wine-file-updated", path = path.display()));
+                        }
+                    }
+                    Err(err) => {
+                        eprintln!("   ✗ {}", err);
+                        code = 1;
+                    }
+                }
+                for (key, value) in profile.wine.launch_env(&prefix.path) {
+                    println!("   {}={}", key, value);
+                }
+            }
+            code
+        }
+        WineAction::Restore => {
+            let mut code = 0;
+            for prefix in prefixes {
+                match prefix.restore() {
+                    Ok(restored) => {
+                        for path in restored {
+                            println!("{}", t!("wine-file-restored", path = path.display()));
+                        }
+                    }
+                    Err(err) => {
+                        eprintln!("✗ {}: {}", prefix.path.display(), err);
+                        code = 1;
+                    }
+                }
+            }
+            code
+        }
+    }
+}
+
+fn handler(action: HandlerAction) -> i32 {
+    let handler = match UriHandler::resolve(paths::get()) {
+        Ok(handler) => handler,
+        Err(err) => {
+            eprintln!("✗ {}", err);
+            return 1;
+        }
+    };
+
+    let result = match action {
+        HandlerAction::Status => {
+            let registered = handler.registered_schemes();
+            for scheme in uri_handler::SCHEMES {
+                if registered.contains(&scheme) {
+                    println!("{} {}:", t!("autostart-status-installed"), scheme);
+                } else {
+                    println!("{} {}:", t!("autostart-status-missing"), scheme);
+                }
+                if let Some(previous) = handler.previous_handler(scheme) {
+                    println!("   {}", t!("handler-forward-to", handler = previous));
+                }
+            }
+            println!("📁 {}", handler.mimeapps_file.display());
+            return 0;
+        }
+        HandlerAction::Install => handler
+            .install()
+            .map(|_| t!("handler-installed", path = handler.desktop_file.display())),
+        HandlerAction::Remove => handler.remove().map(|_| t!("handler-removed")),
+    };
+
+    match result {
+        Ok(message) => {
+            println!("{}", message);
+            0
+        }
+        Err(err) => {
+            eprintln!("{}", t!("handler-failed", error = err));
+            1
+        }
+    }
+}
+
+fn doctor() -> i32 {
+    println!("{}", t!("cli-doctor-environment"));
+    println!(
+        "   OS: {} ({}), kernel {}",
+        System::long_os_version().unwrap_or_else(|| std::env::consts::OS.to_string()),
+        std::env::consts::ARCH,
+        System::kernel_version().unwrap_or_else(|| "?".to_string())
+    );
+    #[cfg(unix)]
+    {
+        // SAFETY: getuid/geteuid không có tham số, không thể lỗi
+        let (uid, euid) = unsafe { (libc::getuid(), libc::geteuid()) };
+        println!("   uid {} / euid {}", uid, euid);
+    }
+    println!();
+
+    println!("{}", t!("cli-doctor-capabilities"));
+    for (capability, result) in capabilities::probe_all() {
+        println!("   {}", capabilities::describe(capability, &result));
+    }
+    println!();
+
+    let config = Config::load();
+    println!("{}", t!("cli-doctor-optimizations"));
+    for optimization in optimizations::registry() {
+        let state = if config.is_enabled(optimization.id()) {
+            String::new()
+        } else {
+            format!(" ({})", t!("main-banner-disabled"))
+        };
+        match optimization.availability() {
+            Availability::Available => println!("   ✓ {}{}", optimization.name(), state),
+            Availability::Unavailable(reason) => {
+                println!("   ✗ {}{}: {}", optimization.name(), state, reason)
+            }
+        }
+    }
+    0
+}
+
+fn plan() -> i32 {
+    let engine = RobloxBoosterEngine::new(Config::load());
+    println!("{}", t!("cli-plan-header"));
+    let plans = engine.plan();
+    println!();
+    for (optimization, actions) in plans {
+        println!("{}", optimization.name());
+        if actions.is_empty() {
+            println!("   {}", t!("cli-plan-no-change"));
+        }
+        for action in actions {
+            println!("   • {}", action);
+        }
+    }
+    0
+}
+
+fn launch(uri: &str) -> i32 {
+    let request = match LaunchRequest::parse(uri) {
+        Ok(request) => request,
+        Err(err) => {
+            eprintln!("✗ {}", err);
+            return 2;
+        }
+    };
+    log_info!("{}", t!("launch-request", request = request));
+
+    let mut config = Config::load();
+    let profile = config.profile_for(request.place_id).cloned();
+    if let Some(profile) = &profile {
+        log_info!("{}", t!("launch-profile", profile = profile.name));
+        config = config.with_profile(profile);
+    }
+    let wine_settings = profile.map(|profile| profile.wine).unwrap_or_default();
+
+    // Profile chỉ định prefix → chạy Roblox trong prefix đó
+    let client = match &wine_settings.prefix {
+        Some(path) => WinePrefix::open(path)
+            .map(Client::from)
+            .ok_or_else(|| t!("wine-prefix-no-roblox", path = path.display())),
+        None => Client::detect(&request),
+    };
+    let client = match client {
+        Ok(client) => client,
+        Err(err) => {
+            log_error!("✗ {}", err);
+            return 1;
+        }
+    };
+    log_info!("{}", t!("launch-client", client = client));
+
+    let mut env = Vec::new();
+    if let Client::Wine { prefix, exe } = &client {
+        let wine_prefix = WinePrefix {
+            path: prefix.clone(),
+            player: exe.clone(),
+        };
+        match wine_prefix.apply(&wine_settings) {
+            Ok(changed) => {
+                for path in changed {
+                    log_info!("{}", t!("wine-file-updated", path = path.display()));
+                }
+            }
+            Err(err) => log_warn!("⚠️  {}", err),
+        }
+        env = wine_settings.launch_env(prefix);
+    }
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            log_error!("{}", t!("engine-no-runtime", error = err));
+            return 1;
+        }
+    };
+    runtime.block_on(async move {
+        let engine = Arc::new(RobloxBoosterEngine::new(config));
+        engine.recover_journal();
+        if let Err(err) = engine.watch_focus() {
+            log_info!("ℹ️  {}", err);
+        }
+        engine.prepare_launch();
+
+        let mut child = match client.spawn(&request, &env) {
+            Ok(child) => child,
+            Err(err) => {
+                log_error!("✗ {}", err);
+                engine.revert_optimizations();
+                return 1;
+            }
+        };
+
+        // Boost loop chỉ có ích khi process Roblox đã xuất hiện
+        let own_pid = std::process::id();
+        let engine_config = engine.config();
+        for _ in 0..LAUNCH_WAIT_SECONDS {
+            let mut sys = System::new();
+            sys.refresh_processes();
+            let started = sys.processes().iter().any(|(pid, process)| {
+                pid.as_u32() != own_pid && engine_config.is_roblox_process(process.name())
+            });
+            if started {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+        }
+        if let Err(err) = engine.start().await {
+            log_error!("✗ {}", err);
+        }
+
+        let status = tokio::task::spawn_blocking(move || child.wait()).await;
+        log_info!("{}", t!("launch-client-exited"));
+        if engine.stop().is_err() {
+            engine.revert_optimizations();
+        }
+        match status {
+            Ok(Ok(status)) if status.success() => 0,
+            _ => 1,
+        }
+    })
+}
+
+fn focus_command(watch: bool) -> i32 {
+    let config = Config::load();
+    let describe = |focus: Focus| -> String {
+        let Focus::Window { window, pid } = focus else {
+            return t!("focus-unknown");
+        };
+        if window == 0 {
+            return t!("focus-no-window");
+        }
+        let Some(pid) = pid else {
+            return t!("focus-window-no-pid", window = format!("{:#x}", window));
+        };
+
+        let mut sys = System::new();
+        sys.refresh_processes();
+        // Client trong sandbox: đổi PID trong namespace của nó về PID theo booster
+        let pid = pid.host_pid(&sys).unwrap_or(pid.pid);
+        let name = sys
+            .process(Pid::from_u32(pid))
+            .map_or_else(|| "?".to_string(), |process| process.name().to_string());
+        let sessions = session::detect(&sys, &config);
+        let window = format!("{:#x}", window);
+        match session::primary(&sessions, focus)
+            .and_then(|root| sessions.iter().position(|session| session.root == root))
+        {
+            Some(index) => t!(
+                "focus-roblox",
+                window = window,
+                pid = pid,
+                name = name,
+                instance = index + 1
+            ),
+            None => t!("focus-other", window = window, pid = pid, name = name),
+        }
+    };
+
+    if !watch {
+        let focus = focus::current();
+        println!("{}", describe(focus));
+        return if focus.is_known() { 0 } else { 1 };
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    if let Err(err) = focus::watch(move |focus| {
+        let _ = sender.send(focus);
+    }) {
+        eprintln!("✗ {}", err);
+        return 1;
+    }
+    // Watcher dừng (mất kết nối X11) → sender bị drop → thoát
+    for focus in receiver {
+        println!("{}", describe(focus));
+    }
+    1
+}
+
+fn schema(output: Option<&std::path::Path>) -> i32 {
+    let Some(path) = output else {
+        match serde_json::to_string_pretty(&config::json_schema()) {
+            Ok(data) => println!("{}", data),
+            Err(err) => {
+                eprintln!("✗ {}", err);
+                return 1;
+            }
+        }
+        return 0;
+    };
+
+    match config::write_schema_file(path) {
+        Ok(()) => {
+            println!("{}", t!("cli-schema-written", path = path.display()));
+            0
+        }
+        Err(err) => {
+            eprintln!("{}", t!("cli-write-failed", path = path.display(), error = err));
+            1
+        }
+    }
+}
+
+fn show_config() -> i32 {
+    let app_paths = paths::get();
+    for (label, path) in [
+        ("System", &app_paths.system_config_file),
+        ("User  ", &app_paths.config_file),
+    ] {
+        let status = if path.is_file() {
+            String::new()
+        } else {
+            format!(" {}", t!("cli-file-missing"))
+        };
+        println!("📁 {}: {}{}", label, path.display(), status);
+    }
+    println!();
+
+    let layered = match LayeredConfig::load_strict() {
+        Ok(layered) => layered,
+        Err(err) => {
+            eprintln!("✗ {}", err);
+            return 1;
+        }
+    };
+
+    let Ok(Value::Object(values)) = serde_json::to_value(&layered.config) else {
+        return 1;
+    };
+
+    println!(
+        "{:<28} {:<10} {}",
+        "FIELD",
+        t!("cli-column-source"),
+        t!("cli-column-value")
+    );
+    for (field, value) in &values {
+        println!("{:<28} {:<10} {}", field, layered.source_of(field).to_string(), value);
+    }
+    0
+}
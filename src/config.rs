@@ -1,17 +1,117 @@
+use crate::i18n::t;
+use crate::logging::{log_error, log_warn};
+use crate::optimizations;
+use crate::paths;
+use crate::session::{InstancePolicy, PowerSavingPolicy};
+use crate::threads::{self, ThreadClass, ThreadPolicy};
+use crate::wine::WineSettings;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
 use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
 
-const CONFIG_PATH: &str = "config.json";
+/// Prefix của biến môi trường override config, vd `ROBLOX_BOOSTER_BOOST_INTERVAL_SECONDS=30`
+pub const ENV_PREFIX: &str = "ROBLOX_BOOSTER_";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Tên file JSON Schema, ghi cạnh config.json và được tham chiếu qua `$schema`
+pub const SCHEMA_FILE_NAME: &str = "config.schema.json";
+
+/// `--set field=value` từ CLI (set một lần trong `main`)
+static CLI_OVERRIDES: OnceLock<Vec<(String, String)>> = OnceLock::new();
+
+/// Giới hạn của `boost_interval_seconds` (0 = mặc định 60s)
+pub const MAX_BOOST_INTERVAL_SECONDS: u64 = 3600;
+
+/// Một thay đổi giữa hai config (dùng cho hot reload)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Ngôn ngữ giao diện và log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Theo locale của hệ điều hành
+    #[default]
+    Auto,
+    Vi,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::Auto, Language::Vi, Language::En];
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self {
+            Self::Auto => "language-auto",
+            Self::Vi => "language-vi",
+            Self::En => "language-en",
+        };
+        f.write_str(&t!(key))
+    }
+}
+
+/// Cấu hình Roblox Booster (config.json)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
+#[schemars(title = "Roblox Booster config")]
 pub struct Config {
+    /// Tự bật booster khi mở app
     pub auto_start: bool,
+    /// Chu kỳ boost loop (giây), 0 = mặc định 60
+    #[schemars(range(max = 3600))]
     pub boost_interval_seconds: u64,
-    pub enable_timer_resolution: bool,
-    pub enable_memory_cleanup: bool,
-    pub enable_auto_detection: bool,
+    /// Tên process coi như Roblox (ngoài các process có chữ "roblox"), so khớp không phân biệt hoa thường
+    pub roblox_process_names: Vec<String>,
+    /// Process bị tạm dừng (SIGSTOP) khi đang chơi Roblox, chạy lại khi Roblox tắt hoặc booster tắt
+    pub pause_process_names: Vec<String>,
+    /// CPU cho cả session Roblox (player, launcher, wineserver), vd `0-7` = chỉ P-core; không có = không đổi
+    pub roblox_affinity: Option<String>,
+    /// Policy theo nhóm thread của Roblox (`render`, `worker`, `audio`, `network`, `other`)
+    pub thread_policies: BTreeMap<ThreadClass, ThreadPolicy>,
+    /// Chạy nhiều client cùng lúc: instance chính được core tốt nhất, instance nền bị hạ priority
+    pub multi_instance: InstancePolicy,
+    /// Boost theo cửa sổ đang focus (X11/XWayland): Roblox không focus bị coi là instance nền
+    pub follow_focus: bool,
+    /// Tiết kiệm pin khi Roblox không được focus hoặc người dùng AFK (giới hạn CPU + hạ priority)
+    pub power_saving: PowerSavingPolicy,
+    /// Ngôn ngữ: `auto` (theo hệ thống), `vi` hoặc `en`
+    pub language: Language,
+    /// Dry-run: vẫn phát hiện + quyết định nhưng chỉ log hành động dự kiến (trước → sau), không thay đổi hệ thống
+    pub dry_run: bool,
+    /// Profile theo game, chọn theo place ID khi launch Roblox qua booster
+    pub profiles: Vec<Profile>,
+    /// Bật/tắt từng optimization đã đăng ký: field `enable_<id>` (vd `enable_timer_resolution`)
+    ///
+    /// Schema của các field này được sinh từ registry trong `json_schema`.
+    #[serde(flatten)]
+    #[schemars(skip)]
+    pub optimizations: BTreeMap<String, bool>,
+}
+
+/// Profile theo game: ghi đè `enable_<id>` của config chung
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Profile {
+    pub name: String,
+    /// Place ID dùng profile này, rỗng = profile mặc định cho mọi place
+    #[serde(default)]
+    pub place_ids: Vec<u64>,
+    /// `enable_<id>` → bật/tắt, vd `{"enable_pause_list": false}`
+    #[serde(default)]
+    pub optimizations: BTreeMap<String, bool>,
+    /// Tuning Wine prefix khi Roblox chạy qua Wine
+    #[serde(default, skip_serializing_if = "WineSettings::is_empty")]
+    pub wine: WineSettings,
 }
 
 impl Default for Config {
@@ -19,37 +119,643 @@ impl Default for Config {
         Self {
             auto_start: false,
             boost_interval_seconds: 60,
-            enable_timer_resolution: true,
-            enable_memory_cleanup: true,
-            enable_auto_detection: true,
+            roblox_process_names: Vec::new(),
+            pause_process_names: Vec::new(),
+            roblox_affinity: None,
+            thread_policies: threads::default_policies(),
+            multi_instance: InstancePolicy::default(),
+            follow_focus: true,
+            power_saving: PowerSavingPolicy::default(),
+            language: Language::default(),
+            dry_run: false,
+            profiles: Vec::new(),
+            optimizations: optimizations::registry()
+                .iter()
+                .map(|optimization| {
+                    (optimizations::config_key(optimization.id()), optimization.default_enabled())
+                })
+                .collect(),
         }
     }
 }
 
 impl Config {
+    /// Load config hiệu lực (defaults → system → user → env → CLI)
     pub fn load() -> Self {
-        let contents = match fs::read_to_string(CONFIG_PATH) {
-            Ok(contents) => contents,
-            Err(_) => {
-                let config = Self::default();
-                let _ = config.save();
-                return config;
+        LayeredConfig::load().config
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.boost_interval_seconds > MAX_BOOST_INTERVAL_SECONDS {
+            return Err(t!(
+                "config-interval-too-large",
+                max = MAX_BOOST_INTERVAL_SECONDS,
+                value = self.boost_interval_seconds
+            ));
+        }
+        if let Some(list) = &self.roblox_affinity {
+            crate::process::parse_cpu_list(list)?;
+        }
+        self.multi_instance
+            .validate()
+            .map_err(|err| format!("multi_instance: {}", err))?;
+        self.power_saving
+            .validate()
+            .map_err(|err| format!("power_saving: {}", err))?;
+        for (class, policy) in &self.thread_policies {
+            policy
+                .validate()
+                .map_err(|err| format!("thread_policies.{}: {}", class, err))?;
+        }
+        let defaults = Config::default();
+        for profile in &self.profiles {
+            if let Some(key) = profile
+                .optimizations
+                .keys()
+                .find(|key| !defaults.optimizations.contains_key(*key))
+            {
+                return Err(t!("config-profile-unknown-key", profile = &profile.name, key = key));
             }
+            profile
+                .wine
+                .validate()
+                .map_err(|err| format!("profile '{}': {}", profile.name, err))?;
+        }
+        Ok(())
+    }
+
+    /// Profile cho place: profile có `place_id` trong `place_ids`, không có thì profile mặc định
+    pub fn profile_for(&self, place_id: Option<u64>) -> Option<&Profile> {
+        place_id
+            .and_then(|id| self.profiles.iter().find(|profile| profile.place_ids.contains(&id)))
+            .or_else(|| self.profiles.iter().find(|profile| profile.place_ids.is_empty()))
+    }
+
+    /// Config sau khi áp dụng override của profile
+    pub fn with_profile(&self, profile: &Profile) -> Config {
+        let mut config = self.clone();
+        config.optimizations.extend(
+            profile
+                .optimizations
+                .iter()
+                .map(|(key, enabled)| (key.clone(), *enabled)),
+        );
+        config
+    }
+
+    /// Interval thực tế của boost loop (0 → 60s)
+    pub fn effective_interval_seconds(&self) -> u64 {
+        if self.boost_interval_seconds == 0 {
+            60
+        } else {
+            self.boost_interval_seconds
+        }
+    }
+
+    /// Process có phải Roblox không (built-in + `roblox_process_names`)
+    pub fn is_roblox_process(&self, name: &str) -> bool {
+        crate::booster::is_roblox_process(name) || contains_name(&self.roblox_process_names, name)
+    }
+
+    pub fn is_pause_process(&self, name: &str) -> bool {
+        contains_name(&self.pause_process_names, name)
+    }
+
+    /// Optimization `id` có được bật không (thiếu field → mặc định của optimization)
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.optimizations
+            .get(&optimizations::config_key(id))
+            .copied()
+            .unwrap_or_else(|| {
+                optimizations::registry()
+                    .iter()
+                    .any(|optimization| optimization.id() == id && optimization.default_enabled())
+            })
+    }
+
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        self.optimizations.insert(optimizations::config_key(id), enabled);
+    }
+
+    /// Liệt kê các field khác nhau giữa `self` (cũ) và `other` (mới)
+    pub fn diff(&self, other: &Self) -> Vec<ConfigChange> {
+        let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+            (serde_json::to_value(self), serde_json::to_value(other))
+        else {
+            return Vec::new();
         };
 
-        match serde_json::from_str(&contents) {
-            Ok(config) => config,
-            Err(_) => {
-                let config = Self::default();
-                let _ = config.save();
-                config
+        new.iter()
+            .filter_map(|(field, new_value)| {
+                let old_value = old.get(field).unwrap_or(&serde_json::Value::Null);
+                (old_value != new_value).then(|| ConfigChange {
+                    field: field.clone(),
+                    old: old_value.to_string(),
+                    new: new_value.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Ghi các field đã sửa (khác `original`, config hiệu lực trước khi sửa) vào file config của user
+    ///
+    /// Field khác trong user file giữ nguyên; giá trị chỉ đến từ env/CLI (vd
+    /// `--dry-run`) không bị lưu. Field sửa về đúng defaults + system config bị
+    /// xóa khỏi user file để giá trị admin đặt trong system config vẫn có hiệu lực.
+    pub fn save(&self, original: &Config) -> io::Result<()> {
+        let app_paths = paths::get();
+        self.save_to(
+            original,
+            &app_paths.config_file,
+            &LayeredConfig::base_value(&app_paths.system_config_file),
+        )
+    }
+
+    /// `save` với path và base layer (defaults + system) cho trước
+    fn save_to(&self, original: &Config, path: &Path, base: &Map<String, Value>) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+
+        // User file lỗi → không ghi đè, tránh mất nội dung user chưa sửa được
+        let mut user = read_file_layer(path)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+            .unwrap_or_default();
+        if let (Value::Object(before), Value::Object(after)) = (to_value(original), to_value(self)) {
+            for (field, value) in after {
+                if before.get(&field) == Some(&value) {
+                    continue;
+                }
+                if base.get(&field) == Some(&value) {
+                    user.remove(&field);
+                } else {
+                    user.insert(field, value);
+                }
             }
         }
-    }
 
-    pub fn save(&self) -> io::Result<()> {
-        let data = serde_json::to_string_pretty(self)
+        // Schema cho editor (autocomplete + validate); lỗi ghi schema không chặn việc lưu config
+        if let Some(dir) = path.parent() {
+            if write_schema_file(&dir.join(SCHEMA_FILE_NAME)).is_ok() {
+                user.insert(
+                    "$schema".to_string(),
+                    Value::String(format!("./{}", SCHEMA_FILE_NAME)),
+                );
+            }
+        }
+
+        let data = serde_json::to_string_pretty(&Value::Object(user))
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
-        fs::write(CONFIG_PATH, data)
+        fs::write(path, data)
+    }
+}
+
+/// Nguồn của một giá trị config (layer sau override layer trước)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigLayer {
+    Default,
+    System,
+    User,
+    Env,
+    Cli,
+}
+
+impl fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Default => return f.write_str(&t!("config-layer-default")),
+            Self::System => "system",
+            Self::User => "user",
+            Self::Env => "env",
+            Self::Cli => "cli",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Config hiệu lực + layer mà mỗi field lấy giá trị
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    pub config: Config,
+    pub sources: BTreeMap<String, ConfigLayer>,
+}
+
+impl LayeredConfig {
+    /// Load tất cả layers; layer lỗi bị bỏ qua (in cảnh báo)
+    pub fn load() -> Self {
+        match Self::build(false) {
+            Ok(layered) => layered,
+            Err(err) => {
+                log_error!("✗ {}", err);
+                Self::defaults()
+            }
+        }
+    }
+
+    /// Load tất cả layers; layer nào lỗi hoặc có field không biết → `Err` (dùng cho hot reload)
+    pub fn load_strict() -> Result<Self, String> {
+        Self::build(true)
+    }
+
+    pub fn source_of(&self, field: &str) -> ConfigLayer {
+        self.sources
+            .get(field)
+            .copied()
+            .unwrap_or(ConfigLayer::Default)
+    }
+
+    fn defaults() -> Self {
+        let config = Config::default();
+        let sources = field_names(&config)
+            .into_iter()
+            .map(|field| (field, ConfigLayer::Default))
+            .collect();
+        Self { config, sources }
+    }
+
+    /// Defaults + system config (phần user file không được ghi lặp lại)
+    fn base_value(system_config_file: &Path) -> Map<String, Value> {
+        let mut base = match to_value(&Config::default()) {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        if let Ok(Some(system)) = read_file_layer(system_config_file) {
+            base.extend(system);
+        }
+        base
+    }
+
+    fn build(strict: bool) -> Result<Self, String> {
+        let app_paths = paths::get();
+        Self::build_from(
+            strict,
+            &app_paths.system_config_file,
+            &app_paths.config_file,
+            std::env::vars(),
+            CLI_OVERRIDES.get().map(Vec::as_slice),
+        )
+    }
+
+    /// Merge defaults → `system_file` → `user_file` → biến `ENV_PREFIX*` trong `env` → `cli`
+    ///
+    /// `strict`: layer lỗi hoặc có field không biết → `Err`; không thì bỏ qua (in cảnh báo).
+    fn build_from(
+        strict: bool,
+        system_file: &Path,
+        user_file: &Path,
+        env: impl IntoIterator<Item = (String, String)>,
+        cli: Option<&[(String, String)]>,
+    ) -> Result<Self, String> {
+        let mut layered = Self::defaults();
+        let mut merged = match to_value(&layered.config) {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+
+        let layers = [
+            (ConfigLayer::System, read_file_layer(system_file)),
+            (ConfigLayer::User, read_file_layer(user_file)),
+            (ConfigLayer::Env, Ok(Some(env_layer(&merged, env)))),
+            (ConfigLayer::Cli, cli_layer(&merged, cli)),
+        ];
+
+        for (layer, values) in layers {
+            let values = match values {
+                Ok(Some(values)) => values,
+                Ok(None) => continue,
+                Err(err) if strict => return Err(t!("config-layer-error", layer = layer, error = err)),
+                Err(err) => {
+                    log_error!("{}", t!("config-layer-skipped", layer = layer, error = err));
+                    continue;
+                }
+            };
+
+            let mut candidate = merged.clone();
+            for (field, value) in &values {
+                if field == "$schema" {
+                    continue;
+                }
+                if candidate.contains_key(field) {
+                    candidate.insert(field.clone(), value.clone());
+                } else if strict {
+                    let err = t!("config-unknown-field-name", field = field);
+                    return Err(t!("config-layer-error", layer = layer, error = err));
+                } else {
+                    log_warn!("{}", t!("config-unknown-field", layer = layer, field = field));
+                }
+            }
+
+            let config = match serde_json::from_value::<Config>(Value::Object(candidate.clone()))
+                .map_err(|err| err.to_string())
+                .and_then(|config| config.validate().map(|_| config))
+            {
+                Ok(config) => config,
+                Err(err) if strict => return Err(t!("config-layer-error", layer = layer, error = err)),
+                Err(err) => {
+                    log_error!("{}", t!("config-layer-skipped", layer = layer, error = err));
+                    continue;
+                }
+            };
+
+            for field in values.keys() {
+                if merged.contains_key(field) {
+                    layered.sources.insert(field.clone(), layer);
+                }
+            }
+            merged = candidate;
+            layered.config = config;
+        }
+
+        Ok(layered)
+    }
+}
+
+/// Parse nội dung `config.json` của booster: object JSON chỉ gồm field của `Config` và hợp lệ
+///
+/// Chặt hơn `serde` (mọi field đều có default): `config.json` của project khác không lọt qua.
+pub fn parse_config_file(contents: &str) -> Result<Config, String> {
+    let Value::Object(values) = serde_json::from_str::<Value>(contents).map_err(|err| err.to_string())? else {
+        return Err(t!("config-not-object", path = "config.json"));
+    };
+    let known = field_names(&Config::default());
+    if let Some(field) = values.keys().find(|field| *field != "$schema" && !known.contains(field)) {
+        return Err(t!("config-unknown-field-name", field = field));
+    }
+    let config: Config = serde_json::from_value(Value::Object(values)).map_err(|err| err.to_string())?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// JSON Schema của `Config` (draft-07)
+///
+/// Field `enable_<id>` được thêm từ các optimization đã đăng ký.
+pub fn json_schema() -> Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(Config)).unwrap_or(Value::Null);
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        for optimization in optimizations::registry() {
+            properties.insert(
+                optimizations::config_key(optimization.id()),
+                serde_json::json!({
+                    "description": format!("{}: {}", optimization.name(), optimization.description()),
+                    "type": "boolean",
+                    "default": optimization.default_enabled(),
+                }),
+            );
+        }
+    }
+    schema
+}
+
+pub fn write_schema_file(path: &Path) -> io::Result<()> {
+    let data = serde_json::to_string_pretty(&json_schema())
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(path, data)
+}
+
+/// Lưu `--set` từ CLI; key không hợp lệ → `Err`
+pub fn set_cli_overrides(overrides: Vec<(String, String)>) -> Result<(), String> {
+    let known = field_names(&Config::default());
+    if let Some((field, _)) = overrides.iter().find(|(field, _)| !known.contains(field)) {
+        return Err(t!(
+            "config-unknown-override",
+            field = field,
+            known = known.join(", ")
+        ));
+    }
+    let _ = CLI_OVERRIDES.set(overrides);
+    Ok(())
+}
+
+fn contains_name(list: &[String], name: &str) -> bool {
+    list.iter().any(|entry| entry.eq_ignore_ascii_case(name))
+}
+
+fn to_value(config: &Config) -> Value {
+    serde_json::to_value(config).unwrap_or(Value::Null)
+}
+
+fn field_names(config: &Config) -> Vec<String> {
+    match to_value(config) {
+        Value::Object(map) => map.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Giá trị từ env/CLI: thử parse JSON (`true`, `30`), không được thì coi là string
+fn parse_raw_value(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// File không tồn tại → `Ok(None)`
+fn read_file_layer(path: &Path) -> Result<Option<Map<String, Value>>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(t!("config-read-failed", path = path.display(), error = err)),
+    };
+
+    match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Object(map)) => Ok(Some(map)),
+        Ok(_) => Err(t!("config-not-object", path = path.display())),
+        Err(err) => Err(t!("config-invalid-json", path = path.display(), error = err)),
+    }
+}
+
+fn env_layer(known: &Map<String, Value>, env: impl IntoIterator<Item = (String, String)>) -> Map<String, Value> {
+    env.into_iter()
+        .filter_map(|(key, raw)| {
+            let field = key.strip_prefix(ENV_PREFIX)?.to_lowercase();
+            known
+                .contains_key(&field)
+                .then(|| (field, parse_raw_value(&raw)))
+        })
+        .collect()
+}
+
+fn cli_layer(known: &Map<String, Value>, overrides: Option<&[(String, String)]>) -> Result<Option<Map<String, Value>>, String> {
+    let Some(overrides) = overrides else {
+        return Ok(None);
+    };
+
+    let mut values = Map::new();
+    for (field, raw) in overrides {
+        if !known.contains_key(field) {
+            return Err(t!("config-unknown-field-name", field = field));
+        }
+        values.insert(field.clone(), parse_raw_value(raw));
     }
-}
\ No newline at end of file
+    Ok(Some(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct Layers {
+        dir: tempfile::TempDir,
+    }
+
+    impl Layers {
+        fn new(system: Option<Value>, user: Option<Value>) -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let layers = Self { dir };
+            for (path, value) in [(layers.system(), system), (layers.user(), user)] {
+                if let Some(value) = value {
+                    fs::write(path, value.to_string()).unwrap();
+                }
+            }
+            layers
+        }
+
+        fn system(&self) -> std::path::PathBuf {
+            self.dir.path().join("system.json")
+        }
+
+        fn user(&self) -> std::path::PathBuf {
+            self.dir.path().join("config.json")
+        }
+
+        fn build(&self, strict: bool, env: &[(&str, &str)], cli: &[(&str, &str)]) -> Result<LayeredConfig, String> {
+            let env = env.iter().map(|(key, value)| (key.to_string(), value.to_string()));
+            let cli: Vec<_> = cli
+                .iter()
+                .map(|(field, value)| (field.to_string(), value.to_string()))
+                .collect();
+            LayeredConfig::build_from(strict, &self.system(), &self.user(), env, Some(&cli))
+        }
+
+        fn user_value(&self) -> Value {
+            serde_json::from_str(&fs::read_to_string(self.user()).unwrap()).unwrap()
+        }
+    }
+
+    #[test]
+    fn later_layers_override_earlier_ones() {
+        let layers = Layers::new(
+            Some(json!({ "boost_interval_seconds": 10, "auto_start": true, "follow_focus": false })),
+            Some(json!({ "boost_interval_seconds": 20, "dry_run": true, "$schema": "./config.schema.json" })),
+        );
+        let layered = layers
+            .build(
+                true,
+                &[
+                    ("ROBLOX_BOOSTER_BOOST_INTERVAL_SECONDS", "30"),
+                    ("ROBLOX_BOOSTER_ROBLOX_AFFINITY", "0-3"),
+                    ("ROBLOX_BOOSTER_NOT_A_FIELD", "1"),
+                    ("BOOST_INTERVAL_SECONDS", "99"),
+                ],
+                &[("roblox_affinity", "4-7")],
+            )
+            .unwrap();
+
+        let config = &layered.config;
+        assert_eq!(config.boost_interval_seconds, 30);
+        assert!(config.auto_start);
+        assert!(!config.follow_focus);
+        assert!(config.dry_run);
+        assert_eq!(config.roblox_affinity.as_deref(), Some("4-7"));
+        assert_eq!(config.language, Language::default());
+
+        assert_eq!(layered.source_of("language"), ConfigLayer::Default);
+        assert_eq!(layered.source_of("auto_start"), ConfigLayer::System);
+        assert_eq!(layered.source_of("follow_focus"), ConfigLayer::System);
+        assert_eq!(layered.source_of("dry_run"), ConfigLayer::User);
+        assert_eq!(layered.source_of("boost_interval_seconds"), ConfigLayer::Env);
+        assert_eq!(layered.source_of("roblox_affinity"), ConfigLayer::Cli);
+        assert!(!layered.sources.contains_key("$schema"));
+    }
+
+    #[test]
+    fn missing_files_fall_back_to_defaults() {
+        let layers = Layers::new(None, None);
+        let layered = layers.build(true, &[], &[]).unwrap();
+        assert_eq!(to_value(&layered.config), to_value(&Config::default()));
+        assert!(layered.sources.values().all(|layer| *layer == ConfigLayer::Default));
+    }
+
+    #[test]
+    fn strict_rejects_unknown_fields_and_invalid_layers() {
+        let layers = Layers::new(None, Some(json!({ "auto_start": true, "boost_intervall": 5 })));
+        let err = layers.build(true, &[], &[]).unwrap_err();
+        assert!(err.contains("boost_intervall"), "{}", err);
+
+        // Không strict: bỏ field lạ, giữ phần còn lại của layer
+        let layered = layers.build(false, &[], &[]).unwrap();
+        assert!(layered.config.auto_start);
+        assert_eq!(layered.source_of("auto_start"), ConfigLayer::User);
+
+        let layers = Layers::new(Some(json!({ "boost_interval_seconds": 4000 })), None);
+        assert!(layers.build(true, &[], &[]).is_err());
+        let layered = layers.build(false, &[], &[]).unwrap();
+        assert_eq!(layered.config.boost_interval_seconds, 60);
+        assert_eq!(layered.source_of("boost_interval_seconds"), ConfigLayer::Default);
+
+        let layers = Layers::new(None, None);
+        assert!(layers.build(true, &[], &[("no_such_field", "1")]).is_err());
+    }
+
+    #[test]
+    fn save_writes_only_edited_fields() {
+        let layers = Layers::new(
+            Some(json!({ "boost_interval_seconds": 10 })),
+            Some(json!({ "auto_start": true, "roblox_process_names": ["sober"] })),
+        );
+        let original = layers
+            .build(true, &[("ROBLOX_BOOSTER_DRY_RUN", "true")], &[("follow_focus", "false")])
+            .unwrap()
+            .config;
+        let base = LayeredConfig::base_value(&layers.system());
+
+        let mut edited = original.clone();
+        edited.pause_process_names = vec!["obs".to_string()];
+        edited.save_to(&original, &layers.user(), &base).unwrap();
+
+        let user = layers.user_value();
+        assert_eq!(user["auto_start"], json!(true));
+        assert_eq!(user["roblox_process_names"], json!(["sober"]));
+        assert_eq!(user["pause_process_names"], json!(["obs"]));
+        assert_eq!(user["$schema"], json!(format!("./{}", SCHEMA_FILE_NAME)));
+        // Giá trị từ env/CLI và system không bị ghi vào user file
+        for field in ["dry_run", "follow_focus", "boost_interval_seconds", "language"] {
+            assert!(user.get(field).is_none(), "{}", field);
+        }
+        assert!(layers.dir.path().join(SCHEMA_FILE_NAME).is_file());
+    }
+
+    #[test]
+    fn save_removes_fields_equal_to_base() {
+        let layers = Layers::new(
+            Some(json!({ "boost_interval_seconds": 10 })),
+            Some(json!({ "boost_interval_seconds": 20, "auto_start": true })),
+        );
+        let original = layers.build(true, &[], &[]).unwrap().config;
+        let base = LayeredConfig::base_value(&layers.system());
+
+        let mut edited = original.clone();
+        edited.boost_interval_seconds = 10;
+        edited.auto_start = false;
+        edited.save_to(&original, &layers.user(), &base).unwrap();
+
+        let user = layers.user_value();
+        assert!(user.get("boost_interval_seconds").is_none());
+        assert!(user.get("auto_start").is_none());
+        let reloaded = layers.build(true, &[], &[]).unwrap();
+        assert_eq!(reloaded.config.boost_interval_seconds, 10);
+        assert_eq!(reloaded.source_of("boost_interval_seconds"), ConfigLayer::System);
+        assert!(!reloaded.config.auto_start);
+    }
+
+    #[test]
+    fn save_keeps_invalid_user_file() {
+        let layers = Layers::new(None, None);
+        fs::write(layers.user(), "{ broken").unwrap();
+        let original = Config::default();
+        let mut edited = original.clone();
+        edited.auto_start = true;
+        assert!(edited.save_to(&original, &layers.user(), &Map::new()).is_err());
+        assert_eq!(fs::read_to_string(layers.user()).unwrap(), "{ broken");
+    }
+}
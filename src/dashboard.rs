@@ -0,0 +1,259 @@
+use crate::i18n::t;
+use crate::metrics::MetricsSample;
+use iced::mouse;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::widget::{column, row, text, Column};
+use iced::{Color, Element, Length, Point, Rectangle, Renderer, Size, Theme};
+use std::collections::VecDeque;
+
+/// Số mẫu giữ lại (1 mẫu/giây → 2 phút)
+const HISTORY_LEN: usize = 120;
+
+const BLUE: Color = Color::from_rgb(0.35, 0.6, 1.0);
+const GREEN: Color = Color::from_rgb(0.3, 0.85, 0.45);
+const ORANGE: Color = Color::from_rgb(1.0, 0.6, 0.2);
+const RED: Color = Color::from_rgb(1.0, 0.35, 0.35);
+
+/// Dữ liệu của dashboard (lịch sử mẫu từ engine)
+#[derive(Default)]
+pub struct Dashboard {
+    history: VecDeque<MetricsSample>,
+}
+
+impl Dashboard {
+    pub fn push(&mut self, sample: MetricsSample) {
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(sample);
+    }
+
+    pub fn latest(&self) -> Option<&MetricsSample> {
+        self.history.back()
+    }
+
+    pub fn view<'a, Message: 'a>(&'a self) -> Element<'a, Message> {
+        let Some(latest) = self.latest() else {
+            return text(t!("dashboard-sampling")).size(12).into();
+        };
+
+        let mut content: Column<'a, Message> = column![
+            chart_header(
+                "CPU".to_string(),
+                vec![
+                    (t!("dashboard-system-cpu", value = format!("{:.0}", latest.system_cpu)), BLUE),
+                    (format!("Roblox {:.0}%", latest.roblox_cpu), GREEN),
+                ]
+            ),
+            self.chart(
+                100.0,
+                vec![
+                    Series::new(BLUE, |s: &MetricsSample| s.system_cpu),
+                    Series::new(GREEN, |s: &MetricsSample| s.roblox_cpu),
+                ]
+            ),
+            chart_header(
+                "RAM".to_string(),
+                vec![
+                    (
+                        format!(
+                            "{:.1}/{:.1} GB",
+                            gib(latest.memory_used),
+                            gib(latest.memory_total)
+                        ),
+                        BLUE
+                    ),
+                    (format!("Roblox {:.0} MB", mib(latest.roblox_memory)), GREEN),
+                ]
+            ),
+            self.chart(
+                100.0,
+                vec![
+                    Series::new(BLUE, MetricsSample::memory_percent),
+                    Series::new(GREEN, MetricsSample::roblox_memory_percent),
+                ]
+            ),
+        ]
+        .spacing(4);
+
+        if let Some(pressure) = latest.pressure {
+            content = content
+                .push(chart_header(
+                    "PSI (avg10)".to_string(),
+                    vec![
+                        (format!("CPU {:.1}%", pressure.cpu), BLUE),
+                        (format!("Mem {:.1}%", pressure.memory), ORANGE),
+                        (format!("IO {:.1}%", pressure.io), RED),
+                    ],
+                ))
+                .push(self.chart(
+                    100.0,
+                    vec![
+                        Series::new(BLUE, |s: &MetricsSample| s.pressure.map_or(0.0, |p| p.cpu)),
+                        Series::new(ORANGE, |s: &MetricsSample| {
+                            s.pressure.map_or(0.0, |p| p.memory)
+                        }),
+                        Series::new(RED, |s: &MetricsSample| s.pressure.map_or(0.0, |p| p.io)),
+                    ],
+                ));
+        }
+
+        if let Some(temperature) = latest.temperature {
+            content = content
+                .push(chart_header(
+                    t!("dashboard-temperature"),
+                    vec![(format!("{:.0}°C", temperature), RED)],
+                ))
+                .push(self.chart(
+                    110.0,
+                    vec![Series::new(RED, |s: &MetricsSample| {
+                        s.temperature.unwrap_or(0.0)
+                    })],
+                ));
+        }
+
+        content = content.push(text(t!("dashboard-roblox")).size(14));
+        if latest.roblox_processes.is_empty() {
+            content = content.push(text(format!("   {}", t!("dashboard-no-roblox"))).size(12));
+        }
+        for process in &latest.roblox_processes {
+            content = content.push(
+                text(format!(
+                    "   • {} (PID {}) - CPU {:.0}% - {:.0} MB",
+                    process.name,
+                    process.pid,
+                    process.cpu,
+                    mib(process.memory)
+                ))
+                .size(12),
+            );
+        }
+
+        content = content.push(text(t!("dashboard-active")).size(14));
+        if latest.active_optimizations.is_empty() {
+            content = content.push(text(format!("   {}", t!("dashboard-booster-off"))).size(12));
+        }
+        for optimization in &latest.active_optimizations {
+            content = content.push(text(format!("   • {}", optimization)).size(12));
+        }
+
+        content.into()
+    }
+
+    fn chart<'a, Message: 'a>(&'a self, max: f32, series: Vec<Series>) -> Element<'a, Message> {
+        Canvas::new(LineChart {
+            history: &self.history,
+            max,
+            series,
+        })
+        .width(Length::Fill)
+        .height(Length::Fixed(70.0))
+        .into()
+    }
+}
+
+/// Tiêu đề chart + giá trị hiện tại, tô cùng màu với đường tương ứng
+fn chart_header<'a, Message: 'a>(title: String, values: Vec<(String, Color)>) -> Element<'a, Message> {
+    values
+        .into_iter()
+        .fold(row![text(title).size(13)], |header, (value, color)| {
+            header.push(text(value).size(12).style(color))
+        })
+        .spacing(10)
+        .into()
+}
+
+fn gib(bytes: u64) -> f64 {
+    bytes as f64 / 1024.0 / 1024.0 / 1024.0
+}
+
+fn mib(bytes: u64) -> f64 {
+    bytes as f64 / 1024.0 / 1024.0
+}
+
+/// Một đường trên chart
+struct Series {
+    color: Color,
+    value: fn(&MetricsSample) -> f32,
+}
+
+impl Series {
+    fn new(color: Color, value: fn(&MetricsSample) -> f32) -> Self {
+        Self { color, value }
+    }
+}
+
+/// Line chart vẽ bằng canvas, trục x = thời gian (phải = mới nhất)
+struct LineChart<'a> {
+    history: &'a VecDeque<MetricsSample>,
+    max: f32,
+    series: Vec<Series>,
+}
+
+impl<'a, Message> canvas::Program<Message> for LineChart<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let size = bounds.size();
+
+        frame.fill_rectangle(Point::ORIGIN, size, Color::from_rgb(0.12, 0.12, 0.14));
+
+        let grid = Stroke::default()
+            .with_color(Color::from_rgba(1.0, 1.0, 1.0, 0.08))
+            .with_width(1.0);
+        for fraction in [0.25, 0.5, 0.75] {
+            let y = size.height * fraction;
+            frame.stroke(
+                &Path::line(Point::new(0.0, y), Point::new(size.width, y)),
+                grid.clone(),
+            );
+        }
+
+        let step = size.width / (HISTORY_LEN - 1) as f32;
+        let offset = HISTORY_LEN - self.history.len();
+
+        for series in &self.series {
+            if self.history.len() < 2 {
+                continue;
+            }
+
+            let path = Path::new(|builder| {
+                for (index, sample) in self.history.iter().enumerate() {
+                    let value = (series.value)(sample).clamp(0.0, self.max);
+                    let point = Point::new(
+                        (offset + index) as f32 * step,
+                        size.height - value / self.max * size.height,
+                    );
+                    if index == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+
+            frame.stroke(
+                &path,
+                Stroke::default().with_color(series.color).with_width(1.5),
+            );
+        }
+
+        // Viền
+        frame.stroke(
+            &Path::rectangle(Point::ORIGIN, Size::new(size.width, size.height)),
+            Stroke::default()
+                .with_color(Color::from_rgba(1.0, 1.0, 1.0, 0.2))
+                .with_width(1.0),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,69 @@
+use crate::config::ConfigChange;
+use crate::i18n::t;
+use crate::metrics::MetricsSample;
+use std::fmt;
+
+/// Trạng thái của boost loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineStatus {
+    Stopped,
+    Starting,
+    Running,
+    Stopping,
+}
+
+impl EngineStatus {
+    /// Đang chạy hoặc đang khởi động (nút bật/tắt sẽ dừng booster)
+    pub fn is_active(self) -> bool {
+        matches!(self, Self::Starting | Self::Running)
+    }
+}
+
+impl fmt::Display for EngineStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&t!(match self {
+            Self::Stopped => "status-stopped",
+            Self::Starting => "status-starting",
+            Self::Running => "status-running",
+            Self::Stopping => "status-stopping",
+        }))
+    }
+}
+
+/// Sự kiện do engine phát ra (UI subscribe qua `RobloxBoosterEngine::subscribe`)
+#[derive(Debug, Clone)]
+pub enum EngineEvent {
+    /// Booster bật/tắt (kể cả khi gọi từ ngoài UI, vd auto_start)
+    StatusChanged(EngineStatus),
+    /// Config mới đã được áp dụng cho engine đang chạy
+    ConfigChanged { changes: Vec<ConfigChange> },
+    /// File config thay đổi nhưng nội dung không hợp lệ → giữ config cũ
+    ConfigRejected { reason: String },
+    /// Mẫu CPU/RAM/PSI/nhiệt độ định kỳ (không in ra console)
+    Metrics(MetricsSample),
+}
+
+impl fmt::Display for EngineEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StatusChanged(status) => write!(f, "{}", status),
+            Self::ConfigChanged { changes } => {
+                write!(f, "{}", t!("event-config-changed"))?;
+                for change in changes {
+                    write!(f, " {} {} → {};", change.field, change.old, change.new)?;
+                }
+                Ok(())
+            }
+            Self::ConfigRejected { reason } => {
+                write!(f, "{}", t!("event-config-rejected", reason = reason))
+            }
+            Self::Metrics(sample) => write!(
+                f,
+                "📊 CPU {:.0}% | RAM {:.0}% | Roblox CPU {:.0}%",
+                sample.system_cpu,
+                sample.memory_percent(),
+                sample.roblox_cpu
+            ),
+        }
+    }
+}
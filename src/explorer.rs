@@ -0,0 +1,294 @@
+use crate::capabilities::{self, Capability};
+use crate::i18n::t;
+use crate::process::ProcessEntry;
+use iced::widget::{button, column, row, scrollable, text, text_input, Column};
+use iced::{Alignment, Element, Length};
+
+/// Số dòng tối đa hiển thị (tránh lag khi có hàng nghìn process)
+const MAX_ROWS: usize = 200;
+
+/// Cột dùng để sắp xếp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Name,
+    Pid,
+    Cpu,
+    Memory,
+}
+
+/// List trong config mà user có thể thêm process vào
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessList {
+    Roblox,
+    Pause,
+}
+
+#[derive(Debug, Clone)]
+pub enum ExplorerMessage {
+    FilterChanged(String),
+    SortBy(SortColumn),
+    Refresh,
+    /// Kết quả của `ExplorerQuery::select`: tổng số process khớp filter + các dòng hiển thị
+    Loaded(usize, Vec<ProcessEntry>),
+    AddToList(ProcessList, String),
+    Boost(u32),
+    Revert(u32),
+    /// Kết quả boost/revert/thêm vào list (chạy ngoài UI thread): thông báo hoặc lỗi
+    ActionDone(Result<String, String>),
+}
+
+/// Filter + cách sắp xếp hiện tại, dùng để chọn dòng hiển thị ngoài UI thread
+#[derive(Debug, Clone)]
+pub struct ExplorerQuery {
+    filter: String,
+    sort: SortColumn,
+    descending: bool,
+}
+
+impl ExplorerQuery {
+    /// Lọc + sắp xếp snapshot, giữ tối đa `MAX_ROWS` dòng rồi đọc nice/affinity/cgroup cho các dòng đó
+    ///
+    /// Trả về tổng số process khớp filter và các dòng hiển thị.
+    pub fn select(&self, entries: Vec<ProcessEntry>) -> (usize, Vec<ProcessEntry>) {
+        let mut entries: Vec<ProcessEntry> = entries
+            .into_iter()
+            .filter(|entry| self.matches(entry))
+            .collect();
+        let total = entries.len();
+        self.sort(&mut entries);
+        entries.truncate(MAX_ROWS);
+        for entry in &mut entries {
+            entry.load_details();
+        }
+        (total, entries)
+    }
+
+    fn matches(&self, entry: &ProcessEntry) -> bool {
+        let filter = self.filter.to_lowercase();
+        filter.is_empty()
+            || entry.name.to_lowercase().contains(&filter)
+            || entry.pid.to_string().contains(&filter)
+    }
+
+    fn sort(&self, entries: &mut [ProcessEntry]) {
+        let column = self.sort;
+        entries.sort_by(|a, b| match column {
+            SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortColumn::Pid => a.pid.cmp(&b.pid),
+            SortColumn::Cpu => a.cpu.total_cmp(&b.cpu),
+            SortColumn::Memory => a.memory.cmp(&b.memory),
+        });
+        if self.descending {
+            entries.reverse();
+        }
+    }
+}
+
+/// State của trang Process Explorer
+pub struct ProcessExplorer {
+    query: ExplorerQuery,
+    /// Các dòng đang hiển thị (đã lọc, sắp xếp, tối đa `MAX_ROWS`)
+    entries: Vec<ProcessEntry>,
+    /// Tổng số process khớp filter
+    total: usize,
+    /// Đang đọc snapshot → bỏ qua các lần refresh tự động tới khi xong
+    loading: bool,
+    status: Option<String>,
+    /// Lý do không boost được (thiếu CAP_SYS_NICE, ...), `None` = boost được
+    boost_unavailable: Option<String>,
+}
+
+impl Default for ProcessExplorer {
+    fn default() -> Self {
+        Self {
+            query: ExplorerQuery {
+                filter: String::new(),
+                sort: SortColumn::Cpu,
+                descending: true,
+            },
+            entries: Vec::new(),
+            total: 0,
+            loading: false,
+            status: None,
+            // Thiếu quyền nice vẫn boost được qua privileged helper
+            boost_unavailable: capabilities::probe(Capability::SysNice)
+                .or_else(|err| capabilities::probe(Capability::PrivilegedHelper).map_err(|_| err))
+                .err(),
+        }
+    }
+}
+
+impl ProcessExplorer {
+    /// Query cho lần đọc snapshot tiếp theo, `None` nếu đang đọc dở (trừ khi `force`)
+    pub fn begin_load(&mut self, force: bool) -> Option<ExplorerQuery> {
+        if self.loading && !force {
+            return None;
+        }
+        self.loading = true;
+        Some(self.query.clone())
+    }
+
+    /// Nhận kết quả của `ExplorerQuery::select`
+    pub fn set_entries(&mut self, total: usize, entries: Vec<ProcessEntry>) {
+        self.total = total;
+        self.entries = entries;
+        self.loading = false;
+    }
+
+    /// Thông báo kết quả thao tác gần nhất (boost/revert/thêm vào list)
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+
+    /// Xử lý các message chỉ thay đổi state hiển thị
+    pub fn update(&mut self, message: &ExplorerMessage) {
+        match message {
+            ExplorerMessage::FilterChanged(filter) => self.query.filter = filter.clone(),
+            ExplorerMessage::SortBy(column) => {
+                let query = &mut self.query;
+                if query.sort == *column {
+                    query.descending = !query.descending;
+                } else {
+                    query.sort = *column;
+                    // Số thì mặc định giảm dần, tên thì tăng dần
+                    query.descending = !matches!(column, SortColumn::Name);
+                }
+                // Sắp xếp lại ngay các dòng đang có, snapshot mới tới sau
+                self.query.sort(&mut self.entries);
+            }
+            _ => {}
+        }
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &ProcessEntry> {
+        self.entries.iter().filter(|entry| self.query.matches(entry))
+    }
+
+    pub fn view(&self) -> Element<'_, ExplorerMessage> {
+        let toolbar = row![
+            text_input(&t!("explorer-filter-placeholder"), &self.query.filter)
+                .on_input(ExplorerMessage::FilterChanged)
+                .padding(8),
+            button(text("🔄").size(14))
+                .padding(8)
+                .on_press(ExplorerMessage::Refresh),
+        ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+
+        let header = row![
+            self.sort_button(&t!("explorer-column-name"), SortColumn::Name, 4),
+            self.sort_button("PID", SortColumn::Pid, 1),
+            self.sort_button("CPU", SortColumn::Cpu, 1),
+            self.sort_button("RAM", SortColumn::Memory, 1),
+        ]
+        .spacing(4);
+
+        let rows = self
+            .visible()
+            .fold(Column::new().spacing(6), |rows, entry| {
+                rows.push(Self::entry_view(entry, self.boost_unavailable.is_none()))
+            });
+
+        let mut content = column![toolbar, header].spacing(8);
+        if let Some(reason) = &self.boost_unavailable {
+            content = content.push(text(t!("explorer-boost-unavailable", reason = reason)).size(11));
+        }
+        if let Some(status) = &self.status {
+            content = content.push(text(status).size(12));
+        }
+        content = content.push(
+            text(t!("explorer-total", count = self.total, max = MAX_ROWS))
+            .size(11),
+        );
+
+        content
+            .push(scrollable(rows).height(Length::Fill))
+            .into()
+    }
+
+    fn sort_button(&self, label: &str, column: SortColumn, portion: u16) -> Element<'_, ExplorerMessage> {
+        let arrow = match (self.query.sort == column, self.query.descending) {
+            (true, true) => " ▼",
+            (true, false) => " ▲",
+            _ => "",
+        };
+        button(text(format!("{}{}", label, arrow)).size(12))
+            .width(Length::FillPortion(portion))
+            .on_press(ExplorerMessage::SortBy(column))
+            .into()
+    }
+
+    fn entry_view(entry: &ProcessEntry, can_boost: bool) -> Element<'_, ExplorerMessage> {
+        let mut tags = Vec::new();
+        if entry.roblox_match {
+            tags.push(t!("explorer-tag-roblox"));
+        }
+        if let (Some(root), Some(instance)) = (entry.session, entry.instance) {
+            tags.push(t!("explorer-tag-session", instance = instance, pid = root));
+        }
+        if entry.in_pause_list {
+            tags.push(if entry.paused {
+                t!("explorer-tag-paused")
+            } else {
+                t!("explorer-tag-pause-list")
+            });
+        }
+        if entry.boosted {
+            tags.push(t!("explorer-tag-boosted"));
+        }
+
+        let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        let details = format!(
+            "nice {} | CPU {} | cgroup {}",
+            entry.nice.map_or("-".to_string(), |nice| nice.to_string()),
+            optional(&entry.affinity),
+            optional(&entry.cgroup),
+        );
+
+        let summary = row![
+            text(&entry.name).size(13).width(Length::FillPortion(4)),
+            text(entry.pid).size(12).width(Length::FillPortion(1)),
+            text(format!("{:.1}%", entry.cpu)).size(12).width(Length::FillPortion(1)),
+            text(format!("{:.0} MB", entry.memory as f64 / 1024.0 / 1024.0))
+                .size(12)
+                .width(Length::FillPortion(1)),
+        ]
+        .spacing(4);
+
+        let mut actions = row![].spacing(6);
+        if !entry.roblox_match {
+            actions = actions.push(
+                button(text("+ Roblox").size(11))
+                    .padding(4)
+                    .on_press(ExplorerMessage::AddToList(ProcessList::Roblox, entry.name.clone())),
+            );
+        }
+        if !entry.in_pause_list {
+            actions = actions.push(
+                button(text("+ Pause").size(11))
+                    .padding(4)
+                    .on_press(ExplorerMessage::AddToList(ProcessList::Pause, entry.name.clone())),
+            );
+        }
+        actions = actions.push(if entry.boosted {
+            button(text(t!("explorer-revert")).size(11))
+                .padding(4)
+                .on_press(ExplorerMessage::Revert(entry.pid))
+        } else {
+            button(text(t!("explorer-boost")).size(11))
+                .padding(4)
+                .on_press_maybe(can_boost.then_some(ExplorerMessage::Boost(entry.pid)))
+        });
+
+        column![
+            summary,
+            text(details).size(10),
+            row![text(tags.join("  ")).size(11), actions]
+                .spacing(10)
+                .align_items(Alignment::Center),
+        ]
+        .spacing(2)
+        .into()
+    }
+}
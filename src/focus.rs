@@ -0,0 +1,268 @@
+#[cfg(target_os = "linux")]
+use crate::capabilities::ProbeResult;
+use crate::i18n::t;
+use crate::logging::log_warn;
+use crate::session::NamespacedPid;
+use std::time::Duration;
+
+/// Chu kỳ hỏi thời gian không thao tác (X server không gửi event khi có input)
+const IDLE_POLL: Duration = Duration::from_secs(2);
+
+/// Cửa sổ đang được focus theo window manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Focus {
+    /// Không theo dõi được (không có X11, cửa sổ Wayland native đang focus...) → bỏ qua focus
+    #[default]
+    Unknown,
+    /// `_NET_ACTIVE_WINDOW` (0 = không có cửa sổ nào) và process tạo ra nó
+    #[cfg_attr(not(all(unix, not(target_os = "macos"))), allow(dead_code))]
+    Window { window: u32, pid: Option<NamespacedPid> },
+}
+
+impl Focus {
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
+
+    pub fn pid(&self) -> Option<NamespacedPid> {
+        match self {
+            Self::Window { pid, .. } => *pid,
+            Self::Unknown => None,
+        }
+    }
+}
+
+/// Focus hiện tại (kết nối X11 một lần), `Unknown` nếu không có X11
+pub fn current() -> Focus {
+    FocusTracker::connect().map_or(Focus::Unknown, |tracker| tracker.current())
+}
+
+/// Probe cho `doctor`: kết nối được X server có EWMH không
+#[cfg(target_os = "linux")]
+pub fn probe() -> ProbeResult {
+    FocusTracker::connect().map(|tracker| tracker.describe())
+}
+
+/// Theo dõi focus trên thread riêng, gọi `on_change` mỗi khi cửa sổ focus đổi (và một lần lúc đầu)
+///
+/// Mất kết nối X11 → gọi `on_change(Focus::Unknown)` rồi dừng.
+pub fn watch(mut on_change: impl FnMut(Focus) + Send + 'static) -> Result<(), String> {
+    let tracker = FocusTracker::connect()?;
+    std::thread::Builder::new()
+        .name("focus-watcher".to_string())
+        .spawn(move || {
+            if let Err(err) = tracker.run(&mut on_change) {
+                log_warn!("⚠️  {}", err);
+            }
+            on_change(Focus::Unknown);
+        })
+        .map(|_| ())
+        .map_err(|err| t!("focus-unavailable", error = err))
+}
+
+/// Hỏi thời gian người dùng không thao tác (MIT-SCREEN-SAVER) mỗi `IDLE_POLL` trên thread riêng
+///
+/// Mất kết nối X11 → gọi `on_idle(None)` rồi dừng.
+pub fn watch_idle(mut on_idle: impl FnMut(Option<Duration>) + Send + 'static) -> Result<(), String> {
+    let tracker = FocusTracker::connect()?;
+    // Server không có extension → báo lỗi ngay thay vì trong thread
+    tracker.idle_time()?;
+    std::thread::Builder::new()
+        .name("idle-watcher".to_string())
+        .spawn(move || loop {
+            match tracker.idle_time() {
+                Ok(idle) => on_idle(Some(idle)),
+                Err(err) => {
+                    log_warn!("⚠️  {}", err);
+                    on_idle(None);
+                    break;
+                }
+            }
+            std::thread::sleep(IDLE_POLL);
+        })
+        .map(|_| ())
+        .map_err(|err| t!("idle-unavailable", error = err))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+use x11rb::{
+    connection::Connection,
+    protocol::{
+        res::{ClientIdMask, ClientIdSpec, ConnectionExt as _},
+        screensaver::ConnectionExt as _,
+        xproto::{AtomEnum, ChangeWindowAttributesAux, ConnectionExt, EventMask, Window},
+        Event,
+    },
+    rust_connection::RustConnection,
+};
+
+/// Kết nối X11 (`$DISPLAY`, cả XWayland) đọc `_NET_ACTIVE_WINDOW` của root window
+#[cfg(all(unix, not(target_os = "macos")))]
+struct FocusTracker {
+    conn: RustConnection,
+    root: Window,
+    active_window: u32,
+    wm_pid: u32,
+    /// X server có extension X-Resource (hỏi được PID thật của X client)
+    xres: bool,
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl FocusTracker {
+    fn connect() -> Result<Self, String> {
+        let error = |err: &dyn std::fmt::Display| t!("focus-unavailable", error = err);
+        let (conn, screen) = x11rb::connect(None).map_err(|err| error(&err))?;
+        let root = conn.setup().roots[screen].root;
+        let intern = |name: &[u8]| -> Result<u32, String> {
+            Ok(conn
+                .intern_atom(false, name)
+                .map_err(|err| error(&err))?
+                .reply()
+                .map_err(|err| error(&err))?
+                .atom)
+        };
+        let active_window = intern(b"_NET_ACTIVE_WINDOW")?;
+        let wm_pid = intern(b"_NET_WM_PID")?;
+        let xres = conn
+            .res_query_version(1, 2)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .is_some();
+        Ok(Self {
+            conn,
+            root,
+            active_window,
+            wm_pid,
+            xres,
+        })
+    }
+
+    fn describe(&self) -> String {
+        let display = std::env::var("DISPLAY").unwrap_or_default();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            format!("XWayland {}", display)
+        } else {
+            format!("X11 {}", display)
+        }
+    }
+
+    /// Giá trị 32-bit đầu tiên của một property
+    fn property(&self, window: Window, property: u32, kind: AtomEnum) -> Option<u32> {
+        self.conn
+            .get_property(false, window, property, kind, 0, 1)
+            .ok()?
+            .reply()
+            .ok()?
+            .value32()?
+            .next()
+    }
+
+    fn current(&self) -> Focus {
+        let window = self
+            .property(self.root, self.active_window, AtomEnum::WINDOW)
+            .unwrap_or(0);
+        // Wayland: cửa sổ native được focus → XWayland báo 0, không biết Roblox có focus không
+        if window == 0 && std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            return Focus::Unknown;
+        }
+        let pid = (window != 0).then(|| self.window_pid(window)).flatten();
+        Focus::Window { window, pid }
+    }
+
+    /// Process tạo cửa sổ, kèm PID namespace của nó
+    ///
+    /// Ưu tiên PID của X client theo X server (X-Resource, đúng cả với client trong sandbox);
+    /// không có X-Resource → `_NET_WM_PID`, coi như cùng PID namespace với booster.
+    fn window_pid(&self, window: Window) -> Option<NamespacedPid> {
+        if let Some(pid) = self.client_pid(window).and_then(NamespacedPid::innermost) {
+            return Some(pid);
+        }
+        let pid = self
+            .property(window, self.wm_pid, AtomEnum::CARDINAL)
+            .filter(|pid| *pid != 0)?;
+        NamespacedPid::local(pid)
+    }
+
+    /// PID (theo X server) của X client sở hữu `window`
+    fn client_pid(&self, window: Window) -> Option<u32> {
+        if !self.xres {
+            return None;
+        }
+        let spec = ClientIdSpec {
+            client: window,
+            mask: ClientIdMask::LOCAL_CLIENT_PID,
+        };
+        self.conn
+            .res_query_client_ids(&[spec])
+            .ok()?
+            .reply()
+            .ok()?
+            .ids
+            .first()?
+            .value
+            .first()
+            .copied()
+    }
+
+    /// Thời gian từ lần cuối người dùng dùng chuột/bàn phím
+    fn idle_time(&self) -> Result<Duration, String> {
+        let error = |err: &dyn std::fmt::Display| t!("idle-unavailable", error = err);
+        let info = self
+            .conn
+            .screensaver_query_info(self.root)
+            .map_err(|err| error(&err))?
+            .reply()
+            .map_err(|err| error(&err))?;
+        Ok(Duration::from_millis(info.ms_since_user_input.into()))
+    }
+
+    /// Nghe `PropertyNotify` của root window, chỉ gọi `on_change` khi focus thật sự đổi
+    fn run(&self, on_change: &mut impl FnMut(Focus)) -> Result<(), String> {
+        let error = |err: &dyn std::fmt::Display| t!("focus-lost", error = err);
+        let events = ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+        self.conn
+            .change_window_attributes(self.root, &events)
+            .map_err(|err| error(&err))?
+            .check()
+            .map_err(|err| error(&err))?;
+
+        let mut focus = self.current();
+        on_change(focus);
+        loop {
+            let event = self.conn.wait_for_event().map_err(|err| error(&err))?;
+            let Event::PropertyNotify(event) = event else {
+                continue;
+            };
+            if event.atom != self.active_window {
+                continue;
+            }
+            let new = self.current();
+            if new != focus {
+                focus = new;
+                on_change(focus);
+            }
+        }
+    }
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+struct FocusTracker;
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+impl FocusTracker {
+    fn connect() -> Result<Self, String> {
+        Err(t!("focus-unsupported"))
+    }
+
+    fn current(&self) -> Focus {
+        Focus::Unknown
+    }
+
+    fn idle_time(&self) -> Result<Duration, String> {
+        Err(t!("focus-unsupported"))
+    }
+
+    fn run(&self, _on_change: &mut impl FnMut(Focus)) -> Result<(), String> {
+        Ok(())
+    }
+}
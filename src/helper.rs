@@ -0,0 +1,107 @@
+use crate::helper_protocol::{self, HelperRequest, HelperResponse};
+use crate::i18n::t;
+use std::io::Write;
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+#[cfg(target_os = "linux")]
+const HELPER_NAME: &str = "roblox_booster_helper";
+/// Vị trí cài đặt (khớp `org.freedesktop.policykit.exec.path` trong file polkit policy)
+#[cfg(target_os = "linux")]
+const HELPER_INSTALL_DIR: &str = "/usr/libexec/roblox_booster";
+
+/// Binary helper: `/usr/libexec/roblox_booster/` (đường dẫn trong polkit policy)
+///
+/// Bản debug còn thử binary cạnh app (`target/debug`) để thử helper khi dev; bản
+/// release không chạy helper ở thư mục user ghi được.
+#[cfg(target_os = "linux")]
+fn helper_path() -> Option<PathBuf> {
+    let installed = PathBuf::from(HELPER_INSTALL_DIR).join(HELPER_NAME);
+    if installed.is_file() {
+        return Some(installed);
+    }
+    #[cfg(debug_assertions)]
+    {
+        let exe = std::env::current_exe().ok()?;
+        let sibling = exe.parent()?.join(HELPER_NAME);
+        if sibling.is_file() {
+            return Some(sibling);
+        }
+    }
+    None
+}
+
+/// Lệnh chạy helper: trực tiếp nếu đã là root, không thì qua pkexec
+#[cfg(target_os = "linux")]
+fn command() -> Result<Command, String> {
+    let helper = helper_path().ok_or_else(|| t!("helper-not-installed"))?;
+    // SAFETY: geteuid không có tham số, không thể lỗi
+    if unsafe { libc::geteuid() } == 0 {
+        return Ok(Command::new(helper));
+    }
+    let pkexec = crate::paths::find_in_path("pkexec").ok_or_else(|| t!("helper-no-pkexec"))?;
+    let mut command = Command::new(pkexec);
+    command.arg(helper);
+    Ok(command)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn command() -> Result<Command, String> {
+    Err(t!("cap-linux-only"))
+}
+
+/// Helper có dùng được không: `Ok(lệnh sẽ chạy)` hoặc `Err(lý do)`
+pub fn availability() -> Result<String, String> {
+    let command = command()?;
+    let mut description = command.get_program().to_string_lossy().into_owned();
+    for arg in command.get_args() {
+        description.push(' ');
+        description.push_str(&arg.to_string_lossy());
+    }
+    Ok(description)
+}
+
+/// Gửi request cho helper (có thể hiện hộp thoại xác thực của polkit)
+///
+/// Trả về giá trị trước khi đổi.
+pub fn run(request: &HelperRequest) -> Result<String, String> {
+    // Request sai bị từ chối ở đây, không hỏi mật khẩu vô ích
+    helper_protocol::validate(request).map_err(|err| t!("helper-failed", error = err))?;
+    #[cfg(target_os = "linux")]
+    if let HelperRequest::SetNice { pid, .. } = request {
+        // SAFETY: getuid không có tham số, không thể lỗi
+        helper_protocol::check_nice_target(*pid, unsafe { libc::getuid() })
+            .map_err(|err| t!("helper-failed", error = err))?;
+    }
+
+    let data = serde_json::to_string(request).map_err(|err| t!("helper-failed", error = err))?;
+    if data.len() as u64 >= helper_protocol::MAX_REQUEST_LEN {
+        return Err(t!("helper-failed", error = "request too large"));
+    }
+
+    let mut child = command()?
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|err| t!("helper-failed", error = err))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "{}", data).map_err(|err| t!("helper-failed", error = err))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|err| t!("helper-failed", error = err))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match serde_json::from_str::<HelperResponse>(stdout.trim()) {
+        Ok(HelperResponse::Ok { previous }) => Ok(previous),
+        Ok(HelperResponse::Error { message }) => Err(t!("helper-failed", error = message)),
+        // pkexec: 126 = user hủy xác thực, 127 = không được phép
+        Err(_) => Err(match output.status.code() {
+            Some(126) | Some(127) => t!("helper-auth-failed"),
+            _ => t!("helper-failed", error = output.status),
+        }),
+    }
+}
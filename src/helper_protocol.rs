@@ -0,0 +1,216 @@
+// Giao thức giữa app và `roblox_booster_helper` (chạy bằng root qua pkexec)
+//
+// App ghi một `HelperRequest` (JSON, một dòng) vào stdin của helper, helper trả
+// một `HelperResponse` ra stdout. Helper chỉ làm các thao tác trong whitelist
+// dưới đây và validate lại mọi request, không tin app.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// Kích thước request tối đa (bytes)
+pub const MAX_REQUEST_LEN: u64 = 4096;
+
+/// Governor được phép đặt (còn phải có trong `scaling_available_governors`)
+pub const GOVERNORS: &[&str] = &["performance", "schedutil", "ondemand", "conservative", "powersave"];
+
+/// sysctl được phép đổi: (key, min, max)
+pub const SYSCTL_WHITELIST: &[(&str, i64, i64)] = &[
+    ("kernel.sched_autogroup_enabled", 0, 1),
+    ("vm.swappiness", 0, 200),
+];
+
+/// Khoảng nice helper được phép đặt (không cho realtime / nice quá thấp)
+pub const NICE_RANGE: std::ops::RangeInclusive<i32> = -10..=19;
+
+/// Process helper được phép đổi nice: tên (comm của process chính hoặc binary) chứa một trong các chuỗi này
+#[cfg(target_os = "linux")]
+pub const NICE_TARGETS: &[&str] = &["roblox", "sober", "wine"];
+
+/// Process có tên `names` (comm, tên binary, ...) có phải Roblox/Sober/Wine không
+#[cfg(target_os = "linux")]
+pub fn is_nice_target(names: &[&str]) -> bool {
+    names.iter().any(|name| {
+        let name = name.to_lowercase();
+        NICE_TARGETS.iter().any(|target| name.contains(target))
+    })
+}
+
+/// `SetNice` chỉ áp dụng cho process Roblox/Sober/Wine thuộc user `caller`
+///
+/// Helper kiểm tra lại với UID đã gọi pkexec: không có kiểm tra này helper thành
+/// công cụ renice mọi process.
+#[cfg(target_os = "linux")]
+pub fn check_nice_target(pid: u32, caller: u32) -> Result<(), String> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid))
+        .map_err(|err| format!("pid {}: {}", pid, err))?;
+    let field = |name: &str| -> Option<u32> {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name))?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    };
+    let owner = field("Uid:").ok_or_else(|| format!("pid {}: cannot read owner", pid))?;
+    if owner != caller {
+        return Err(format!("pid {} is not owned by uid {}", pid, caller));
+    }
+
+    // TID → kiểm tra tên process chính, không phải tên thread
+    let tgid = field("Tgid:").unwrap_or(pid);
+    let comm = fs::read_to_string(format!("/proc/{}/comm", tgid)).unwrap_or_default();
+    let exe = fs::read_link(format!("/proc/{}/exe", tgid))
+        .ok()
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_default();
+    if !is_nice_target(&[comm.trim(), &exe]) {
+        return Err(format!("pid {} ({}) is not a Roblox, Sober or Wine process", pid, comm.trim()));
+    }
+    Ok(())
+}
+
+/// Setting hệ thống helper đổi được
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Setting {
+    /// cpufreq governor của mọi CPU
+    Governor,
+    /// sysctl trong `SYSCTL_WHITELIST`, vd `kernel.sched_autogroup_enabled`
+    Sysctl { key: String },
+}
+
+impl Setting {
+    /// File cần ghi (governor: một file mỗi CPU)
+    pub fn paths(&self) -> Vec<PathBuf> {
+        match self {
+            Self::Governor => {
+                let mut paths: Vec<PathBuf> = fs::read_dir("/sys/devices/system/cpu")
+                    .map(|entries| {
+                        entries
+                            .filter_map(|entry| entry.ok())
+                            .filter(|entry| {
+                                let name = entry.file_name();
+                                let name = name.to_string_lossy();
+                                name.strip_prefix("cpu")
+                                    .is_some_and(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+                            })
+                            .map(|entry| entry.path().join("cpufreq").join("scaling_governor"))
+                            .filter(|path| path.is_file())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                paths.sort();
+                paths
+            }
+            Self::Sysctl { key } => vec![PathBuf::from("/proc/sys").join(key.replace('.', "/"))],
+        }
+    }
+
+    /// Giá trị hiện tại (file đầu tiên), không cần root
+    pub fn read_current(&self) -> Result<String, String> {
+        let path = self
+            .paths()
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("{}: not available", self))?;
+        fs::read_to_string(&path)
+            .map(|value| value.trim().to_string())
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+}
+
+impl fmt::Display for Setting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Governor => f.write_str("cpufreq governor"),
+            Self::Sysctl { key } => f.write_str(key),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum HelperRequest {
+    /// Đặt setting, trả về giá trị cũ
+    Set { setting: Setting, value: String },
+    /// Đặt nice cho process Roblox/Sober/Wine của user gọi pkexec, trả về nice cũ
+    ///
+    /// `pid` có thể là TID (nice theo thread trên Linux).
+    SetNice { pid: u32, nice: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum HelperResponse {
+    Ok { previous: String },
+    Error { message: String },
+}
+
+/// Kiểm tra request theo whitelist (cả app và helper đều gọi)
+pub fn validate(request: &HelperRequest) -> Result<(), String> {
+    match request {
+        HelperRequest::Set {
+            setting: Setting::Governor,
+            value,
+        } => {
+            if !GOVERNORS.contains(&value.as_str()) {
+                return Err(format!("governor '{}' is not allowed", value));
+            }
+        }
+        HelperRequest::Set {
+            setting: Setting::Sysctl { key },
+            value,
+        } => {
+            let (_, min, max) = SYSCTL_WHITELIST
+                .iter()
+                .find(|(allowed, _, _)| allowed == key)
+                .ok_or_else(|| format!("sysctl '{}' is not allowed", key))?;
+            let number: i64 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("sysctl '{}': '{}' is not a number", key, value))?;
+            if number < *min || number > *max {
+                return Err(format!("sysctl '{}': {} is outside {}..={}", key, number, min, max));
+            }
+        }
+        HelperRequest::SetNice { pid, nice } => {
+            if *pid <= 1 {
+                return Err(format!("pid {} is not allowed", pid));
+            }
+            if !NICE_RANGE.contains(nice) {
+                return Err(format!(
+                    "nice {} is outside {}..={}",
+                    nice,
+                    NICE_RANGE.start(),
+                    NICE_RANGE.end()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn nice_targets_match_roblox_sober_and_wine() {
+        assert!(is_nice_target(&["RobloxPlayerBet"]));
+        assert!(is_nice_target(&["bash", "sober"]));
+        assert!(is_nice_target(&["services.exe", "wine64-preloader"]));
+        assert!(!is_nice_target(&["firefox", "firefox-bin"]));
+        assert!(!is_nice_target(&[]));
+    }
+
+    #[test]
+    fn validate_rejects_init_and_out_of_range_nice() {
+        assert!(validate(&HelperRequest::SetNice { pid: 1, nice: 0 }).is_err());
+        assert!(validate(&HelperRequest::SetNice { pid: 1234, nice: -20 }).is_err());
+        assert!(validate(&HelperRequest::SetNice { pid: 1234, nice: -10 }).is_ok());
+    }
+}
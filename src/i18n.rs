@@ -0,0 +1,107 @@
+use crate::config::Language;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use unic_langid::{langid, LanguageIdentifier};
+
+const VI_FTL: &str = include_str!("../locales/vi.ftl");
+const EN_FTL: &str = include_str!("../locales/en.ftl");
+
+/// Ngôn ngữ thực tế đang dùng (sau khi resolve `Language::Auto`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Vi,
+    En,
+}
+
+struct Catalogs {
+    vi: FluentBundle<FluentResource>,
+    en: FluentBundle<FluentResource>,
+}
+
+static CATALOGS: OnceLock<Catalogs> = OnceLock::new();
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+fn bundle(id: LanguageIdentifier, source: &str) -> FluentBundle<FluentResource> {
+    let resource = FluentResource::try_new(source.to_string())
+        .unwrap_or_else(|(resource, errors)| {
+            eprintln!("✗ Lỗi catalog {}: {:?}", id, errors);
+            resource
+        });
+
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Không chèn ký tự Unicode isolation (hiển thị lỗi trên console)
+    bundle.set_use_isolating(false);
+    if let Err(errors) = bundle.add_resource(resource) {
+        eprintln!("✗ Trùng key trong catalog: {:?}", errors);
+    }
+    bundle
+}
+
+fn catalogs() -> &'static Catalogs {
+    CATALOGS.get_or_init(|| Catalogs {
+        vi: bundle(langid!("vi"), VI_FTL),
+        en: bundle(langid!("en"), EN_FTL),
+    })
+}
+
+/// Đổi ngôn ngữ (áp dụng ngay cho mọi chuỗi dịch sau đó)
+pub fn set_language(language: Language) {
+    let locale = match language {
+        Language::Auto => detect_locale(),
+        Language::Vi => Locale::Vi,
+        Language::En => Locale::En,
+    };
+    CURRENT.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn current() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::En,
+        _ => Locale::Vi,
+    }
+}
+
+/// Locale của hệ điều hành: tiếng Việt nếu là `vi*`, còn lại tiếng Anh
+pub fn detect_locale() -> Locale {
+    match sys_locale::get_locale() {
+        Some(locale) if locale.to_lowercase().starts_with("vi") => Locale::Vi,
+        Some(_) => Locale::En,
+        None => Locale::Vi,
+    }
+}
+
+/// Dịch `key` theo ngôn ngữ hiện tại; thiếu key → fallback tiếng Việt → chính key
+pub fn translate(key: &str, args: Option<&FluentArgs>) -> String {
+    let catalogs = catalogs();
+    let bundles = match current() {
+        Locale::Vi => [&catalogs.vi, &catalogs.en],
+        Locale::En => [&catalogs.en, &catalogs.vi],
+    };
+
+    for bundle in bundles {
+        let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+            continue;
+        };
+        let mut errors = Vec::new();
+        return bundle
+            .format_pattern(pattern, args, &mut errors)
+            .into_owned();
+    }
+    key.to_string()
+}
+
+/// `t!("key")` hoặc `t!("key", name = value, ...)` (value: mọi kiểu `Display`)
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value.to_string());)+
+        $crate::i18n::translate($key, Some(&args))
+    }};
+}
+
+pub(crate) use t;
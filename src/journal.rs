@@ -0,0 +1,532 @@
+use crate::cgroup;
+use crate::helper;
+use crate::helper_protocol::{HelperRequest, Setting};
+use crate::i18n::t;
+use crate::logging::{log_error, log_info, log_warn};
+use crate::paths;
+use crate::process;
+use crate::threads::{self, ThreadKnob};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use sysinfo::{Pid, System};
+
+const JOURNAL_FILE_NAME: &str = "journal.json";
+/// `flock` quanh mỗi lần đọc-sửa-ghi journal (file journal bị thay bằng rename nên không khóa trực tiếp được)
+const JOURNAL_LOCK_FILE_NAME: &str = "journal.lock";
+const INSTANCE_LOCK_FILE_NAME: &str = "booster.lock";
+
+/// Khóa đọc-sửa-ghi file journal giữa các thread
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Process này đang giữ `InstanceLock` → được ghi thay đổi mới vào journal
+static INSTANCE_HELD: AtomicBool = AtomicBool::new(false);
+
+/// Khóa độc quyền `<state_dir>/booster.lock` giữa các process booster của cùng user
+///
+/// Chỉ process giữ khóa mới được recover: journal của booster khác đang chạy không
+/// phải "sót lại sau crash". Khóa tự nhả khi drop hoặc khi process thoát (kể cả bị kill).
+pub struct InstanceLock {
+    _file: fs::File,
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        INSTANCE_HELD.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Lấy `InstanceLock`, `Err` nếu booster khác (GUI, `launch`, ...) đang giữ khóa
+pub fn lock_instance() -> Result<InstanceLock, String> {
+    let path = paths::get().state_dir.join(INSTANCE_LOCK_FILE_NAME);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| t!("journal-lock-failed", error = err))?;
+    }
+    match open_locked(&path) {
+        Ok(Some(file)) => {
+            INSTANCE_HELD.store(true, Ordering::SeqCst);
+            Ok(InstanceLock { _file: file })
+        }
+        Ok(None) => Err(t!("journal-locked", path = path.display())),
+        Err(err) => Err(t!("journal-lock-failed", error = err)),
+    }
+}
+
+/// `flock(LOCK_EX | LOCK_NB)`, `Ok(None)` nếu process khác đang giữ
+#[cfg(unix)]
+fn open_locked(path: &Path) -> io::Result<Option<fs::File>> {
+    use std::os::unix::io::AsRawFd;
+
+    let file = fs::OpenOptions::new().create(true).write(true).truncate(false).open(path)?;
+    // SAFETY: flock chỉ thao tác trên fd còn sống của `file`
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } == 0 {
+        return Ok(Some(file));
+    }
+    let err = io::Error::last_os_error();
+    if err.kind() == io::ErrorKind::WouldBlock {
+        Ok(None)
+    } else {
+        Err(err)
+    }
+}
+
+/// Mở không chia sẻ (share mode 0): handle thứ hai bị sharing violation
+#[cfg(windows)]
+fn open_locked(path: &Path) -> io::Result<Option<fs::File>> {
+    use std::os::windows::fs::OpenOptionsExt;
+
+    /// `ERROR_SHARING_VIOLATION`
+    const SHARING_VIOLATION: i32 = 32;
+    match fs::OpenOptions::new().create(true).write(true).truncate(false).share_mode(0).open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(err) if err.raw_os_error() == Some(SHARING_VIOLATION) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Đợi tới khi lấy được `flock(LOCK_EX)` của `path` (nhả khi drop file)
+#[cfg(unix)]
+fn lock_blocking(path: &Path) -> io::Result<fs::File> {
+    use std::os::unix::io::AsRawFd;
+
+    let file = fs::OpenOptions::new().create(true).write(true).truncate(false).open(path)?;
+    // SAFETY: flock chỉ thao tác trên fd còn sống của `file`
+    while unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+    Ok(file)
+}
+
+#[cfg(windows)]
+fn lock_blocking(path: &Path) -> io::Result<fs::File> {
+    loop {
+        if let Some(file) = open_locked(path)? {
+            return Ok(file);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+/// Một thay đổi hệ thống đã (hoặc sắp) được áp dụng, kèm đủ thông tin để hoàn tác
+///
+/// `name` dùng để không đụng nhầm process khác khi PID đã bị tái sử dụng.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    /// Đổi nice của process, `original` = giá trị trước khi đổi
+    Nice { pid: u32, name: String, original: i32 },
+    /// Process bị tạm dừng (SIGSTOP)
+    Paused { pid: u32, name: String },
+    /// `timeBeginPeriod(period)` (Windows)
+    TimerResolution { period: u32 },
+    /// Setting hệ thống đổi qua privileged helper, `previous` = giá trị trước khi đổi
+    Setting { setting: Setting, previous: String },
+    /// Đổi CPU affinity, `original` = danh sách CPU trước khi đổi (vd `0-15`)
+    Affinity { pid: u32, name: String, original: String },
+    /// Chuyển process sang cgroup khác, `original` = cgroup cũ (vd `/user.slice/...`)
+    Cgroup { pid: u32, name: String, original: String },
+    /// Đổi nice/affinity/timer slack của một thread, `name` = tên thread
+    Thread {
+        pid: u32,
+        tid: u32,
+        name: String,
+        knob: ThreadKnob,
+        original: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::Nice { pid, name, original } => {
+                t!("journal-change-nice", name = name, pid = pid, original = original)
+            }
+            Self::Paused { pid, name } => t!("journal-change-paused", name = name, pid = pid),
+            Self::TimerResolution { period } => t!("journal-change-timer", period = period),
+            Self::Setting { setting, previous } => {
+                t!("journal-change-setting", setting = setting, previous = previous)
+            }
+            Self::Affinity { pid, name, original } => {
+                t!("journal-change-affinity", name = name, pid = pid, original = original)
+            }
+            Self::Cgroup { pid, name, original } => {
+                t!("journal-change-cgroup", name = name, pid = pid, original = original)
+            }
+            Self::Thread {
+                pid,
+                tid,
+                name,
+                knob,
+                original,
+            } => t!(
+                "journal-change-thread",
+                knob = knob,
+                name = name,
+                pid = pid,
+                tid = tid,
+                original = original
+            ),
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Kết quả hoàn tác một thay đổi khi recover
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Restored,
+    /// Không cần hoàn tác (process đã thoát, OS tự hoàn tác, ...)
+    Skipped(String),
+    /// Hoàn tác lỗi → giữ lại trong journal để thử lại lần sau
+    Failed(String),
+}
+
+/// Ghi thay đổi vào journal TRƯỚC khi áp dụng
+///
+/// Không ghi được journal → không được áp dụng thay đổi (không hoàn tác được nếu crash).
+/// Process không giữ `InstanceLock` (booster khác đang chạy) → từ chối.
+pub fn record(change: Change) -> Result<(), String> {
+    if !INSTANCE_HELD.load(Ordering::SeqCst) {
+        return Err(t!("journal-not-owner"));
+    }
+    Journal::current().record(change)
+}
+
+/// Xóa thay đổi khỏi journal sau khi đã hoàn tác (hoặc áp dụng thất bại)
+pub fn complete(change: &Change) {
+    if let Err(err) = Journal::current().complete(change) {
+        log_error!("{}", err);
+    }
+}
+
+/// Các thay đổi chưa được hoàn tác (còn sót lại sau crash)
+pub fn pending() -> Result<Vec<Change>, String> {
+    Journal::current().pending()
+}
+
+/// Hoàn tác mọi thay đổi còn trong journal, theo thứ tự ngược với lúc ghi
+///
+/// Thay đổi hoàn tác lỗi được giữ lại trong journal.
+pub fn recover() -> Result<Vec<(Change, Outcome)>, String> {
+    let mut sys = System::new();
+    sys.refresh_processes();
+    Journal::current().recover_with(|change| undo(&sys, change))
+}
+
+/// `<state_dir>/journal.json`
+///
+/// Mỗi lần đọc-sửa-ghi giữ `LOCK` (giữa các thread) và `flock` của `journal.lock`
+/// (giữa các process: GUI, `launch`, `recover`).
+struct Journal {
+    path: PathBuf,
+    lock_path: PathBuf,
+}
+
+/// Giữ hai khóa của `Journal` tới khi drop
+struct JournalGuard {
+    _file: fs::File,
+    _guard: std::sync::MutexGuard<'static, ()>,
+}
+
+impl Journal {
+    fn new(state_dir: &Path) -> Self {
+        Self {
+            path: state_dir.join(JOURNAL_FILE_NAME),
+            lock_path: state_dir.join(JOURNAL_LOCK_FILE_NAME),
+        }
+    }
+
+    fn current() -> Self {
+        Self::new(&paths::get().state_dir)
+    }
+
+    fn lock(&self) -> Result<JournalGuard, String> {
+        let guard = LOCK.lock().unwrap();
+        if let Some(parent) = self.lock_path.parent() {
+            fs::create_dir_all(parent).map_err(|err| t!("journal-lock-failed", error = err))?;
+        }
+        let file = lock_blocking(&self.lock_path).map_err(|err| t!("journal-lock-failed", error = err))?;
+        Ok(JournalGuard {
+            _file: file,
+            _guard: guard,
+        })
+    }
+
+    fn record(&self, change: Change) -> Result<(), String> {
+        let _guard = self.lock()?;
+        let mut changes = self.read().map_err(|err| t!("journal-read-failed", error = err))?;
+        changes.push(change);
+        self.write(&changes).map_err(|err| t!("journal-write-failed", error = err))
+    }
+
+    fn complete(&self, change: &Change) -> Result<(), String> {
+        let _guard = self.lock()?;
+        let mut changes = self.read().map_err(|err| t!("journal-read-failed", error = err))?;
+        let Some(index) = changes.iter().position(|entry| entry == change) else {
+            return Ok(());
+        };
+        changes.remove(index);
+        self.write(&changes).map_err(|err| t!("journal-write-failed", error = err))
+    }
+
+    fn pending(&self) -> Result<Vec<Change>, String> {
+        let _guard = self.lock()?;
+        self.read().map_err(|err| t!("journal-read-failed", error = err))
+    }
+
+    /// Hoàn tác bằng `undo` từ thay đổi mới nhất về cũ nhất, giữ lại các thay đổi `Failed`
+    fn recover_with(&self, undo: impl Fn(&Change) -> Outcome) -> Result<Vec<(Change, Outcome)>, String> {
+        let _guard = self.lock()?;
+        let changes = self.read().map_err(|err| t!("journal-read-failed", error = err))?;
+        if changes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let results: Vec<(Change, Outcome)> = changes
+            .into_iter()
+            .rev()
+            .map(|change| {
+                let outcome = undo(&change);
+                (change, outcome)
+            })
+            .collect();
+
+        let mut remaining: Vec<Change> = results
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, Outcome::Failed(_)))
+            .map(|(change, _)| change.clone())
+            .collect();
+        remaining.reverse();
+        self.write(&remaining).map_err(|err| t!("journal-write-failed", error = err))?;
+        Ok(results)
+    }
+
+    fn read(&self) -> io::Result<Vec<Change>> {
+        match fs::read_to_string(&self.path) {
+            Ok(data) => serde_json::from_str(&data)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Ghi file tạm rồi rename để crash giữa chừng không làm hỏng journal
+    fn write(&self, changes: &[Change]) -> io::Result<()> {
+        if changes.is_empty() {
+            return match fs::remove_file(&self.path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+                _ => Ok(()),
+            };
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(changes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+/// Hoàn tác một thay đổi (không sửa file journal)
+pub fn undo(sys: &System, change: &Change) -> Outcome {
+    match change {
+        Change::Nice { pid, name, original } => {
+            if !is_same_process(sys, *pid, name) {
+                return Outcome::Skipped(t!("journal-process-gone"));
+            }
+            match process::set_nice_privileged(*pid, *original) {
+                Ok(()) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+        Change::Paused { pid, name } => {
+            if !is_same_process(sys, *pid, name) {
+                return Outcome::Skipped(t!("journal-process-gone"));
+            }
+            match process::resume(*pid) {
+                Ok(()) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+        // Timer resolution gắn với process đã gọi, Windows tự hoàn tác khi process thoát
+        Change::TimerResolution { .. } => Outcome::Skipped(t!("journal-timer-auto-reverted")),
+        Change::Setting { setting, previous } => {
+            if setting.read_current().as_deref() == Ok(previous.as_str()) {
+                return Outcome::Skipped(t!("journal-setting-unchanged"));
+            }
+            let request = HelperRequest::Set {
+                setting: setting.clone(),
+                value: previous.clone(),
+            };
+            match helper::run(&request) {
+                Ok(_) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+        Change::Affinity { pid, name, original } => {
+            if !is_same_process(sys, *pid, name) {
+                return Outcome::Skipped(t!("journal-process-gone"));
+            }
+            match process::set_affinity(*pid, original) {
+                Ok(()) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+        Change::Cgroup { pid, name, original } => {
+            if !is_same_process(sys, *pid, name) {
+                return Outcome::Skipped(t!("journal-process-gone"));
+            }
+            match cgroup::move_pid(*pid, &cgroup::absolute(original)) {
+                Ok(()) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+        Change::Thread {
+            pid,
+            tid,
+            name,
+            knob,
+            original,
+        } => {
+            // TID bị tái sử dụng trong cùng process → so tên thread
+            if threads::read_comm(*pid, *tid).as_deref() != Some(name.as_str()) {
+                return Outcome::Skipped(t!("journal-process-gone"));
+            }
+            match knob.set(*tid, original) {
+                Ok(()) => Outcome::Restored,
+                Err(err) => Outcome::Failed(err),
+            }
+        }
+    }
+}
+
+/// PID vẫn là process cũ (cùng tên)
+///
+/// `sys` có thể là snapshot cũ (revert ngay sau khi Roblox thoát) → Linux kiểm tra lại `/proc/<pid>`.
+fn is_same_process(sys: &System, pid: u32, name: &str) -> bool {
+    let alive = !cfg!(target_os = "linux") || std::path::Path::new(&format!("/proc/{}", pid)).exists();
+    alive
+        && sys
+            .process(Pid::from_u32(pid))
+            .is_some_and(|process| name.is_empty() || process.name() == name)
+}
+
+/// `recover` + ghi log từng thay đổi; `false` nếu còn thay đổi chưa hoàn tác được
+pub fn recover_logged() -> bool {
+    let results = match recover() {
+        Ok(results) => results,
+        Err(err) => {
+            log_error!("✗ {}", err);
+            return false;
+        }
+    };
+    if results.is_empty() {
+        log_info!("{}", t!("journal-empty"));
+        return true;
+    }
+
+    log_info!("{}", t!("journal-recovering", count = results.len()));
+    let mut failed = 0;
+    for (change, outcome) in &results {
+        match outcome {
+            Outcome::Restored => log_info!("  {}", t!("journal-restored", change = change)),
+            Outcome::Skipped(reason) => {
+                log_info!("  {}", t!("journal-skipped", change = change, reason = reason))
+            }
+            Outcome::Failed(err) => {
+                failed += 1;
+                log_error!("  {}", t!("journal-failed", change = change, error = err))
+            }
+        }
+    }
+    if failed > 0 {
+        log_warn!("{}", t!("journal-kept", count = failed));
+    }
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn nice(pid: u32) -> Change {
+        Change::Nice {
+            pid,
+            name: format!("proc{}", pid),
+            original: 0,
+        }
+    }
+
+    #[test]
+    fn record_then_complete_removes_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path());
+        journal.record(nice(1)).unwrap();
+        journal.record(nice(2)).unwrap();
+        assert_eq!(journal.pending().unwrap(), vec![nice(1), nice(2)]);
+
+        journal.complete(&nice(1)).unwrap();
+        assert_eq!(journal.pending().unwrap(), vec![nice(2)]);
+        // Không có trong journal → bỏ qua
+        journal.complete(&nice(3)).unwrap();
+        journal.complete(&nice(2)).unwrap();
+        assert!(journal.pending().unwrap().is_empty());
+        assert!(!journal.path.exists());
+    }
+
+    #[test]
+    fn recover_undoes_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path());
+        for pid in 1..=3 {
+            journal.record(nice(pid)).unwrap();
+        }
+
+        let order = RefCell::new(Vec::new());
+        let results = journal
+            .recover_with(|change| {
+                order.borrow_mut().push(change.clone());
+                Outcome::Restored
+            })
+            .unwrap();
+        assert_eq!(*order.borrow(), vec![nice(3), nice(2), nice(1)]);
+        assert_eq!(results.len(), 3);
+        assert!(journal.pending().unwrap().is_empty());
+        assert!(journal.recover_with(|_| Outcome::Restored).unwrap().is_empty());
+    }
+
+    #[test]
+    fn recover_keeps_failed_entries_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path());
+        for pid in 1..=4 {
+            journal.record(nice(pid)).unwrap();
+        }
+
+        let results = journal
+            .recover_with(|change| match change {
+                Change::Nice { pid: 1 | 3, .. } => Outcome::Failed("denied".to_string()),
+                Change::Nice { pid: 2, .. } => Outcome::Skipped("gone".to_string()),
+                _ => Outcome::Restored,
+            })
+            .unwrap();
+        assert_eq!(results[0], (nice(4), Outcome::Restored));
+        assert_eq!(journal.pending().unwrap(), vec![nice(1), nice(3)]);
+
+        // Lần sau chỉ thử lại các thay đổi còn lỗi
+        let retried = journal.recover_with(|_| Outcome::Restored).unwrap();
+        assert_eq!(retried.len(), 2);
+        assert!(journal.pending().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,457 @@
+use crate::i18n::t;
+use crate::paths;
+use crate::uri_handler::{self, UriHandler};
+use crate::wine::{self, WinePrefix};
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+
+/// Flatpak ID của Sober (Roblox client cho Linux)
+const SOBER_APP_ID: &str = "org.vinegarhq.Sober";
+
+/// Chế độ launch trong URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchMode {
+    /// Vào game (place)
+    Play,
+    /// Mở app Roblox (không vào game)
+    App,
+    /// Roblox Studio
+    Edit,
+    Other(String),
+}
+
+impl fmt::Display for LaunchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Play => f.write_str("play"),
+            Self::App => f.write_str("app"),
+            Self::Edit => f.write_str("edit"),
+            Self::Other(mode) => f.write_str(mode),
+        }
+    }
+}
+
+/// Tham số launch từ URI `roblox-player:` / `roblox://`
+#[derive(Clone, PartialEq, Eq)]
+pub struct LaunchRequest {
+    /// URI gốc, chuyển nguyên cho client
+    pub uri: String,
+    pub launch_mode: LaunchMode,
+    pub place_id: Option<u64>,
+    /// Ticket đăng nhập (`gameinfo`), không được ghi ra log
+    pub game_info: Option<String>,
+    /// Release channel, `None` = production
+    pub channel: Option<String>,
+    /// Server cụ thể (`gameInstanceId` / `gameId`)
+    pub job_id: Option<String>,
+    /// Thời điểm bấm Play trên web (ms)
+    pub launch_time: Option<u64>,
+}
+
+// Debug tự viết để ticket không lọt vào log
+impl fmt::Debug for LaunchRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LaunchRequest")
+            .field("launch_mode", &self.launch_mode)
+            .field("place_id", &self.place_id)
+            .field("game_info", &self.game_info.as_ref().map(|_| "<redacted>"))
+            .field("channel", &self.channel)
+            .field("job_id", &self.job_id)
+            .field("launch_time", &self.launch_time)
+            .finish()
+    }
+}
+
+impl fmt::Display for LaunchRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.launch_mode)?;
+        if let Some(place_id) = self.place_id {
+            write!(f, ", place {}", place_id)?;
+        }
+        if let Some(channel) = &self.channel {
+            write!(f, ", channel {}", channel)?;
+        }
+        Ok(())
+    }
+}
+
+impl LaunchRequest {
+    /// Parse `roblox-player:1+launchmode:play+gameinfo:...` hoặc `roblox://experiences/start?placeId=...`
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let uri = uri.trim();
+        let lower = uri.to_ascii_lowercase();
+        if lower.starts_with("roblox-player:") {
+            Ok(Self::parse_player(uri, &uri["roblox-player:".len()..]))
+        } else if lower.starts_with("roblox://") {
+            Ok(Self::parse_roblox(uri, &uri["roblox://".len()..]))
+        } else if lower.starts_with("roblox:") {
+            Ok(Self::parse_roblox(uri, &uri["roblox:".len()..]))
+        } else {
+            Err(t!("launch-invalid-uri", uri = uri))
+        }
+    }
+
+    /// Scheme của URI (`roblox-player` / `roblox`)
+    pub fn scheme(&self) -> &'static str {
+        if self.uri.to_ascii_lowercase().starts_with("roblox-player:") {
+            "roblox-player"
+        } else {
+            "roblox"
+        }
+    }
+
+    fn empty(uri: &str, launch_mode: LaunchMode) -> Self {
+        Self {
+            uri: uri.to_string(),
+            launch_mode,
+            place_id: None,
+            game_info: None,
+            channel: None,
+            job_id: None,
+            launch_time: None,
+        }
+    }
+
+    /// `roblox-player:`: các cặp `key:value` nối bằng `+`
+    fn parse_player(uri: &str, rest: &str) -> Self {
+        let mut request = Self::empty(uri, LaunchMode::Play);
+        for part in rest.split('+') {
+            let Some((key, value)) = part.split_once(':') else {
+                continue;
+            };
+            let value = percent_decode(value);
+            match key.to_ascii_lowercase().as_str() {
+                "launchmode" => request.launch_mode = parse_mode(&value),
+                "gameinfo" => request.game_info = non_empty(value),
+                "channel" => request.channel = non_empty(value),
+                "launchtime" => request.launch_time = value.parse().ok(),
+                // placeId + gameId nằm trong query của PlaceLauncher URL
+                "placelauncherurl" => {
+                    let query = value.split_once('?').map_or("", |(_, query)| query);
+                    request.apply_query(query);
+                }
+                _ => {}
+            }
+        }
+        request
+    }
+
+    /// `roblox://experiences/start?placeId=...` hoặc dạng cũ `roblox://placeId=...`
+    fn parse_roblox(uri: &str, rest: &str) -> Self {
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let path = path.trim_matches('/');
+
+        let mut request = Self::empty(uri, LaunchMode::App);
+        if path.contains('=') {
+            request.apply_query(path);
+        }
+        request.apply_query(query);
+        if request.place_id.is_some() || path.eq_ignore_ascii_case("experiences/start") {
+            request.launch_mode = LaunchMode::Play;
+        }
+        request
+    }
+
+    fn apply_query(&mut self, query: &str) {
+        for pair in query.split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = percent_decode(value);
+            match key.to_ascii_lowercase().as_str() {
+                "placeid" => self.place_id = value.parse().ok().or(self.place_id),
+                "gameinstanceid" | "gameid" => self.job_id = non_empty(value).or(self.job_id.take()),
+                "channel" => self.channel = non_empty(value).or(self.channel.take()),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn parse_mode(value: &str) -> LaunchMode {
+    match value.to_ascii_lowercase().as_str() {
+        "play" => LaunchMode::Play,
+        "app" => LaunchMode::App,
+        "edit" => LaunchMode::Edit,
+        other => LaunchMode::Other(other.to_string()),
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Decode `%XX` (UTF-8); chuỗi hỏng giữ nguyên
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| value.to_string())
+}
+
+/// Roblox client dùng để launch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Client {
+    /// Handler URI trước khi booster được đăng ký (xem `uri_handler`)
+    Handler { id: String, command: Vec<String> },
+    /// Sober qua flatpak
+    Sober,
+    /// `RobloxPlayerBeta.exe` trong Wine prefix
+    Wine { prefix: PathBuf, exe: PathBuf },
+    /// Bản cài Windows gốc
+    Windows { exe: PathBuf },
+}
+
+impl fmt::Display for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Handler { id, .. } => write!(f, "{}", id),
+            Self::Sober => write!(f, "Sober (flatpak {})", SOBER_APP_ID),
+            Self::Wine { prefix, exe } => {
+                write!(f, "Wine ({}): {}", prefix.display(), exe.display())
+            }
+            Self::Windows { exe } => write!(f, "Windows: {}", exe.display()),
+        }
+    }
+}
+
+impl Client {
+    /// Client đầu tiên tìm thấy: handler cũ của scheme → Windows gốc → Sober → Wine
+    pub fn detect(request: &LaunchRequest) -> Result<Self, String> {
+        let client = if cfg!(target_os = "windows") {
+            previous_handler(request).or_else(|| windows_player().map(|exe| Self::Windows { exe }))
+        } else {
+            Self::first_available(
+                || previous_handler(request),
+                sober_installed,
+                || {
+                    paths::find_in_path("wine")?;
+                    wine::discover().into_iter().next()
+                },
+            )
+        };
+        client.ok_or_else(|| t!("launch-no-client"))
+    }
+
+    /// Thứ tự ưu tiên trên Linux: handler cũ → Sober → Wine prefix
+    ///
+    /// Nguồn sau chỉ được hỏi khi nguồn trước không có client (`flatpak info` chạy chậm).
+    fn first_available(
+        previous: impl FnOnce() -> Option<Self>,
+        sober: impl FnOnce() -> bool,
+        wine: impl FnOnce() -> Option<WinePrefix>,
+    ) -> Option<Self> {
+        previous()
+            .or_else(|| sober().then_some(Self::Sober))
+            .or_else(|| wine().map(Self::from))
+    }
+
+    fn command(&self, request: &LaunchRequest) -> Command {
+        match self {
+            Self::Handler { command: args, .. } => {
+                let mut command = Command::new(&args[0]);
+                command.args(&args[1..]);
+                command
+            }
+            Self::Sober => {
+                let mut command = Command::new("flatpak");
+                command.args(["run", SOBER_APP_ID, &request.uri]);
+                command
+            }
+            Self::Wine { prefix, exe } => {
+                let mut command = Command::new("wine");
+                command.env("WINEPREFIX", prefix).arg(exe).arg(&request.uri);
+                command
+            }
+            Self::Windows { exe } => {
+                let mut command = Command::new(exe);
+                command.arg(&request.uri);
+                command
+            }
+        }
+    }
+
+    /// Chạy client; `env` thêm vào môi trường của process (vd tuning Wine của profile)
+    pub fn spawn(&self, request: &LaunchRequest, env: &[(String, String)]) -> Result<Child, String> {
+        if request.launch_mode == LaunchMode::Edit {
+            return Err(t!("launch-studio-unsupported"));
+        }
+        self.command(request)
+            .envs(env.iter().map(|(key, value)| (key, value)))
+            .spawn()
+            .map_err(|err| t!("launch-spawn-failed", client = self, error = err))
+    }
+}
+
+/// Handler được ghi lại khi booster đăng ký làm URI handler → forward nguyên URI
+fn previous_handler(request: &LaunchRequest) -> Option<Client> {
+    let app_paths = paths::get();
+    let handler = UriHandler::with_exec(app_paths, PathBuf::new());
+    let id = handler.previous_handler(request.scheme())?;
+    let desktop_file = uri_handler::find_desktop_file(app_paths, &id)?;
+    let command = uri_handler::desktop_exec(&desktop_file, &request.uri)?;
+    Some(Client::Handler { id, command })
+}
+
+impl From<WinePrefix> for Client {
+    fn from(prefix: WinePrefix) -> Self {
+        Self::Wine {
+            prefix: prefix.path,
+            exe: prefix.player,
+        }
+    }
+}
+
+fn sober_installed() -> bool {
+    paths::find_in_path("flatpak").is_some()
+        && Command::new("flatpak")
+            .args(["info", SOBER_APP_ID])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+}
+
+fn windows_player() -> Option<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+        roots.push(PathBuf::from(local).join("Roblox"));
+    }
+    if let Some(program_files) = std::env::var_os("ProgramFiles(x86)") {
+        roots.push(PathBuf::from(program_files).join("Roblox"));
+    }
+    wine::newest_player(&roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_player_uri() {
+        let uri = "roblox-player:1+launchmode:play+gameinfo:TICKET+launchtime:1700000000000\
+                   +placelauncherurl:https%3A%2F%2Fassetgame.roblox.com%2Fgame%2FPlaceLauncher.ashx\
+                   %3Frequest%3DRequestGame%26placeId%3D1818%26gameId%3Dabc-123\
+                   +browsertrackerid:42+robloxLocale:en_us+channel:zbeta";
+        let request = LaunchRequest::parse(uri).unwrap();
+        assert_eq!(request.scheme(), "roblox-player");
+        assert_eq!(request.launch_mode, LaunchMode::Play);
+        assert_eq!(request.place_id, Some(1818));
+        assert_eq!(request.job_id.as_deref(), Some("abc-123"));
+        assert_eq!(request.game_info.as_deref(), Some("TICKET"));
+        assert_eq!(request.channel.as_deref(), Some("zbeta"));
+        assert_eq!(request.launch_time, Some(1_700_000_000_000));
+        assert_eq!(request.uri, uri);
+        assert!(!format!("{:?}", request).contains("TICKET"));
+    }
+
+    #[test]
+    fn parses_player_uri_modes() {
+        let request = LaunchRequest::parse("ROBLOX-PLAYER:1+launchmode:app").unwrap();
+        assert_eq!(request.launch_mode, LaunchMode::App);
+        assert_eq!(request.place_id, None);
+
+        let request = LaunchRequest::parse("roblox-player:1+launchmode:edit").unwrap();
+        assert_eq!(request.launch_mode, LaunchMode::Edit);
+    }
+
+    #[test]
+    fn parses_roblox_uris() {
+        let request = LaunchRequest::parse("roblox://placeId=1818&gameInstanceId=job-1").unwrap();
+        assert_eq!(request.scheme(), "roblox");
+        assert_eq!(request.launch_mode, LaunchMode::Play);
+        assert_eq!(request.place_id, Some(1818));
+        assert_eq!(request.job_id.as_deref(), Some("job-1"));
+
+        let request = LaunchRequest::parse("roblox://experiences/start?placeId=920587237").unwrap();
+        assert_eq!(request.launch_mode, LaunchMode::Play);
+        assert_eq!(request.place_id, Some(920587237));
+
+        let request = LaunchRequest::parse("roblox:").unwrap();
+        assert_eq!(request.launch_mode, LaunchMode::App);
+        assert_eq!(request.place_id, None);
+    }
+
+    #[test]
+    fn malformed_fields_are_ignored() {
+        let request =
+            LaunchRequest::parse("roblox-player:garbage+launchtime:soon+gameinfo:+:+placelauncherurl:").unwrap();
+        assert_eq!(request.launch_mode, LaunchMode::Play);
+        assert_eq!(request.launch_time, None);
+        assert_eq!(request.game_info, None);
+        assert_eq!(request.place_id, None);
+
+        let request = LaunchRequest::parse("roblox://placeId=abc").unwrap();
+        assert_eq!(request.place_id, None);
+        assert_eq!(request.launch_mode, LaunchMode::App);
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        assert!(LaunchRequest::parse("").is_err());
+        assert!(LaunchRequest::parse("   ").is_err());
+        assert!(LaunchRequest::parse("https://www.roblox.com/games/1818").is_err());
+        assert!(LaunchRequest::parse("roblox-studio:1+launchmode:edit").is_err());
+    }
+
+    #[test]
+    fn percent_decode_keeps_broken_sequences() {
+        assert_eq!(percent_decode("a%20b"), "a b");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+
+    fn handler() -> Client {
+        Client::Handler {
+            id: "org.vinegarhq.Vinegar.player.desktop".to_string(),
+            command: vec!["vinegar".to_string(), "player".to_string()],
+        }
+    }
+
+    fn prefix() -> WinePrefix {
+        WinePrefix {
+            path: PathBuf::from("/home/user/.wine"),
+            player: PathBuf::from("/home/user/.wine/drive_c/Roblox/RobloxPlayerBeta.exe"),
+        }
+    }
+
+    #[test]
+    fn detect_prefers_previous_handler() {
+        let client = Client::first_available(
+            || Some(handler()),
+            || panic!("Sober must not be probed when a previous handler exists"),
+            || panic!("Wine must not be probed when a previous handler exists"),
+        );
+        assert_eq!(client, Some(handler()));
+    }
+
+    #[test]
+    fn detect_prefers_sober_over_wine() {
+        let client = Client::first_available(
+            || None,
+            || true,
+            || panic!("Wine must not be probed when Sober is installed"),
+        );
+        assert_eq!(client, Some(Client::Sober));
+    }
+
+    #[test]
+    fn detect_falls_back_to_wine() {
+        let client = Client::first_available(|| None, || false, || Some(prefix()));
+        assert_eq!(client, Some(Client::from(prefix())));
+        assert_eq!(Client::first_available(|| None, || false, || None), None);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::i18n::t;
+use crate::logging::{Level, LogEntry};
+use crate::paths;
+use iced::widget::{button, column, row, scrollable, text, text_input, Column};
+use iced::{Alignment, Color, Command, Element, Length};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+/// Số dòng log tối đa trong panel
+const LOG_PANEL_LEN: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub enum LogPanelMessage {
+    FilterChanged(String),
+    /// `None` = tất cả mức độ
+    LevelFilter(Option<Level>),
+    Copy,
+    Save,
+}
+
+/// State của trang Logs
+#[derive(Default)]
+pub struct LogPanel {
+    entries: VecDeque<LogEntry>,
+    filter: String,
+    level: Option<Level>,
+    status: Option<String>,
+}
+
+impl LogPanel {
+    pub fn new(history: Vec<LogEntry>) -> Self {
+        Self {
+            entries: history.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() == LOG_PANEL_LEN {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.level == Level::Error)
+            .count()
+    }
+
+    /// Copy trả về `Command` ghi clipboard
+    pub fn update<Message: 'static>(&mut self, message: LogPanelMessage) -> Command<Message> {
+        match message {
+            LogPanelMessage::FilterChanged(filter) => self.filter = filter,
+            LogPanelMessage::LevelFilter(level) => self.level = level,
+            LogPanelMessage::Copy => {
+                self.status = Some(t!("logs-copied"));
+                return iced::clipboard::write(self.visible_text());
+            }
+            LogPanelMessage::Save => {
+                self.status = Some(match self.save() {
+                    Ok(path) => t!("logs-saved", path = path.display()),
+                    Err(err) => t!("logs-save-failed", error = err),
+                });
+            }
+        }
+        Command::none()
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &LogEntry> {
+        let filter = self.filter.to_lowercase();
+        self.entries.iter().filter(move |entry| {
+            self.level.is_none_or(|level| entry.level == level)
+                && (filter.is_empty() || entry.message.to_lowercase().contains(&filter))
+        })
+    }
+
+    fn visible_text(&self) -> String {
+        self.visible()
+            .map(|entry| entry.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Ghi log đang hiển thị vào `<state_dir>/logs/`
+    fn save(&self) -> std::io::Result<PathBuf> {
+        let dir = paths::get().state_dir.join("logs");
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join(format!(
+            "roblox_booster-{}.log",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, self.visible_text() + "\n")?;
+        Ok(path)
+    }
+
+    pub fn view(&self) -> Element<'_, LogPanelMessage> {
+        let level_button = |label: String, level: Option<Level>| {
+            let label = if self.level == level {
+                format!("• {}", label)
+            } else {
+                label
+            };
+            button(text(label).size(12))
+                .padding(6)
+                .on_press(LogPanelMessage::LevelFilter(level))
+        };
+
+        let toolbar = row![
+            text_input(&t!("logs-filter-placeholder"), &self.filter)
+                .on_input(LogPanelMessage::FilterChanged)
+                .padding(8),
+            level_button(t!("logs-level-all"), None),
+            level_button("Info".to_string(), Some(Level::Info)),
+            level_button("Warn".to_string(), Some(Level::Warn)),
+            level_button("Error".to_string(), Some(Level::Error)),
+        ]
+        .spacing(6)
+        .align_items(Alignment::Center);
+
+        let lines = self
+            .visible()
+            .fold(Column::new().spacing(2), |lines, entry| {
+                let color = match entry.level {
+                    Level::Info => Color::from_rgb(0.8, 0.8, 0.8),
+                    Level::Warn => Color::from_rgb(1.0, 0.75, 0.3),
+                    Level::Error => Color::from_rgb(1.0, 0.4, 0.4),
+                };
+                lines.push(text(entry.to_string()).size(12).style(color))
+            });
+
+        let mut content = column![
+            row![
+                button(text("📋 COPY").size(14))
+                    .padding(10)
+                    .on_press(LogPanelMessage::Copy),
+                button(text(t!("logs-button-save")).size(14))
+                    .padding(10)
+                    .on_press(LogPanelMessage::Save),
+            ]
+            .spacing(12)
+            .align_items(Alignment::Center),
+            toolbar,
+        ]
+        .spacing(10);
+
+        if let Some(status) = &self.status {
+            content = content.push(text(status).size(12));
+        }
+
+        content.push(scrollable(lines).height(Length::Fill)).into()
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+use tokio::sync::broadcast;
+
+/// Số dòng log giữ lại trong bộ nhớ (cho log panel)
+const HISTORY_LEN: usize = 1000;
+
+/// Mức độ của một dòng log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub message: String,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {:<5} {}",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            self.level,
+            self.message
+        )
+    }
+}
+
+struct Logger {
+    history: Mutex<VecDeque<LogEntry>>,
+    sender: broadcast::Sender<LogEntry>,
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+fn logger() -> &'static Logger {
+    LOGGER.get_or_init(|| Logger {
+        history: Mutex::new(VecDeque::with_capacity(HISTORY_LEN)),
+        sender: broadcast::channel(256).0,
+    })
+}
+
+/// Ghi log: in ra console (INFO → stdout, WARN/ERROR → stderr) và gửi cho log panel
+pub fn log(level: Level, message: String) {
+    match level {
+        Level::Info => println!("{}", message),
+        Level::Warn | Level::Error => eprintln!("{}", message),
+    }
+
+    let entry = LogEntry {
+        timestamp: Local::now(),
+        level,
+        message: message.trim().to_string(),
+    };
+
+    let logger = logger();
+    {
+        let mut history = logger.history.lock().unwrap();
+        if history.len() == HISTORY_LEN {
+            history.pop_front();
+        }
+        history.push_back(entry.clone());
+    }
+    let _ = logger.sender.send(entry);
+}
+
+/// Các dòng log đã ghi (kể cả trước khi UI subscribe)
+pub fn history() -> Vec<LogEntry> {
+    logger().history.lock().unwrap().iter().cloned().collect()
+}
+
+pub fn subscribe() -> broadcast::Receiver<LogEntry> {
+    logger().sender.subscribe()
+}
+
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Info, format!($($arg)*))
+    };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Warn, format!($($arg)*))
+    };
+}
+
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::Level::Error, format!($($arg)*))
+    };
+}
+
+pub(crate) use {log_error, log_info, log_warn};
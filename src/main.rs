@@ -1,30 +1,110 @@
+mod autostart;
+mod booster;
+mod capabilities;
+mod cgroup;
+mod cli;
 mod config;
+mod dashboard;
+mod events;
+mod explorer;
+mod focus;
+mod helper;
+mod helper_protocol;
+mod i18n;
+mod journal;
+mod launcher;
+mod log_panel;
+mod logging;
+mod metrics;
+mod optimizations;
+mod paths;
+mod process;
+mod session;
+mod session_panel;
+mod settings;
+mod threads;
 mod ui;
+mod uri_handler;
+mod wine;
 
+use cli::Cli;
+use config::{Config, Language};
+use i18n::t;
+use logging::{log_error, log_info};
+use optimizations::Availability;
 use iced::{Application, Settings};
 
 fn main() -> iced::Result {
+    // Ngôn ngữ theo hệ thống cho tới khi đọc được config
+    i18n::set_language(Language::Auto);
+
+    let cli = match Cli::parse() {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("✗ {}", err);
+            eprintln!("{}", cli::usage());
+            std::process::exit(2);
+        }
+    };
+
+    let app_paths = paths::init(cli.config_path.clone());
+    if let Err(err) = config::set_cli_overrides(cli.overrides.clone()) {
+        eprintln!("✗ {}", err);
+        std::process::exit(2);
+    }
+    if let Err(err) = app_paths.ensure_dirs() {
+        log_error!("{}", t!("main-create-dirs-failed", error = err));
+    }
+    // Chỉ GUI: lệnh CLI chạy từ thư mục bất kỳ, không đụng vào config.json ở đó
+    if cli.command.is_none() {
+        match app_paths.migrate_legacy_config(&paths::legacy_config_path()) {
+            Ok(true) => log_info!(
+                "{}",
+                t!("main-config-migrated", path = app_paths.config_file.display())
+            ),
+            Ok(false) => {}
+            Err(err) => log_error!("{}", t!("main-config-migrate-failed", error = err)),
+        }
+    }
+
+    let config = Config::load();
+    i18n::set_language(config.language);
+
+    if let Some(command) = &cli.command {
+        std::process::exit(cli::run(command));
+    }
+
     // Banner
     println!("╔══════════════════════════════════════╗");
     println!("║     ROBLOX BOOSTER v0.1.0            ║");
-    println!("║     Tối ưu hóa Roblox cho Windows    ║");
+    println!("║     {:<33}║", t!("main-banner-subtitle"));
     println!("╚══════════════════════════════════════╝");
     println!();
-    println!("Tính năng:");
-    println!("  ✓ Timer Resolution (1ms)");
-    println!("  ✓ GPU Priority Boost");
-    println!("  ✓ Memory Cleanup (60s interval)");
-    println!("  ✓ Auto-Detection");
-    println!("  ✓ Config Persistence");
-    println!("  ✓ CPU Affinity (P-cores)");
+    println!("{}", t!("main-banner-features"));
+    for optimization in optimizations::registry() {
+        match optimization.availability() {
+            Availability::Unavailable(reason) => {
+                println!("  ✗ {} ({})", optimization.name(), reason)
+            }
+            Availability::Available if config.is_enabled(optimization.id()) => {
+                println!("  ✓ {}", optimization.name())
+            }
+            Availability::Available => {
+                println!("  ○ {} ({})", optimization.name(), t!("main-banner-disabled"))
+            }
+        }
+    }
     println!();
-    println!("⚠️  Khuyến nghị: Chạy với quyền Administrator");
+    println!("{}", t!("main-banner-admin-hint"));
+    println!("📁 Config: {}", app_paths.config_file.display());
     println!();
     
     ui::RobloxBooster::run(Settings {
+        flags: config,
         window: iced::window::Settings {
-            size: iced::Size::new(450.0, 500.0),
-            resizable: false,
+            size: iced::Size::new(760.0, 640.0),
+            min_size: Some(iced::Size::new(480.0, 400.0)),
+            resizable: true,
             decorations: true,
             ..Default::default()
         },
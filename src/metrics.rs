@@ -0,0 +1,183 @@
+use sysinfo::{Components, ProcessRefreshKind, System, UpdateKind};
+
+/// Một process Roblox được phát hiện
+#[derive(Debug, Clone)]
+pub struct ProcessInfo {
+    pub name: String,
+    pub pid: u32,
+    /// % của một core (giống Task Manager / top)
+    pub cpu: f32,
+    /// Bytes
+    pub memory: u64,
+}
+
+/// Pressure Stall Information (Linux, `avg10` của dòng `some`, đơn vị %)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pressure {
+    pub cpu: f32,
+    pub memory: f32,
+    pub io: f32,
+}
+
+/// Một lần lấy mẫu hệ thống, dùng cho dashboard
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSample {
+    /// % tổng CPU
+    pub system_cpu: f32,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    /// % tổng CPU (đã chia cho số core)
+    pub roblox_cpu: f32,
+    pub roblox_memory: u64,
+    /// `None` nếu không có `/proc/pressure` (Windows, kernel cũ)
+    pub pressure: Option<Pressure>,
+    /// Nhiệt độ cao nhất trong các sensor (°C)
+    pub temperature: Option<f32>,
+    pub roblox_processes: Vec<ProcessInfo>,
+    pub active_optimizations: Vec<String>,
+}
+
+impl MetricsSample {
+    pub fn memory_percent(&self) -> f32 {
+        percent(self.memory_used, self.memory_total)
+    }
+
+    pub fn roblox_memory_percent(&self) -> f32 {
+        percent(self.roblox_memory, self.memory_total)
+    }
+}
+
+fn percent(part: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        (part as f64 / total as f64 * 100.0) as f32
+    }
+}
+
+/// Lấy mẫu CPU/RAM/PSI/nhiệt độ
+///
+/// CPU usage của sysinfo là delta giữa hai lần refresh nên `Sampler` cần
+/// được gọi định kỳ với cùng một `System`.
+pub struct Sampler {
+    components: Components,
+}
+
+impl Sampler {
+    pub fn new() -> Self {
+        Self {
+            components: Components::new_with_refreshed_list(),
+        }
+    }
+
+    pub fn sample<F>(&mut self, sys: &mut System, is_roblox: F) -> MetricsSample
+    where
+        F: Fn(&str) -> bool,
+    {
+        sys.refresh_cpu();
+        sys.refresh_memory();
+        // cmd/environ đọc một lần cho mỗi process: `session::detect` cần để gom session Wine
+        sys.refresh_processes_specifics(
+            ProcessRefreshKind::new()
+                .with_memory()
+                .with_cpu()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet)
+                .with_environ(UpdateKind::OnlyIfNotSet),
+        );
+        self.components.refresh();
+
+        let cpu_count = sys.cpus().len().max(1) as f32;
+        let mut roblox_processes: Vec<ProcessInfo> = sys
+            .processes()
+            .iter()
+            .filter(|(_, process)| is_roblox(process.name()))
+            .map(|(pid, process)| ProcessInfo {
+                name: process.name().to_string(),
+                pid: pid.as_u32(),
+                cpu: process.cpu_usage(),
+                memory: process.memory(),
+            })
+            .collect();
+        roblox_processes.sort_by_key(|process| process.pid);
+
+        let temperature = self
+            .components
+            .iter()
+            .map(|component| component.temperature())
+            .filter(|temp| temp.is_finite() && *temp > 0.0)
+            .reduce(f32::max);
+
+        MetricsSample {
+            system_cpu: sys.global_cpu_info().cpu_usage(),
+            memory_used: sys.used_memory(),
+            memory_total: sys.total_memory(),
+            roblox_cpu: roblox_processes.iter().map(|p| p.cpu).sum::<f32>() / cpu_count,
+            roblox_memory: roblox_processes.iter().map(|p| p.memory).sum(),
+            pressure: read_pressure(),
+            temperature,
+            roblox_processes,
+            active_optimizations: Vec::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_pressure() -> Option<Pressure> {
+    Some(Pressure {
+        cpu: read_psi_avg10("cpu")?,
+        memory: read_psi_avg10("memory")?,
+        io: read_psi_avg10("io")?,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_pressure() -> Option<Pressure> {
+    None
+}
+
+/// Đọc `avg10` của dòng `some` trong `/proc/pressure/<resource>`
+#[cfg(target_os = "linux")]
+fn read_psi_avg10(resource: &str) -> Option<f32> {
+    let contents = std::fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+    parse_psi_avg10(&contents)
+}
+
+/// Format: `some avg10=0.12 avg60=0.05 avg300=0.01 total=123456`
+#[cfg(target_os = "linux")]
+fn parse_psi_avg10(contents: &str) -> Option<f32> {
+    contents
+        .lines()
+        .find(|line| line.starts_with("some "))?
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("avg10="))?
+        .parse()
+        .ok()
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::parse_psi_avg10;
+
+    #[test]
+    fn parses_avg10_of_some_line() {
+        let contents = "some avg10=1.25 avg60=0.50 avg300=0.10 total=123456\n\
+                        full avg10=9.00 avg60=0.00 avg300=0.00 total=42\n";
+        assert_eq!(parse_psi_avg10(contents), Some(1.25));
+    }
+
+    #[test]
+    fn finds_some_line_after_full_line() {
+        let contents = "full avg10=9.00 avg60=0.00 avg300=0.00 total=42\n\
+                        some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n";
+        assert_eq!(parse_psi_avg10(contents), Some(0.0));
+    }
+
+    #[test]
+    fn rejects_missing_or_malformed_avg10() {
+        assert_eq!(parse_psi_avg10(""), None);
+        assert_eq!(parse_psi_avg10("full avg10=1.00 total=1\n"), None);
+        assert_eq!(parse_psi_avg10("some avg60=1.00 total=1\n"), None);
+        assert_eq!(parse_psi_avg10("some avg10=abc total=1\n"), None);
+    }
+}
@@ -0,0 +1,1346 @@
+use crate::capabilities::{self, Capability};
+use crate::cgroup;
+use crate::config::Config;
+use crate::focus::Focus;
+use crate::helper;
+use crate::helper_protocol::{HelperRequest, Setting};
+use crate::i18n::t;
+use crate::journal::{self, Change};
+use crate::logging::{log_error, log_info};
+use crate::process;
+use crate::session::{self, RobloxSession};
+use crate::threads::{self, ThreadClass, ThreadKnob, ThreadPolicy};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use sysinfo::{Pid, Signal, System};
+
+/// Optimization có chạy được trên máy hiện tại không
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Availability {
+    Available,
+    /// Kèm lý do (hiển thị trong banner/settings)
+    Unavailable(String),
+}
+
+impl Availability {
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Available)
+    }
+}
+
+/// Dữ liệu mỗi chu kỳ boost loop truyền cho optimization
+pub struct OptimizationContext<'a> {
+    pub sys: &'a System,
+    pub config: &'a Config,
+    pub roblox_running: bool,
+    /// Cửa sổ đang focus (`Unknown` nếu không theo dõi được hoặc tắt `follow_focus`)
+    pub focus: Focus,
+    /// Thời gian người dùng không thao tác (X11), `None` = không biết
+    pub idle: Option<Duration>,
+}
+
+/// Thay đổi optimization sẽ làm nếu không ở chế độ dry-run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAction {
+    /// Đối tượng bị thay đổi (process, setting hệ thống, ...)
+    pub target: String,
+    pub before: String,
+    pub after: String,
+}
+
+impl fmt::Display for PlannedAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} → {}", self.target, self.before, self.after)
+    }
+}
+
+/// Một tính năng tối ưu mà engine bật/tắt được
+///
+/// Engine gọi `apply` mỗi chu kỳ khi optimization được bật trong config (nên
+/// `apply` phải idempotent) và `revert` khi tắt booster hoặc tắt optimization.
+pub trait Optimization: Send + Sync {
+    /// Key ổn định, dùng trong config (`enable_<id>`) và catalog dịch
+    fn id(&self) -> &'static str;
+
+    fn name(&self) -> String {
+        t!(&format!("optimization-{}", self.id()))
+    }
+
+    fn description(&self) -> String {
+        t!(&format!("optimization-{}-hint", self.id()))
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    /// Hệ điều hành hiện tại có hỗ trợ không
+    fn supported(&self) -> Availability {
+        Availability::Available
+    }
+
+    /// Quyền/tính năng hệ thống cần có (xem `capabilities`)
+    fn requirements(&self) -> &'static [Capability] {
+        &[]
+    }
+
+    /// Hệ điều hành + từng capability trong `requirements` (probe đã cache)
+    fn availability(&self) -> Availability {
+        if let Availability::Unavailable(reason) = self.supported() {
+            return Availability::Unavailable(reason);
+        }
+        for &capability in self.requirements() {
+            if let Err(reason) = capabilities::probe(capability) {
+                return Availability::Unavailable(t!(
+                    "cap-requirement-missing",
+                    capability = capability,
+                    reason = reason
+                ));
+            }
+        }
+        Availability::Available
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String>;
+
+    /// Dry-run: các thay đổi `apply` sẽ làm với `ctx`, không đụng vào hệ thống
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction>;
+
+    fn revert(&self, _sys: &System) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Mô tả ngắn khi đang có hiệu lực (dashboard), `None` = không làm gì
+    fn status(&self, config: &Config) -> Option<String>;
+
+    /// PID đang bị optimization tác động (process explorer)
+    fn affected_pids(&self) -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Trạng thái với một session Roblox (trang Phiên chơi), `None` = không tác động
+    fn session_status(&self, _session: &RobloxSession) -> Option<String> {
+        None
+    }
+}
+
+/// Tất cả optimization theo thứ tự áp dụng (revert theo thứ tự ngược lại)
+pub fn registry() -> Vec<Arc<dyn Optimization>> {
+    vec![
+        Arc::new(TimerResolution::default()),
+        Arc::new(MemoryCleanup),
+        Arc::new(AutoDetection),
+        Arc::new(SessionBoost::default()),
+        Arc::new(ThreadTuning::default()),
+        Arc::new(PauseList::default()),
+        Arc::new(PrivilegedSetting::new("cpu_governor", Setting::Governor, "performance")),
+        // Tắt autogroup → nice của Roblox có tác dụng với process ở session khác
+        Arc::new(PrivilegedSetting::new(
+            "sched_autogroup",
+            Setting::Sysctl {
+                key: "kernel.sched_autogroup_enabled".to_string(),
+            },
+            "0",
+        )),
+    ]
+}
+
+/// Tên field trong config.json bật/tắt optimization
+pub fn config_key(id: &str) -> String {
+    format!("enable_{}", id)
+}
+
+// ========================================
+// TIMER RESOLUTION (System-wide, Windows)
+// ========================================
+
+/// Set timer resolution xuống 1ms - KHÔNG CẦN ADMIN
+/// Áp dụng cho toàn hệ thống, benefit cho tất cả apps
+#[derive(Default)]
+pub struct TimerResolution {
+    active: AtomicBool,
+}
+
+impl TimerResolution {
+    pub const ID: &'static str = "timer_resolution";
+}
+
+impl Optimization for TimerResolution {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn supported(&self) -> Availability {
+        if cfg!(target_os = "windows") {
+            Availability::Available
+        } else {
+            Availability::Unavailable(t!("optimization-windows-only"))
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn apply(&self, _ctx: &OptimizationContext) -> Result<(), String> {
+        if self.active.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let change = Change::TimerResolution { period: 1 };
+        journal::record(change.clone())?;
+        let result = winmm_call("timeBeginPeriod", 1)
+            .and_then(|code| match code {
+                0 => Ok(()),
+                code => Err(t!("timer-apply-failed", code = code)),
+            });
+        if let Err(err) = result {
+            journal::complete(&change);
+            return Err(err);
+        }
+        self.active.store(true, Ordering::SeqCst);
+        log_info!("{}", t!("timer-applied"));
+        log_info!("  {}", t!("timer-applied-benefit"));
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn apply(&self, _ctx: &OptimizationContext) -> Result<(), String> {
+        Err(t!("optimization-windows-only"))
+    }
+
+    fn plan(&self, _ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        if self.active.load(Ordering::SeqCst) {
+            return Vec::new();
+        }
+        vec![PlannedAction {
+            target: t!("plan-timer-target"),
+            before: t!("plan-timer-default"),
+            after: "1ms".to_string(),
+        }]
+    }
+
+    fn revert(&self, _sys: &System) -> Result<(), String> {
+        if !self.active.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            winmm_call("timeEndPeriod", 1)?;
+            journal::complete(&Change::TimerResolution { period: 1 });
+            log_info!("{}", t!("timer-restored"));
+        }
+        Ok(())
+    }
+
+    fn status(&self, _config: &Config) -> Option<String> {
+        self.active
+            .load(Ordering::SeqCst)
+            .then(|| t!("opt-timer-resolution"))
+    }
+}
+
+/// Gọi `timeBeginPeriod`/`timeEndPeriod` trong winmm.dll
+#[cfg(target_os = "windows")]
+fn winmm_call(function: &str, period: u32) -> Result<u32, String> {
+    use std::ffi::CString;
+    use windows::core::PCSTR;
+    use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryA};
+
+    unsafe {
+        let winmm = LoadLibraryA(PCSTR(c"winmm.dll".as_ptr() as *const u8))
+            .map_err(|_| t!("timer-winmm-failed"))?;
+        let name = CString::new(function).unwrap();
+        let func = GetProcAddress(winmm, PCSTR(name.as_ptr() as *const u8))
+            .ok_or_else(|| t!("timer-winmm-failed"))?;
+        let call: extern "system" fn(u32) -> u32 = std::mem::transmute(func);
+        Ok(call(period))
+    }
+}
+
+// ========================================
+// MEMORY CLEANUP (Current process, Windows)
+// ========================================
+
+/// Dọn RAM của chính app này mỗi chu kỳ - KHÔNG CẦN ADMIN
+/// Giải phóng RAM cho Roblox sử dụng
+pub struct MemoryCleanup;
+
+impl Optimization for MemoryCleanup {
+    fn id(&self) -> &'static str {
+        "memory_cleanup"
+    }
+
+    fn supported(&self) -> Availability {
+        if cfg!(target_os = "windows") {
+            Availability::Available
+        } else {
+            Availability::Unavailable(t!("optimization-windows-only"))
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn apply(&self, _ctx: &OptimizationContext) -> Result<(), String> {
+        use windows::Win32::System::ProcessStatus::EmptyWorkingSet;
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        unsafe {
+            EmptyWorkingSet(GetCurrentProcess())
+                .map_err(|err| t!("cleanup-failed", error = format!("{:?}", err)))?;
+        }
+        log_info!("{}", t!("cleanup-done"));
+        log_info!("  {}", t!("cleanup-done-benefit"));
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn apply(&self, _ctx: &OptimizationContext) -> Result<(), String> {
+        Err(t!("optimization-windows-only"))
+    }
+
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let pid = std::process::id();
+        let memory = ctx
+            .sys
+            .process(Pid::from_u32(pid))
+            .map_or(0, |process| process.memory());
+        vec![PlannedAction {
+            target: format!("roblox_booster (PID {})", pid),
+            before: format!("{:.0} MB", memory as f64 / 1024.0 / 1024.0),
+            after: t!("plan-cleanup-after"),
+        }]
+    }
+
+    fn status(&self, config: &Config) -> Option<String> {
+        Some(t!(
+            "opt-memory-cleanup",
+            seconds = config.effective_interval_seconds()
+        ))
+    }
+}
+
+// ========================================
+// ROBLOX DETECTION (Chỉ hiển thị)
+// ========================================
+
+/// Phát hiện Roblox để hiển thị status - KHÔNG CẦN ADMIN
+pub struct AutoDetection;
+
+impl Optimization for AutoDetection {
+    fn id(&self) -> &'static str {
+        "auto_detection"
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String> {
+        let sessions = session::detect(ctx.sys, ctx.config);
+
+        if sessions.is_empty() {
+            log_info!("{}", t!("detect-none"));
+        } else {
+            log_info!("{}", t!("detect-found"));
+            for (session, line, marker) in Self::describe(ctx, &sessions) {
+                match marker {
+                    Some(marker) => log_info!("   • {} {}", line, marker),
+                    None => log_info!("   • {}", line),
+                }
+                for member in &session.members {
+                    log_info!("      - {} (PID: {}) [{}]", member.name, member.pid, member.role);
+                }
+            }
+            log_info!("   {}", t!("detect-optimizing"));
+        }
+        Ok(())
+    }
+
+    /// Mỗi session Roblox sẽ được theo dõi là một action (instance chính được đánh dấu)
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let sessions = session::detect(ctx.sys, ctx.config);
+        Self::describe(ctx, &sessions)
+            .into_iter()
+            .map(|(_, line, marker)| PlannedAction {
+                target: line,
+                before: t!("plan-detect-untracked"),
+                after: marker.unwrap_or_else(|| t!("plan-detect-tracked")),
+            })
+            .collect()
+    }
+
+    fn status(&self, _config: &Config) -> Option<String> {
+        Some(t!("opt-auto-detection"))
+    }
+}
+
+impl AutoDetection {
+    /// Dòng mô tả từng session + đánh dấu instance chính/đang focus (nếu cần phân biệt)
+    fn describe<'a>(
+        ctx: &OptimizationContext,
+        sessions: &'a [RobloxSession],
+    ) -> Vec<(&'a RobloxSession, String, Option<String>)> {
+        let primary = session::primary(sessions, ctx.focus);
+        let marker = if ctx.focus.is_known() {
+            t!("detect-focused")
+        } else {
+            t!("detect-primary")
+        };
+        sessions
+            .iter()
+            .enumerate()
+            .map(|(index, session)| {
+                let line = t!(
+                    "detect-session",
+                    instance = index + 1,
+                    name = session.root_name(),
+                    pid = session.root,
+                    count = session.members.len()
+                );
+                let marked = primary == Some(session.root) && (sessions.len() > 1 || ctx.focus.is_known());
+                (session, line, marked.then(|| marker.clone()))
+            })
+            .collect()
+    }
+}
+
+/// Ghi journal rồi áp dụng; thành công → thêm `change` vào `changes`
+fn apply_journaled(changes: &mut Vec<Change>, change: Change, action: &dyn Fn() -> Result<(), String>) {
+    if let Err(err) = journal::record(change.clone()) {
+        log_error!("✗ {}", err);
+        return;
+    }
+    match action() {
+        Ok(()) => changes.push(change),
+        Err(err) => {
+            journal::complete(&change);
+            log_error!("✗ {}", err);
+        }
+    }
+}
+
+// ========================================
+// SESSION BOOST (cả cây process của Roblox)
+// ========================================
+
+/// cgroup chứa session Roblox (instance chính), cạnh cgroup của booster
+const SESSION_CGROUP: &str = "roblox_booster-roblox.scope";
+/// `cpu.weight` của cgroup session (mặc định của kernel: 100)
+const SESSION_CPU_WEIGHT: &str = "1000";
+/// cgroup chứa các instance nền khi chạy nhiều client
+const BACKGROUND_CGROUP: &str = "roblox_booster-background.scope";
+const BACKGROUND_CPU_WEIGHT: &str = "50";
+/// cgroup chứa các instance đang tiết kiệm pin (`cpu.max` theo `power_saving`)
+const POWER_SAVING_CGROUP: &str = "roblox_booster-powersave.scope";
+
+/// PID gốc của các instance cần tiết kiệm pin theo `power_saving`: mọi instance khi
+/// người dùng AFK, instance không được focus khi biết focus
+fn power_saving_roots(ctx: &OptimizationContext, sessions: &[RobloxSession]) -> Vec<u32> {
+    let power = &ctx.config.power_saving;
+    if !power.enabled {
+        return Vec::new();
+    }
+    let idle = power.is_idle(ctx.idle);
+    let unfocused = power.when_unfocused && ctx.focus.is_known();
+    let focused = session::primary(sessions, ctx.focus);
+    sessions
+        .iter()
+        .map(|session| session.root)
+        .filter(|root| idle || (unfocused && Some(*root) != focused))
+        .collect()
+}
+
+/// Vai trò của instance trong chu kỳ hiện tại
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tier {
+    Primary,
+    /// Instance nền (nhiều client hoặc không được focus)
+    Background,
+    /// Tiết kiệm pin, kèm giá trị `cpu.max`
+    PowerSaving(String),
+}
+
+/// Giá trị áp dụng cho mọi process của một instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MemberTarget {
+    nice: i32,
+    affinity: Option<String>,
+    tier: Tier,
+}
+
+impl MemberTarget {
+    /// Nice cao hơn mức boost = hạ priority → chỉ tăng nice; còn lại chỉ giảm nice
+    ///
+    /// Giảm nice thấp hơn `capabilities::nice_floor` (thiếu `SysNice`) → bỏ qua, tăng nice luôn được.
+    fn wants_nice(&self, current: i32) -> bool {
+        let wanted = if self.nice > process::BOOST_NICE {
+            current < self.nice
+        } else {
+            current > self.nice
+        };
+        wanted && (self.nice >= current || self.nice >= capabilities::nice_floor())
+    }
+
+    /// (tên cgroup, `cpu.weight`)
+    fn cgroup(&self) -> (&'static str, &'static str) {
+        match self.tier {
+            Tier::Primary => (SESSION_CGROUP, SESSION_CPU_WEIGHT),
+            Tier::Background => (BACKGROUND_CGROUP, BACKGROUND_CPU_WEIGHT),
+            Tier::PowerSaving(_) => (POWER_SAVING_CGROUP, BACKGROUND_CPU_WEIGHT),
+        }
+    }
+}
+
+/// Thay đổi đã áp dụng cho một process trong session
+struct TunedMember {
+    name: String,
+    target: MemberTarget,
+    changes: Vec<Change>,
+}
+
+/// Tăng priority, đặt affinity và chuyển vào cgroup riêng cho MỌI process của
+/// session Roblox (player, launcher/sandbox, wineserver, crash handler)
+///
+/// Nhiều client cùng lúc → mỗi client là một instance: instance chính được core
+/// tốt nhất, các instance nền bị hạ priority theo `multi_instance`. Instance không
+/// được focus hoặc người dùng AFK → giới hạn CPU theo `power_saving`.
+///
+/// Không đòi capability nào: từng bước tự bỏ qua khi thiếu quyền (giảm nice cần
+/// `SysNice`, cgroup cần `CgroupDelegation`) → thiếu quyền nice thì tiết kiệm pin
+/// (`cpu.max`, tăng nice) và affinity vẫn chạy.
+#[derive(Default)]
+pub struct SessionBoost {
+    tuned: Mutex<HashMap<u32, TunedMember>>,
+    /// PID gốc của các instance (theo thứ tự mở) + instance chính nếu có từ 2 instance
+    instances: Mutex<(Vec<u32>, Option<u32>)>,
+}
+
+impl SessionBoost {
+    pub const ID: &'static str = "session_boost";
+
+    /// Target theo PID gốc của session
+    ///
+    /// 1 instance, không biết focus → boost như nhau; nhiều instance hoặc biết focus →
+    /// instance chính (đang focus) được boost, instance nền bị hạ theo `multi_instance`.
+    /// Bật `power_saving`: instance nền vì không được focus, hoặc mọi instance khi AFK → tiết kiệm pin.
+    fn targets(ctx: &OptimizationContext, sessions: &[RobloxSession]) -> HashMap<u32, MemberTarget> {
+        let config = ctx.config;
+        let multi = sessions.len() > 1;
+        let split = multi || ctx.focus.is_known();
+        let primary = session::primary(sessions, ctx.focus);
+        let policy = &config.multi_instance;
+        let fastest = if multi { threads::fastest_cpus() } else { None };
+        let saving = power_saving_roots(ctx, sessions);
+
+        sessions
+            .iter()
+            .map(|session| {
+                let background = split && Some(session.root) != primary;
+                if saving.contains(&session.root) {
+                    let target = MemberTarget {
+                        nice: config.power_saving.nice,
+                        affinity: threads::resolve_affinity(policy.background_affinity.as_deref(), fastest.as_deref())
+                            .or(config.roblox_affinity.as_deref())
+                            .map(str::to_string),
+                        tier: Tier::PowerSaving(config.power_saving.cpu_max()),
+                    };
+                    return (session.root, target);
+                }
+                let (nice, affinity) = match (split, background) {
+                    (false, _) => (process::BOOST_NICE, None),
+                    (true, false) if !multi => (process::BOOST_NICE, None),
+                    (true, false) => (
+                        process::BOOST_NICE,
+                        threads::resolve_affinity(policy.primary_affinity.as_deref(), fastest.as_deref()),
+                    ),
+                    (true, true) => (
+                        policy.background_nice.unwrap_or(process::BOOST_NICE),
+                        threads::resolve_affinity(policy.background_affinity.as_deref(), fastest.as_deref()),
+                    ),
+                };
+                let target = MemberTarget {
+                    nice,
+                    affinity: affinity.or(config.roblox_affinity.as_deref()).map(str::to_string),
+                    tier: if background { Tier::Background } else { Tier::Primary },
+                };
+                (session.root, target)
+            })
+            .collect()
+    }
+
+    /// cgroup cho `target` nếu được delegate, `None` = bỏ qua bước cgroup
+    fn cgroup_dir(target: &MemberTarget) -> Option<PathBuf> {
+        capabilities::probe(Capability::CgroupDelegation).ok()?;
+        let (name, weight) = target.cgroup();
+        let result = cgroup::sibling(name).and_then(|dir| {
+            cgroup::write(&dir, "cpu.weight", weight)?;
+            if let Tier::PowerSaving(cpu_max) = &target.tier {
+                cgroup::write(&dir, "cpu.max", cpu_max)?;
+            }
+            Ok(dir)
+        });
+        match result {
+            Ok(dir) => Some(dir),
+            Err(err) => {
+                log_error!("✗ {}", err);
+                None
+            }
+        }
+    }
+
+    /// Áp dụng cho một process, trả về các thay đổi thành công (đã ghi journal)
+    fn tune(pid: u32, name: &str, target: &MemberTarget, cgroup_dir: Option<&Path>) -> Vec<Change> {
+        let mut changes = Vec::new();
+        let mut apply = |change: Change, action: &dyn Fn() -> Result<(), String>| {
+            apply_journaled(&mut changes, change, action)
+        };
+
+        if let Some(original) = process::read_nice(pid).filter(|nice| target.wants_nice(*nice)) {
+            let change = Change::Nice {
+                pid,
+                name: name.to_string(),
+                original,
+            };
+            apply(change, &|| process::set_nice(pid, target.nice));
+        }
+        if let Some(list) = &target.affinity {
+            if let Some(original) = process::read_affinity(pid).filter(|current| current != list) {
+                let change = Change::Affinity {
+                    pid,
+                    name: name.to_string(),
+                    original,
+                };
+                apply(change, &|| process::set_affinity(pid, list));
+            }
+        }
+        if let Some(dir) = cgroup_dir {
+            if let Some(original) = process::read_cgroup(pid).filter(|current| cgroup::absolute(current) != dir) {
+                let change = Change::Cgroup {
+                    pid,
+                    name: name.to_string(),
+                    original,
+                };
+                apply(change, &|| cgroup::move_pid(pid, dir));
+            }
+        }
+        changes
+    }
+
+    /// Hoàn tác (process còn chạy) hoặc chỉ xóa khỏi journal (process đã thoát)
+    fn untune(sys: &System, member: &TunedMember) {
+        for change in member.changes.iter().rev() {
+            if let journal::Outcome::Failed(err) = journal::undo(sys, change) {
+                log_error!("✗ {}", err);
+                continue;
+            }
+            journal::complete(change);
+        }
+    }
+
+    fn untune_all(&self, sys: &System) {
+        let mut tuned = self.tuned.lock().unwrap();
+        if tuned.is_empty() {
+            return;
+        }
+        for (_, member) in tuned.drain() {
+            Self::untune(sys, &member);
+        }
+        log_info!("{}", t!("session-reverted"));
+        for name in [SESSION_CGROUP, BACKGROUND_CGROUP, POWER_SAVING_CGROUP] {
+            if let Ok(dir) = cgroup::sibling(name) {
+                cgroup::remove_if_empty(&dir);
+            }
+        }
+    }
+
+    fn sessions(ctx: &OptimizationContext) -> Vec<RobloxSession> {
+        if ctx.roblox_running {
+            session::detect(ctx.sys, ctx.config)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Optimization for SessionBoost {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String> {
+        let sessions = Self::sessions(ctx);
+        let primary = (sessions.len() > 1).then(|| session::primary(&sessions, ctx.focus)).flatten();
+        *self.instances.lock().unwrap() = (sessions.iter().map(|session| session.root).collect(), primary);
+        if sessions.is_empty() {
+            self.untune_all(ctx.sys);
+            return Ok(());
+        }
+
+        let targets = Self::targets(ctx, &sessions);
+        let target_of = |pid: u32| {
+            sessions
+                .iter()
+                .find(|session| session.contains(pid))
+                .and_then(|session| targets.get(&session.root))
+        };
+
+        // Process đã thoát hoặc instance đổi vai trò (chính ↔ nền) → hoàn tác, áp dụng lại bên dưới
+        let mut tuned = self.tuned.lock().unwrap();
+        tuned.retain(|&pid, member| {
+            let keep = target_of(pid) == Some(&member.target)
+                && ctx
+                    .sys
+                    .process(Pid::from_u32(pid))
+                    .is_some_and(|process| process.name() == member.name);
+            if !keep {
+                Self::untune(ctx.sys, member);
+            }
+            keep
+        });
+
+        let mut cgroup_dirs: HashMap<&str, Option<PathBuf>> = HashMap::new();
+        for (index, session) in sessions.iter().enumerate() {
+            let new: Vec<_> = session
+                .members
+                .iter()
+                .filter(|member| !tuned.contains_key(&member.pid))
+                .collect();
+            let Some(target) = targets.get(&session.root).filter(|_| !new.is_empty()) else {
+                continue;
+            };
+            let dir = cgroup_dirs
+                .entry(target.cgroup().0)
+                .or_insert_with(|| Self::cgroup_dir(target))
+                .as_deref();
+            for member in &new {
+                let changes = Self::tune(member.pid, &member.name, target, dir);
+                tuned.insert(
+                    member.pid,
+                    TunedMember {
+                        name: member.name.clone(),
+                        target: target.clone(),
+                        changes,
+                    },
+                );
+            }
+            let key = match target.tier {
+                Tier::Primary => "session-tuned",
+                Tier::Background => "session-tuned-background",
+                Tier::PowerSaving(_) => "session-tuned-power-saving",
+            };
+            log_info!(
+                "{}",
+                t!(
+                    key,
+                    instance = index + 1,
+                    name = session.root_name(),
+                    pid = session.root,
+                    count = new.len(),
+                    limit = ctx.config.power_saving.cpu_limit_percent
+                )
+            );
+        }
+        Ok(())
+    }
+
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let tuned = self.tuned.lock().unwrap();
+        let sessions = Self::sessions(ctx);
+        let targets = Self::targets(ctx, &sessions);
+        let mut actions = Vec::new();
+
+        for (index, session) in sessions.iter().enumerate() {
+            let Some(target) = targets.get(&session.root) else {
+                continue;
+            };
+            let pending: Vec<_> = session
+                .members
+                .iter()
+                .filter(|member| tuned.get(&member.pid).is_none_or(|tuned| tuned.target != *target))
+                .collect();
+            if let Tier::PowerSaving(cpu_max) = &target.tier {
+                if !pending.is_empty() && capabilities::probe(Capability::CgroupDelegation).is_ok() {
+                    let before = process::read_cgroup(session.root)
+                        .and_then(|current| cgroup::read(&cgroup::absolute(&current), "cpu.max"))
+                        .unwrap_or_else(|| "max".to_string());
+                    actions.push(PlannedAction {
+                        target: format!("cpu.max {} (PID {}, #{})", session.root_name(), session.root, index + 1),
+                        before,
+                        after: cpu_max.clone(),
+                    });
+                }
+            }
+            for member in pending {
+                let target_name = format!("{} (PID {}, {}, #{})", member.name, member.pid, member.role, index + 1);
+                if let Some(nice) = process::read_nice(member.pid).filter(|nice| target.wants_nice(*nice)) {
+                    actions.push(PlannedAction {
+                        target: format!("nice {}", target_name),
+                        before: nice.to_string(),
+                        after: target.nice.to_string(),
+                    });
+                }
+                if let Some(list) = &target.affinity {
+                    if let Some(current) = process::read_affinity(member.pid).filter(|current| current != list) {
+                        actions.push(PlannedAction {
+                            target: format!("affinity {}", target_name),
+                            before: current,
+                            after: list.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        for (pid, member) in tuned.iter() {
+            if !sessions.iter().any(|session| session.contains(*pid)) && !member.changes.is_empty() {
+                actions.push(PlannedAction {
+                    target: format!("{} (PID {})", member.name, pid),
+                    before: t!("plan-session-tuned"),
+                    after: t!("plan-session-restored"),
+                });
+            }
+        }
+        actions
+    }
+
+    fn revert(&self, sys: &System) -> Result<(), String> {
+        self.untune_all(sys);
+        Ok(())
+    }
+
+    fn status(&self, config: &Config) -> Option<String> {
+        let tuned = self.tuned.lock().unwrap();
+        let changed: Vec<&TunedMember> = tuned.values().filter(|member| !member.changes.is_empty()).collect();
+        let count = changed.len();
+        if count == 0 {
+            return None;
+        }
+        let saving = changed
+            .iter()
+            .filter(|member| matches!(member.target.tier, Tier::PowerSaving(_)))
+            .count();
+        if saving == count {
+            return Some(t!(
+                "opt-power-saving",
+                count = count,
+                limit = config.power_saving.cpu_limit_percent
+            ));
+        }
+        let (instances, primary) = &*self.instances.lock().unwrap();
+        let primary = primary.and_then(|root| instances.iter().position(|pid| *pid == root));
+        Some(match primary {
+            Some(index) => t!(
+                "opt-instance-count",
+                instances = instances.len(),
+                primary = index + 1,
+                count = count
+            ),
+            None => t!("opt-session-count", count = count),
+        })
+    }
+
+    fn affected_pids(&self) -> Vec<u32> {
+        self.tuned.lock().unwrap().keys().copied().collect()
+    }
+
+    fn session_status(&self, session: &RobloxSession) -> Option<String> {
+        let tuned = self.tuned.lock().unwrap();
+        let members: Vec<&TunedMember> = session
+            .pids()
+            .filter_map(|pid| tuned.get(&pid))
+            .filter(|member| !member.changes.is_empty())
+            .collect();
+        let key = match members.first()?.target.tier {
+            Tier::Primary => "session-state-primary",
+            Tier::Background => "session-state-background",
+            Tier::PowerSaving(_) => "session-state-power-saving",
+        };
+        Some(t!(key, count = members.len()))
+    }
+}
+
+// ========================================
+// THREAD TUNING (theo nhóm thread của Roblox, Linux)
+// ========================================
+
+/// Thread đã áp dụng policy
+struct TunedThread {
+    pid: u32,
+    /// Tên thread lúc áp dụng (đổi tên → phân loại lại)
+    comm: String,
+    changes: Vec<Change>,
+}
+
+/// Phân loại thread của player (render, worker, audio, network) và áp dụng
+/// nice/affinity/timer slack theo `thread_policies`; thread mới được áp dụng ở chu kỳ sau
+#[derive(Default)]
+pub struct ThreadTuning {
+    /// (PID, TID) → thread đã áp dụng
+    tuned: Mutex<HashMap<(u32, u32), TunedThread>>,
+    /// `thread_policies` lúc áp dụng → đổi config thì áp dụng lại
+    policies: Mutex<Option<BTreeMap<ThreadClass, ThreadPolicy>>>,
+}
+
+impl ThreadTuning {
+    pub const ID: &'static str = "thread_tuning";
+
+    /// PID của player trong các session Roblox (bỏ instance đang tiết kiệm pin)
+    fn player_pids(ctx: &OptimizationContext) -> Vec<u32> {
+        if !ctx.roblox_running {
+            return Vec::new();
+        }
+        let sessions = session::detect(ctx.sys, ctx.config);
+        let saving = power_saving_roots(ctx, &sessions);
+        sessions
+            .iter()
+            .filter(|session| !saving.contains(&session.root))
+            .flat_map(|session| session.members.iter())
+            .filter(|member| member.role == session::MemberRole::Player)
+            .map(|member| member.pid)
+            .collect()
+    }
+
+    /// Giá trị đích của từng thuộc tính theo policy (`fastest` → core nhanh nhất, không có → bỏ)
+    fn targets(policy: &ThreadPolicy, fastest: Option<&str>) -> Vec<(ThreadKnob, String)> {
+        let mut targets = Vec::new();
+        if let Some(nice) = policy.nice {
+            targets.push((ThreadKnob::Nice, nice.to_string()));
+        }
+        if let Some(affinity) = threads::resolve_affinity(policy.affinity.as_deref(), fastest) {
+            targets.push((ThreadKnob::Affinity, affinity.to_string()));
+        }
+        if let Some(slack) = policy.timer_slack_ns {
+            targets.push((ThreadKnob::TimerSlack, slack.to_string()));
+        }
+        targets
+    }
+
+    /// Giảm nice thấp hơn `capabilities::nice_floor` (thiếu `SysNice`) → bỏ qua, tăng nice luôn được
+    fn nice_permitted(current: &str, target: &str) -> bool {
+        match (current.parse(), target.parse()) {
+            (Ok(current), Ok(target)) => threads::nice_permitted(current, target, capabilities::nice_floor()),
+            _ => true,
+        }
+    }
+
+    fn untune(sys: &System, thread: &TunedThread) {
+        for change in thread.changes.iter().rev() {
+            if let journal::Outcome::Failed(err) = journal::undo(sys, change) {
+                log_error!("✗ {}", err);
+                continue;
+            }
+            journal::complete(change);
+        }
+    }
+
+    fn untune_all(&self, sys: &System) {
+        let mut tuned = self.tuned.lock().unwrap();
+        if tuned.is_empty() {
+            return;
+        }
+        for (_, thread) in tuned.drain() {
+            Self::untune(sys, &thread);
+        }
+        log_info!("{}", t!("threads-reverted"));
+    }
+}
+
+impl Optimization for ThreadTuning {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn supported(&self) -> Availability {
+        if cfg!(target_os = "linux") {
+            Availability::Available
+        } else {
+            Availability::Unavailable(t!("optimization-linux-only"))
+        }
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String> {
+        let policies = &ctx.config.thread_policies;
+        if self.policies.lock().unwrap().as_ref() != Some(policies) {
+            self.untune_all(ctx.sys);
+            *self.policies.lock().unwrap() = Some(policies.clone());
+        }
+
+        let pids = Self::player_pids(ctx);
+        if pids.is_empty() {
+            self.untune_all(ctx.sys);
+            return Ok(());
+        }
+
+        let mut tuned = self.tuned.lock().unwrap();
+        tuned.retain(|&(pid, tid), thread| {
+            let keep = pids.contains(&pid) && threads::read_comm(pid, tid).as_deref() == Some(thread.comm.as_str());
+            if !keep {
+                Self::untune(ctx.sys, thread);
+            }
+            keep
+        });
+
+        let fastest = threads::fastest_cpus();
+        for pid in pids {
+            let mut count = 0;
+            for thread in threads::list(pid) {
+                if tuned.contains_key(&(pid, thread.tid)) {
+                    continue;
+                }
+                let Some(policy) = policies.get(&thread.class) else {
+                    continue;
+                };
+                let mut changes = Vec::new();
+                for (knob, target) in Self::targets(policy, fastest.as_deref()) {
+                    let Some(original) = knob.read(thread.tid).filter(|current| *current != target) else {
+                        continue;
+                    };
+                    if knob == ThreadKnob::Nice && !Self::nice_permitted(&original, &target) {
+                        continue;
+                    }
+                    let change = Change::Thread {
+                        pid,
+                        tid: thread.tid,
+                        name: thread.comm.clone(),
+                        knob,
+                        original,
+                    };
+                    apply_journaled(&mut changes, change, &|| knob.set(thread.tid, &target));
+                }
+                count += 1;
+                tuned.insert(
+                    (pid, thread.tid),
+                    TunedThread {
+                        pid,
+                        comm: thread.comm,
+                        changes,
+                    },
+                );
+            }
+            if count > 0 {
+                log_info!("{}", t!("threads-tuned", pid = pid, count = count));
+            }
+        }
+        Ok(())
+    }
+
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let tuned = self.tuned.lock().unwrap();
+        let pids = Self::player_pids(ctx);
+        let fastest = threads::fastest_cpus();
+        let mut actions = Vec::new();
+
+        for &pid in &pids {
+            for thread in threads::list(pid) {
+                if tuned.contains_key(&(pid, thread.tid)) {
+                    continue;
+                }
+                let Some(policy) = ctx.config.thread_policies.get(&thread.class) else {
+                    continue;
+                };
+                for (knob, target) in Self::targets(policy, fastest.as_deref()) {
+                    if let Some(current) = knob.read(thread.tid).filter(|current| *current != target) {
+                        actions.push(PlannedAction {
+                            target: format!("{} {} (TID {}, {})", knob, thread.comm, thread.tid, thread.class),
+                            before: current,
+                            after: target,
+                        });
+                    }
+                }
+            }
+        }
+        for (&(pid, tid), thread) in tuned.iter() {
+            if !pids.contains(&pid) && !thread.changes.is_empty() {
+                actions.push(PlannedAction {
+                    target: format!("{} (TID {})", thread.comm, tid),
+                    before: t!("plan-session-tuned"),
+                    after: t!("plan-session-restored"),
+                });
+            }
+        }
+        actions
+    }
+
+    fn revert(&self, sys: &System) -> Result<(), String> {
+        self.untune_all(sys);
+        Ok(())
+    }
+
+    fn status(&self, _config: &Config) -> Option<String> {
+        let count = self
+            .tuned
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|thread| !thread.changes.is_empty())
+            .count();
+        (count > 0).then(|| t!("opt-thread-count", count = count))
+    }
+
+    fn affected_pids(&self) -> Vec<u32> {
+        let tuned = self.tuned.lock().unwrap();
+        let pids: BTreeSet<u32> = tuned.values().map(|thread| thread.pid).collect();
+        pids.into_iter().collect()
+    }
+
+    fn session_status(&self, session: &RobloxSession) -> Option<String> {
+        let count = self
+            .tuned
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|thread| !thread.changes.is_empty() && session.contains(thread.pid))
+            .count();
+        (count > 0).then(|| t!("opt-thread-count", count = count))
+    }
+}
+
+// ========================================
+// PAUSE LIST (SIGSTOP/SIGCONT, Unix)
+// ========================================
+
+/// Tạm dừng process trong pause list khi Roblox chạy, chạy lại khi Roblox tắt
+/// hoặc process bị bỏ khỏi list.
+#[derive(Default)]
+pub struct PauseList {
+    /// PID đang bị tạm dừng → tên process (khớp entry trong journal)
+    paused: Mutex<HashMap<u32, String>>,
+}
+
+impl PauseList {
+    pub const ID: &'static str = "pause_list";
+
+    /// Chạy lại các process không còn cần dừng, dừng các process mới khớp list
+    fn sync(&self, sys: &System, config: &Config, roblox_running: bool) {
+        let mut paused = self.paused.lock().unwrap();
+
+        paused.retain(|&pid, name| {
+            let change = Change::Paused { pid, name: name.clone() };
+            let Some(process) = sys.process(Pid::from_u32(pid)) else {
+                journal::complete(&change);
+                return false;
+            };
+            if roblox_running && config.is_pause_process(process.name()) {
+                return true;
+            }
+            if process.kill_with(Signal::Continue) == Some(true) {
+                log_info!("{}", t!("pause-resumed", name = process.name(), pid = pid));
+            }
+            journal::complete(&change);
+            false
+        });
+
+        if !roblox_running {
+            return;
+        }
+
+        for (pid, process) in sys.processes() {
+            let pid = pid.as_u32();
+            if paused.contains_key(&pid) || !config.is_pause_process(process.name()) {
+                continue;
+            }
+            let change = Change::Paused {
+                pid,
+                name: process.name().to_string(),
+            };
+            if let Err(err) = journal::record(change.clone()) {
+                log_error!("✗ {}", err);
+                continue;
+            }
+            match process.kill_with(Signal::Stop) {
+                Some(true) => {
+                    log_info!("{}", t!("pause-paused", name = process.name(), pid = pid));
+                    paused.insert(pid, process.name().to_string());
+                }
+                Some(false) => {
+                    journal::complete(&change);
+                    log_error!("{}", t!("pause-failed", name = process.name(), pid = pid))
+                }
+                // Platform không hỗ trợ SIGSTOP
+                None => journal::complete(&change),
+            }
+        }
+    }
+}
+
+impl Optimization for PauseList {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn supported(&self) -> Availability {
+        if cfg!(unix) {
+            Availability::Available
+        } else {
+            Availability::Unavailable(t!("optimization-unix-only"))
+        }
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String> {
+        self.sync(ctx.sys, ctx.config, ctx.roblox_running);
+        Ok(())
+    }
+
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let paused = self.paused.lock().unwrap();
+        let mut actions = Vec::new();
+
+        for (pid, process) in ctx.sys.processes() {
+            let pid = pid.as_u32();
+            let matches = ctx.roblox_running && ctx.config.is_pause_process(process.name());
+            let (before, after) = match (paused.contains_key(&pid), matches) {
+                (false, true) => (t!("plan-process-running"), t!("plan-process-paused")),
+                (true, false) => (t!("plan-process-paused"), t!("plan-process-running")),
+                _ => continue,
+            };
+            actions.push(PlannedAction {
+                target: format!("{} (PID {})", process.name(), pid),
+                before,
+                after,
+            });
+        }
+        actions
+    }
+
+    fn revert(&self, sys: &System) -> Result<(), String> {
+        self.sync(sys, &Config::default(), false);
+        Ok(())
+    }
+
+    fn status(&self, _config: &Config) -> Option<String> {
+        let paused = self.paused.lock().unwrap().len();
+        (paused > 0).then(|| t!("opt-paused-count", count = paused))
+    }
+
+    fn affected_pids(&self) -> Vec<u32> {
+        self.paused.lock().unwrap().keys().copied().collect()
+    }
+}
+
+// ========================================
+// SETTING HỆ THỐNG QUA PRIVILEGED HELPER (Linux, cần root)
+// ========================================
+
+/// Đổi một setting hệ thống (governor, sysctl) khi Roblox chạy, trả lại giá trị
+/// cũ khi Roblox tắt hoặc booster tắt. Ghi qua `roblox_booster_helper` (pkexec).
+pub struct PrivilegedSetting {
+    id: &'static str,
+    setting: Setting,
+    value: &'static str,
+    /// Giá trị trước khi đổi, `Some` = đang áp dụng
+    previous: Mutex<Option<String>>,
+}
+
+impl PrivilegedSetting {
+    pub fn new(id: &'static str, setting: Setting, value: &'static str) -> Self {
+        Self {
+            id,
+            setting,
+            value,
+            previous: Mutex::new(None),
+        }
+    }
+
+    fn set(&self, value: &str) -> Result<(), String> {
+        helper::run(&HelperRequest::Set {
+            setting: self.setting.clone(),
+            value: value.to_string(),
+        })
+        .map(|_| ())
+    }
+
+    fn restore(&self) -> Result<(), String> {
+        let mut previous = self.previous.lock().unwrap();
+        let Some(value) = previous.clone() else {
+            return Ok(());
+        };
+        self.set(&value)?;
+        journal::complete(&Change::Setting {
+            setting: self.setting.clone(),
+            previous: value.clone(),
+        });
+        *previous = None;
+        log_info!("{}", t!("setting-restored", setting = &self.setting, value = value));
+        Ok(())
+    }
+}
+
+impl Optimization for PrivilegedSetting {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// Mỗi lần đổi có thể hỏi mật khẩu (polkit) → user tự bật
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn supported(&self) -> Availability {
+        if !cfg!(target_os = "linux") {
+            return Availability::Unavailable(t!("optimization-linux-only"));
+        }
+        match self.setting.read_current() {
+            Ok(_) => Availability::Available,
+            Err(err) => Availability::Unavailable(err),
+        }
+    }
+
+    fn requirements(&self) -> &'static [Capability] {
+        &[Capability::PrivilegedHelper]
+    }
+
+    fn apply(&self, ctx: &OptimizationContext) -> Result<(), String> {
+        if !ctx.roblox_running {
+            return self.restore();
+        }
+
+        let mut previous = self.previous.lock().unwrap();
+        if previous.is_some() {
+            return Ok(());
+        }
+        let current = self.setting.read_current()?;
+        if current == self.value {
+            return Ok(());
+        }
+
+        let change = Change::Setting {
+            setting: self.setting.clone(),
+            previous: current.clone(),
+        };
+        journal::record(change.clone())?;
+        if let Err(err) = self.set(self.value) {
+            journal::complete(&change);
+            return Err(err);
+        }
+        log_info!(
+            "{}",
+            t!("setting-applied", setting = &self.setting, from = &current, to = self.value)
+        );
+        *previous = Some(current);
+        Ok(())
+    }
+
+    fn plan(&self, ctx: &OptimizationContext) -> Vec<PlannedAction> {
+        let previous = self.previous.lock().unwrap().clone();
+        let current = self.setting.read_current().unwrap_or_else(|_| "?".to_string());
+        let after = match (ctx.roblox_running, previous) {
+            (true, None) if current != self.value => self.value.to_string(),
+            (false, Some(previous)) => previous,
+            _ => return Vec::new(),
+        };
+        vec![PlannedAction {
+            target: self.setting.to_string(),
+            before: current,
+            after,
+        }]
+    }
+
+    fn revert(&self, _sys: &System) -> Result<(), String> {
+        self.restore()
+    }
+
+    fn status(&self, _config: &Config) -> Option<String> {
+        self.previous
+            .lock()
+            .unwrap()
+            .is_some()
+            .then(|| format!("{} = {}", self.setting, self.value))
+    }
+}
@@ -0,0 +1,293 @@
+use crate::config;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+const APP_DIR_NAME: &str = "roblox_booster";
+const CONFIG_FILE_NAME: &str = "config.json";
+
+static APP_PATHS: OnceLock<AppPaths> = OnceLock::new();
+
+/// Các thư mục chuẩn của app theo từng nền tảng
+/// - Linux: XDG (`$XDG_CONFIG_HOME`, `$XDG_STATE_HOME`, `$XDG_CACHE_HOME`, `$XDG_DATA_HOME`)
+/// - Windows: `%APPDATA%` / `%LOCALAPPDATA%`
+/// - macOS: `~/Library/Application Support` / `~/Library/Caches`
+#[derive(Debug, Clone)]
+pub struct AppPaths {
+    pub config_dir: PathBuf,
+    pub state_dir: PathBuf,
+    pub cache_dir: PathBuf,
+    /// Thư mục data của user (không kèm tên app), vd `~/.local/share`
+    pub data_home: PathBuf,
+    pub config_file: PathBuf,
+    /// Config mặc định do admin cung cấp (user config override lên trên)
+    pub system_config_file: PathBuf,
+    /// `true` khi `config_file` đến từ `--config`
+    pub config_overridden: bool,
+}
+
+impl AppPaths {
+    /// Resolve paths từ environment hiện tại
+    pub fn resolve(config_override: Option<PathBuf>) -> Self {
+        Self::resolve_with(config_override, |key| env::var_os(key).map(PathBuf::from))
+    }
+
+    /// Resolve paths với env lookup tùy chỉnh (dùng cho HOME tạm)
+    pub fn resolve_with<F>(config_override: Option<PathBuf>, lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<PathBuf>,
+    {
+        let (config_base, state_base, cache_base, data_home) = platform_bases(&lookup);
+        let config_dir = config_base.join(APP_DIR_NAME);
+        let state_dir = state_base.join(APP_DIR_NAME);
+        let cache_dir = cache_base.join(APP_DIR_NAME);
+
+        let config_overridden = config_override.is_some();
+        let config_file = config_override.unwrap_or_else(|| config_dir.join(CONFIG_FILE_NAME));
+        let system_config_file = system_config_dir(&lookup)
+            .join(APP_DIR_NAME)
+            .join(CONFIG_FILE_NAME);
+
+        Self {
+            config_dir,
+            state_dir,
+            cache_dir,
+            data_home,
+            config_file,
+            system_config_file,
+            config_overridden,
+        }
+    }
+
+    /// Tạo các thư mục nếu chưa có
+    pub fn ensure_dirs(&self) -> io::Result<()> {
+        for dir in [&self.config_dir, &self.state_dir, &self.cache_dir] {
+            fs::create_dir_all(dir)?;
+        }
+        if let Some(parent) = self.config_file.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy `./config.json` cũ sang vị trí mới khi chưa có user config
+    ///
+    /// Chỉ copy file parse được thành `Config` (không lấy nhầm `config.json` của project
+    /// khác trong working directory); file cũ giữ nguyên. Trả về `Ok(true)` nếu đã migrate.
+    pub fn migrate_legacy_config(&self, legacy: &Path) -> io::Result<bool> {
+        if self.config_overridden || !legacy.is_file() || self.config_file.exists() {
+            return Ok(false);
+        }
+        let contents = fs::read_to_string(legacy)?;
+        if config::parse_config_file(&contents).is_err() {
+            return Ok(false);
+        }
+
+        if let Some(parent) = self.config_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Ghi file tạm cạnh đích rồi rename (cùng thư mục → không bao giờ rename giữa hai
+        // filesystem), crash giữa chừng không để lại config dở dang
+        let tmp = self.config_file.with_extension("json.tmp");
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, &self.config_file)?;
+        Ok(true)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn platform_bases<F>(lookup: &F) -> (PathBuf, PathBuf, PathBuf, PathBuf)
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    let home = lookup("USERPROFILE").unwrap_or_else(|| PathBuf::from("."));
+    let roaming = lookup("APPDATA").unwrap_or_else(|| home.join("AppData").join("Roaming"));
+    let local = lookup("LOCALAPPDATA").unwrap_or_else(|| home.join("AppData").join("Local"));
+    (roaming.clone(), local.clone(), local.join("cache"), roaming)
+}
+
+#[cfg(target_os = "macos")]
+fn platform_bases<F>(lookup: &F) -> (PathBuf, PathBuf, PathBuf, PathBuf)
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    let home = lookup("HOME").unwrap_or_else(|| PathBuf::from("."));
+    let support = home.join("Library").join("Application Support");
+    (support.clone(), support.clone(), home.join("Library").join("Caches"), support)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn platform_bases<F>(lookup: &F) -> (PathBuf, PathBuf, PathBuf, PathBuf)
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    let home = lookup("HOME").unwrap_or_else(|| PathBuf::from("."));
+
+    // XDG spec: biến môi trường phải là đường dẫn tuyệt đối, nếu không thì bỏ qua
+    let xdg = |key: &str, fallback: PathBuf| {
+        lookup(key)
+            .filter(|path| path.is_absolute())
+            .unwrap_or(fallback)
+    };
+
+    (
+        xdg("XDG_CONFIG_HOME", home.join(".config")),
+        xdg("XDG_STATE_HOME", home.join(".local").join("state")),
+        xdg("XDG_CACHE_HOME", home.join(".cache")),
+        xdg("XDG_DATA_HOME", home.join(".local").join("share")),
+    )
+}
+
+#[cfg(target_os = "windows")]
+fn system_config_dir<F>(lookup: &F) -> PathBuf
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    lookup("PROGRAMDATA").unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"))
+}
+
+#[cfg(target_os = "macos")]
+fn system_config_dir<F>(_lookup: &F) -> PathBuf
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    PathBuf::from("/Library/Application Support")
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn system_config_dir<F>(_lookup: &F) -> PathBuf
+where
+    F: Fn(&str) -> Option<PathBuf>,
+{
+    PathBuf::from("/etc")
+}
+
+/// Khởi tạo paths toàn cục (gọi một lần từ `main`)
+pub fn init(config_override: Option<PathBuf>) -> &'static AppPaths {
+    APP_PATHS.get_or_init(|| AppPaths::resolve(config_override))
+}
+
+/// Paths toàn cục; tự resolve với mặc định nếu `init` chưa được gọi
+pub fn get() -> &'static AppPaths {
+    APP_PATHS.get_or_init(|| AppPaths::resolve(None))
+}
+
+/// Đường dẫn `config.json` cũ (relative với working directory)
+pub fn legacy_config_path() -> PathBuf {
+    PathBuf::from(CONFIG_FILE_NAME)
+}
+
+/// Tìm executable `name` trong `$PATH`
+pub fn find_in_path(name: &str) -> Option<PathBuf> {
+    env::var_os("PATH").and_then(|paths| {
+        env::split_paths(&paths)
+            .map(|dir| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(home: &Path, vars: &[(&str, PathBuf)]) -> AppPaths {
+        let vars = vars.to_vec();
+        let home = home.to_path_buf();
+        AppPaths::resolve_with(None, move |key| {
+            vars.iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.clone())
+                .or_else(|| (key == "HOME").then(|| home.clone()))
+        })
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    #[test]
+    fn resolves_xdg_dirs_with_home_fallback() {
+        let home = Path::new("/home/player");
+        let paths = resolve(home, &[]);
+        assert_eq!(paths.config_dir, home.join(".config").join(APP_DIR_NAME));
+        assert_eq!(paths.state_dir, home.join(".local/state").join(APP_DIR_NAME));
+        assert_eq!(paths.cache_dir, home.join(".cache").join(APP_DIR_NAME));
+        assert_eq!(paths.data_home, home.join(".local/share"));
+        assert_eq!(paths.config_file, paths.config_dir.join(CONFIG_FILE_NAME));
+        assert_eq!(paths.system_config_file, Path::new("/etc/roblox_booster/config.json"));
+        assert!(!paths.config_overridden);
+
+        let paths = resolve(
+            home,
+            &[
+                ("XDG_CONFIG_HOME", PathBuf::from("/xdg/config")),
+                // Không tuyệt đối → bỏ qua theo XDG spec
+                ("XDG_STATE_HOME", PathBuf::from("relative/state")),
+            ],
+        );
+        assert_eq!(paths.config_dir, Path::new("/xdg/config").join(APP_DIR_NAME));
+        assert_eq!(paths.state_dir, home.join(".local/state").join(APP_DIR_NAME));
+    }
+
+    #[test]
+    fn config_override_replaces_config_file() {
+        let paths = AppPaths::resolve_with(Some(PathBuf::from("custom.json")), |_| None);
+        assert_eq!(paths.config_file, Path::new("custom.json"));
+        assert!(paths.config_overridden);
+    }
+
+    #[test]
+    fn migrates_valid_legacy_config_by_copying() {
+        let home = tempfile::tempdir().unwrap();
+        // Working directory khác thư mục config (có thể là filesystem khác)
+        let cwd = tempfile::tempdir().unwrap();
+        let legacy = cwd.path().join(CONFIG_FILE_NAME);
+        fs::write(&legacy, r#"{ "boost_interval_seconds": 30 }"#).unwrap();
+        let paths = resolve(home.path(), &[]);
+
+        assert!(paths.migrate_legacy_config(&legacy).unwrap());
+        assert_eq!(
+            fs::read_to_string(&paths.config_file).unwrap(),
+            r#"{ "boost_interval_seconds": 30 }"#
+        );
+        assert!(legacy.is_file());
+        assert!(!paths.config_file.with_extension("json.tmp").exists());
+        // Đã có user config → không migrate lần nữa
+        assert!(!paths.migrate_legacy_config(&legacy).unwrap());
+    }
+
+    #[test]
+    fn skips_migration_when_target_exists() {
+        let home = tempfile::tempdir().unwrap();
+        let cwd = tempfile::tempdir().unwrap();
+        let legacy = cwd.path().join(CONFIG_FILE_NAME);
+        fs::write(&legacy, r#"{ "auto_start": true }"#).unwrap();
+        let paths = resolve(home.path(), &[]);
+        fs::create_dir_all(&paths.config_dir).unwrap();
+        fs::write(&paths.config_file, "{}").unwrap();
+
+        assert!(!paths.migrate_legacy_config(&legacy).unwrap());
+        assert_eq!(fs::read_to_string(&paths.config_file).unwrap(), "{}");
+    }
+
+    #[test]
+    fn skips_files_that_are_not_booster_configs() {
+        let home = tempfile::tempdir().unwrap();
+        let cwd = tempfile::tempdir().unwrap();
+        let legacy = cwd.path().join(CONFIG_FILE_NAME);
+        let paths = resolve(home.path(), &[]);
+
+        for contents in [
+            "not json",
+            "[1, 2, 3]",
+            r#"{ "name": "my-project", "version": "1.0.0" }"#,
+            r#"{ "boost_interval_seconds": "soon" }"#,
+        ] {
+            fs::write(&legacy, contents).unwrap();
+            assert!(!paths.migrate_legacy_config(&legacy).unwrap(), "{}", contents);
+            assert!(!paths.config_file.exists());
+        }
+        assert!(!paths.migrate_legacy_config(&cwd.path().join("missing.json")).unwrap());
+    }
+}
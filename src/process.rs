@@ -0,0 +1,206 @@
+use crate::helper;
+use crate::helper_protocol::HelperRequest;
+use crate::i18n::t;
+
+/// Thông tin chi tiết của một process cho process explorer
+#[derive(Debug, Clone)]
+pub struct ProcessEntry {
+    pub pid: u32,
+    pub name: String,
+    /// % của một core
+    pub cpu: f32,
+    /// RSS (bytes)
+    pub memory: u64,
+    pub nice: Option<i32>,
+    /// Danh sách CPU được phép chạy, vd `0-7`
+    pub affinity: Option<String>,
+    pub cgroup: Option<String>,
+    pub roblox_match: bool,
+    /// PID gốc của session Roblox chứa process này (xem `session::detect`)
+    pub session: Option<u32>,
+    /// Số thứ tự instance (client) của session, từ 1 theo thứ tự mở
+    pub instance: Option<usize>,
+    pub in_pause_list: bool,
+    pub paused: bool,
+    pub boosted: bool,
+}
+
+impl ProcessEntry {
+    /// Đọc nice/affinity/cgroup từ `/proc` (chỉ gọi cho các dòng đang hiển thị)
+    pub fn load_details(&mut self) {
+        self.nice = read_nice(self.pid);
+        self.affinity = read_affinity(self.pid);
+        self.cgroup = read_cgroup(self.pid);
+    }
+}
+
+/// Priority dùng khi boost một process (nice -5 / ABOVE_NORMAL trên Windows)
+pub const BOOST_NICE: i32 = -5;
+
+/// Đọc nice hiện tại của process
+#[cfg(target_os = "linux")]
+pub fn read_nice(pid: u32) -> Option<i32> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // comm có thể chứa dấu cách → bỏ qua tới dấu ')' cuối
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    // Sau ')': state(3) ... nice là field 19 → index 16
+    fields.get(16)?.parse().ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_nice(_pid: u32) -> Option<i32> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_affinity(pid: u32) -> Option<String> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Cpus_allowed_list:"))
+        .map(|list| list.trim().to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_affinity(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Parse danh sách CPU dạng `0-3,6` (như `Cpus_allowed_list`)
+pub fn parse_cpu_list(list: &str) -> Result<Vec<usize>, String> {
+    let mut cpus = Vec::new();
+    for part in list.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        let (Ok(start), Ok(end)) = (start.trim().parse::<usize>(), end.trim().parse::<usize>()) else {
+            return Err(t!("process-invalid-cpu-list", list = list));
+        };
+        if start > end || end >= 1024 {
+            return Err(t!("process-invalid-cpu-list", list = list));
+        }
+        cpus.extend(start..=end);
+    }
+    if cpus.is_empty() {
+        return Err(t!("process-invalid-cpu-list", list = list));
+    }
+    Ok(cpus)
+}
+
+/// Giới hạn process chạy trên các CPU trong `list` (vd `0-7`)
+#[cfg(target_os = "linux")]
+pub fn set_affinity(pid: u32, list: &str) -> Result<(), String> {
+    let cpus = parse_cpu_list(list)?;
+    // SAFETY: cpu_set_t là bitmask thuần, CPU_ZERO/CPU_SET chỉ ghi vào set cục bộ
+    let result = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_ZERO(&mut set);
+        for cpu in cpus {
+            libc::CPU_SET(cpu, &mut set);
+        }
+        libc::sched_setaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(t!(
+            "process-setaffinity-failed",
+            pid = pid,
+            cpus = list,
+            error = std::io::Error::last_os_error()
+        ))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_affinity(_pid: u32, _list: &str) -> Result<(), String> {
+    Err(t!("optimization-linux-only"))
+}
+
+/// cgroup v2 path (dòng `0::/...`), fallback dòng đầu tiên với cgroup v1
+#[cfg(target_os = "linux")]
+pub fn read_cgroup(pid: u32) -> Option<String> {
+    let contents = std::fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .or_else(|| contents.lines().next().and_then(|line| line.splitn(3, ':').nth(2)))
+        .map(|path| path.to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_cgroup(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Đặt nice cho process
+///
+/// Giảm nice (tăng priority) cần CAP_SYS_NICE hoặc `RLIMIT_NICE` đủ cao.
+#[cfg(unix)]
+pub fn set_nice(pid: u32, nice: i32) -> Result<(), String> {
+    // SAFETY: setpriority chỉ đọc các tham số số nguyên
+    let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid as libc::id_t, nice) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(t!(
+            "process-setpriority-failed",
+            pid = pid,
+            nice = nice,
+            error = std::io::Error::last_os_error()
+        ))
+    }
+}
+
+/// `set_nice`, thiếu quyền → thử lại qua privileged helper (pkexec) nếu có
+pub fn set_nice_privileged(pid: u32, nice: i32) -> Result<(), String> {
+    set_nice(pid, nice).or_else(|err| {
+        if helper::availability().is_err() {
+            return Err(err);
+        }
+        helper::run(&HelperRequest::SetNice { pid, nice }).map(|_| ())
+    })
+}
+
+/// Cho process đang bị tạm dừng (SIGSTOP) chạy lại
+#[cfg(unix)]
+pub fn resume(pid: u32) -> Result<(), String> {
+    // SAFETY: kill chỉ đọc các tham số số nguyên
+    let result = unsafe { libc::kill(pid as libc::pid_t, libc::SIGCONT) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(t!(
+            "process-resume-failed",
+            pid = pid,
+            error = std::io::Error::last_os_error()
+        ))
+    }
+}
+
+#[cfg(not(unix))]
+pub fn resume(_pid: u32) -> Result<(), String> {
+    Err(t!("optimization-unix-only"))
+}
+
+#[cfg(target_os = "windows")]
+pub fn set_nice(pid: u32, nice: i32) -> Result<(), String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, SetPriorityClass, ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS,
+        NORMAL_PRIORITY_CLASS, PROCESS_SET_INFORMATION,
+    };
+
+    let class = match nice {
+        n if n < 0 => ABOVE_NORMAL_PRIORITY_CLASS,
+        0 => NORMAL_PRIORITY_CLASS,
+        _ => BELOW_NORMAL_PRIORITY_CLASS,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_SET_INFORMATION, false, pid)
+            .map_err(|err| t!("process-open-failed", pid = pid, error = format!("{:?}", err)))?;
+        let result = SetPriorityClass(handle, class)
+            .map_err(|err| t!("process-set-class-failed", pid = pid, error = format!("{:?}", err)));
+        let _ = CloseHandle(handle);
+        result
+    }
+}
@@ -0,0 +1,712 @@
+use crate::config::Config;
+use crate::focus::Focus;
+use crate::i18n::t;
+use crate::threads;
+use crate::wine;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+use sysinfo::System;
+
+/// Process chạy/bọc Roblox (leo lên cha qua các process này để tìm gốc của session)
+const LAUNCHER_NAMES: &[&str] = &[
+    "bwrap",
+    "sober",
+    "flatpak-spawn",
+    "wine",
+    "wine64",
+    "wine-preloader",
+    "wine64-preloader",
+    "start.exe",
+];
+
+/// Vai trò của process trong một session Roblox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRole {
+    /// Process khớp tên Roblox (player, Sober)
+    Player,
+    /// Launcher/sandbox bọc player (bwrap, wine loader...)
+    Launcher,
+    /// `wineserver` và service của Wine prefix (`services.exe`, `winedevice.exe`...)
+    Wine,
+    /// Crash handler, process con khác
+    Helper,
+}
+
+impl fmt::Display for MemberRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Player => f.write_str("player"),
+            Self::Launcher => f.write_str("launcher"),
+            Self::Wine => f.write_str("wine"),
+            Self::Helper => f.write_str("helper"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionMember {
+    pub pid: u32,
+    pub name: String,
+    pub role: MemberRole,
+    /// PID trong PID namespace trong cùng (sandbox), `None` nếu không đọc được
+    pub nspid: Option<NamespacedPid>,
+}
+
+/// PID trong một PID namespace: inode của namespace + PID trong namespace đó
+///
+/// Client trong sandbox (Sober/flatpak) có PID riêng trong namespace của nó và hai
+/// sandbox đều có thể có PID 2 → chỉ so PID sẽ nhầm instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NamespacedPid {
+    pub namespace: u64,
+    pub pid: u32,
+}
+
+impl NamespacedPid {
+    /// Process `pid` (PID theo booster) trong PID namespace trong cùng của nó
+    pub fn innermost(pid: u32) -> Option<Self> {
+        let namespace = pid_namespace(pid)?;
+        let nspid = read_namespace_pids(pid).last().copied().unwrap_or(pid);
+        Some(Self { namespace, pid: nspid })
+    }
+
+    /// `pid` hiểu theo PID namespace của booster
+    pub fn local(pid: u32) -> Option<Self> {
+        Some(Self {
+            namespace: pid_namespace(std::process::id())?,
+            pid,
+        })
+    }
+
+    /// PID theo booster của process này (quét `sys` nếu ở namespace khác)
+    pub fn host_pid(self, sys: &System) -> Option<u32> {
+        if Self::local(self.pid) == Some(self) {
+            return Some(self.pid);
+        }
+        sys.processes()
+            .keys()
+            .map(|pid| pid.as_u32())
+            .find(|&pid| Self::innermost(pid) == Some(self))
+    }
+}
+
+/// Một phiên Roblox: cây process từ launcher gốc + wineserver của prefix + process cùng session
+///
+/// Mỗi client Roblox đang chạy là một session riêng, định danh bằng `root`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RobloxSession {
+    /// PID gốc của cây (launcher ngoài cùng hoặc chính player)
+    pub root: u32,
+    /// Thời điểm process gốc khởi động (giây từ epoch)
+    pub started: u64,
+    pub members: Vec<SessionMember>,
+}
+
+impl RobloxSession {
+    pub fn pids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.members.iter().map(|member| member.pid)
+    }
+
+    pub fn contains(&self, pid: u32) -> bool {
+        self.members.iter().any(|member| member.pid == pid)
+    }
+
+    /// `pid` (vd process tạo cửa sổ đang focus) thuộc session này không
+    ///
+    /// So cặp (namespace, PID) với PID trong namespace trong cùng của từng process:
+    /// client trong sandbox (Sober/flatpak) chỉ biết PID trong namespace của nó.
+    pub fn owns_pid(&self, pid: NamespacedPid) -> bool {
+        self.members.iter().any(|member| member.nspid == Some(pid))
+    }
+
+    /// Tên process gốc
+    pub fn root_name(&self) -> &str {
+        self.members
+            .iter()
+            .find(|member| member.pid == self.root)
+            .map_or("?", |member| member.name.as_str())
+    }
+
+    /// Client chạy session: `Sober`, `Wine` hoặc tên process gốc
+    pub fn client(&self) -> &str {
+        if self.members.iter().any(|member| member.name.to_lowercase().contains("sober")) {
+            "Sober"
+        } else if self.members.iter().any(|member| member.role == MemberRole::Wine) {
+            "Wine"
+        } else {
+            self.root_name()
+        }
+    }
+}
+
+fn is_launcher(name: &str) -> bool {
+    let name = name.to_lowercase();
+    LAUNCHER_NAMES.contains(&name.as_str())
+}
+
+/// Process chạy trong Wine: loader `wine*` hoặc `.exe` (comm bị cắt còn 15 ký tự → xem cả cmdline)
+fn is_wine_process(name: &str, cmdline: &[String]) -> bool {
+    let name = name.to_lowercase();
+    name.starts_with("wine")
+        || name.ends_with(".exe")
+        || cmdline
+            .first()
+            .is_some_and(|arg| arg.to_lowercase().ends_with(".exe"))
+}
+
+fn is_crash_handler(name: &str) -> bool {
+    name.to_lowercase().contains("crashhandler")
+}
+
+/// Một process trong snapshot dùng để gom session (xem `group`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ProcessInfo {
+    pid: u32,
+    ppid: Option<u32>,
+    name: String,
+    cmdline: Vec<String>,
+    /// Chỉ cần với process Wine (tìm `WINEPREFIX`), process khác để trống
+    environ: Vec<String>,
+    /// Session ID (`setsid`), chỉ cần với crash handler và process có thể là gốc
+    session_id: Option<u32>,
+    /// Thời điểm khởi động (giây từ epoch)
+    started: u64,
+}
+
+impl ProcessInfo {
+    fn is_wine(&self) -> bool {
+        is_wine_process(&self.name, &self.cmdline)
+    }
+
+    fn prefix(&self) -> Option<PathBuf> {
+        self.is_wine().then(|| wine::environ_prefix(&self.environ)).flatten()
+    }
+}
+
+/// Gom process Roblox thành session theo quan hệ cha/con, session ID và Wine prefix
+pub fn detect(sys: &System, config: &Config) -> Vec<RobloxSession> {
+    let processes: Vec<ProcessInfo> = sys
+        .processes()
+        .iter()
+        // sysinfo liệt kê cả thread (Linux) → chỉ giữ process thật
+        .filter(|(_, process)| process.thread_kind().is_none())
+        .map(|(pid, process)| {
+            let pid = pid.as_u32();
+            let name = process.name();
+            let wine = is_wine_process(name, process.cmd());
+            let root_candidate = config.is_roblox_process(name) || is_launcher(name) || is_crash_handler(name);
+            ProcessInfo {
+                pid,
+                ppid: process.parent().map(|parent| parent.as_u32()),
+                name: name.to_string(),
+                cmdline: process.cmd().to_vec(),
+                environ: if wine { process.environ().to_vec() } else { Vec::new() },
+                session_id: if root_candidate { read_session_id(pid) } else { None },
+                started: process.start_time(),
+            }
+        })
+        .collect();
+
+    let mut sessions = group(&processes, std::process::id(), |name| config.is_roblox_process(name));
+    for member in sessions.iter_mut().flat_map(|session| session.members.iter_mut()) {
+        member.nspid = NamespacedPid::innermost(member.pid);
+    }
+    sessions
+}
+
+/// Gom snapshot `processes` thành session (bỏ qua `own_pid`), sắp theo thứ tự mở; `nspid` để `None`
+///
+/// - Player (trừ crash handler) → gốc là launcher ngoài cùng bọc nó (`LAUNCHER_NAMES`)
+/// - Cả cây con của gốc
+/// - Crash handler tách khỏi cây (double fork) nhưng cùng session ID với gốc
+/// - Process Wine ngoài mọi cây (`wineserver`, `services.exe`...) cùng prefix với process Wine
+///   trong cây; nhiều client chung prefix → thuộc instance mở sớm nhất
+fn group(processes: &[ProcessInfo], own_pid: u32, is_roblox: impl Fn(&str) -> bool) -> Vec<RobloxSession> {
+    // Crash handler cũng có chữ "roblox" nhưng không phải player (và không tự tạo session)
+    let is_player = |name: &str| is_roblox(name) && !is_crash_handler(name);
+    let by_pid: HashMap<u32, &ProcessInfo> = processes
+        .iter()
+        .filter(|process| process.pid != own_pid)
+        .map(|process| (process.pid, process))
+        .collect();
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for process in by_pid.values() {
+        if let Some(parent) = process.ppid {
+            children.entry(parent).or_default().push(process.pid);
+        }
+    }
+
+    // Player → launcher ngoài cùng bọc nó
+    let mut roots = BTreeSet::new();
+    for process in by_pid.values().filter(|process| is_player(&process.name)) {
+        let mut root = process.pid;
+        while let Some(parent) = by_pid[&root].ppid.and_then(|parent| by_pid.get(&parent)) {
+            if !is_launcher(&parent.name) {
+                break;
+            }
+            root = parent.pid;
+        }
+        roots.insert(root);
+    }
+
+    // Cả cây con của từng gốc (gốc nằm trong cây của gốc khác → gộp vào gốc đó)
+    let trees: Vec<(u32, BTreeSet<u32>)> = roots
+        .iter()
+        .map(|&root| {
+            let mut pids = BTreeSet::new();
+            let mut stack = vec![root];
+            while let Some(pid) = stack.pop() {
+                if by_pid.contains_key(&pid) && pids.insert(pid) {
+                    stack.extend(children.get(&pid).into_iter().flatten().copied());
+                }
+            }
+            (root, pids)
+        })
+        .collect();
+    let in_trees: BTreeSet<u32> = trees.iter().flat_map(|(_, pids)| pids.iter().copied()).collect();
+    let mut trees: Vec<(u32, BTreeSet<u32>)> = trees
+        .iter()
+        .filter(|(root, _)| !trees.iter().any(|(other, pids)| other != root && pids.contains(root)))
+        .cloned()
+        .collect();
+    // Instance #1 = client mở sớm nhất
+    trees.sort_by_key(|(root, _)| (by_pid[root].started, *root));
+
+    let mut assigned = BTreeSet::new();
+    let mut sessions = Vec::new();
+    for (root, mut pids) in trees {
+        // Crash handler tách khỏi cây (double fork) nhưng vẫn cùng session ID
+        if let Some(session_id) = by_pid[&root].session_id {
+            pids.extend(
+                by_pid
+                    .values()
+                    .filter(|process| is_crash_handler(&process.name) && process.session_id == Some(session_id))
+                    .map(|process| process.pid),
+            );
+        }
+
+        // wineserver tự daemonize → tìm theo WINEPREFIX của các process trong cây
+        let prefixes: BTreeSet<PathBuf> = pids.iter().filter_map(|pid| by_pid[pid].prefix()).collect();
+        if !prefixes.is_empty() {
+            pids.extend(
+                by_pid
+                    .values()
+                    .filter(|process| !in_trees.contains(&process.pid))
+                    .filter(|process| process.prefix().is_some_and(|prefix| prefixes.contains(&prefix)))
+                    .map(|process| process.pid),
+            );
+        }
+
+        let members: Vec<SessionMember> = pids
+            .into_iter()
+            .filter(|pid| !assigned.contains(pid))
+            .map(|pid| {
+                let process = by_pid[&pid];
+                let name = process.name.clone();
+                let role = if is_player(&name) {
+                    MemberRole::Player
+                } else if is_launcher(&name) {
+                    MemberRole::Launcher
+                } else if process.is_wine() && !is_crash_handler(&name) {
+                    MemberRole::Wine
+                } else {
+                    MemberRole::Helper
+                };
+                SessionMember {
+                    pid,
+                    name,
+                    role,
+                    nspid: None,
+                }
+            })
+            .collect();
+        assigned.extend(members.iter().map(|member| member.pid));
+        let started = by_pid[&root].started;
+        sessions.push(RobloxSession { root, started, members });
+    }
+    sessions
+}
+
+/// Instance chính: session có cửa sổ đang focus; không biết focus → client mở gần nhất
+///
+/// Focus ở cửa sổ khác (không phải Roblox) → `None`, mọi instance đều là instance nền.
+pub fn primary(sessions: &[RobloxSession], focus: Focus) -> Option<u32> {
+    if !focus.is_known() {
+        return sessions.last().map(|session| session.root);
+    }
+    focused_session(focus.pid()?, sessions)
+}
+
+/// PID gốc của session chứa process `pid` (process tạo cửa sổ đang focus)
+///
+/// So cặp (PID namespace, PID): hai sandbox cùng có PID 2 vẫn được phân biệt.
+pub fn focused_session(pid: NamespacedPid, sessions: &[RobloxSession]) -> Option<u32> {
+    sessions
+        .iter()
+        .find(|session| session.owns_pid(pid))
+        .map(|session| session.root)
+}
+
+/// Chia CPU/priority khi chạy nhiều client Roblox cùng lúc (chỉ áp dụng khi có từ 2 instance)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct InstancePolicy {
+    /// CPU cho instance chính (vd `0-7`, `fastest` = core nhanh nhất), không có = `roblox_affinity`
+    pub primary_affinity: Option<String>,
+    /// CPU cho các instance nền, không có = `roblox_affinity`
+    pub background_affinity: Option<String>,
+    /// Nice cho các instance nền (-20..19), không có = boost như instance chính
+    #[schemars(range(min = -20, max = 19))]
+    pub background_nice: Option<i32>,
+}
+
+impl Default for InstancePolicy {
+    fn default() -> Self {
+        Self {
+            primary_affinity: Some(threads::FASTEST_CORES.to_string()),
+            background_affinity: None,
+            background_nice: Some(5),
+        }
+    }
+}
+
+impl InstancePolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        for affinity in [&self.primary_affinity, &self.background_affinity].into_iter().flatten() {
+            if affinity != threads::FASTEST_CORES {
+                crate::process::parse_cpu_list(affinity)?;
+            }
+        }
+        if let Some(nice) = self.background_nice.filter(|nice| !(-20..=19).contains(nice)) {
+            return Err(t!("thread-invalid-nice", nice = nice));
+        }
+        Ok(())
+    }
+}
+
+/// Chu kỳ `cpu.max` (µs), quota tính theo chu kỳ này
+const CPU_MAX_PERIOD_US: u64 = 100_000;
+
+/// Chế độ tiết kiệm pin: Roblox không được focus hoặc người dùng AFK → giới hạn CPU
+/// bằng `cpu.max` và hạ priority, focus lại/thao tác lại → khôi phục ngay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PowerSavingPolicy {
+    pub enabled: bool,
+    /// Instance không được focus (cả cửa sổ bị thu nhỏ) → tiết kiệm pin, cần `follow_focus`
+    pub when_unfocused: bool,
+    /// Không dùng chuột/bàn phím trong N phút (X11) → tiết kiệm pin mọi instance, 0 = tắt
+    pub idle_minutes: u64,
+    /// Giới hạn CPU theo % một core (vd 50 = nửa core, 200 = 2 core), cần cgroup được delegate
+    #[schemars(range(min = 1))]
+    pub cpu_limit_percent: u32,
+    /// Nice khi tiết kiệm pin (-20..19)
+    #[schemars(range(min = -20, max = 19))]
+    pub nice: i32,
+}
+
+impl Default for PowerSavingPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            when_unfocused: true,
+            idle_minutes: 10,
+            cpu_limit_percent: 50,
+            nice: 15,
+        }
+    }
+}
+
+impl PowerSavingPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.cpu_limit_percent == 0 {
+            return Err(t!("power-invalid-cpu-limit", value = self.cpu_limit_percent));
+        }
+        if !(-20..=19).contains(&self.nice) {
+            return Err(t!("thread-invalid-nice", nice = self.nice));
+        }
+        Ok(())
+    }
+
+    /// Người dùng đã AFK đủ `idle_minutes` chưa (`idle` = `None` → không biết → chưa)
+    pub fn is_idle(&self, idle: Option<Duration>) -> bool {
+        self.enabled
+            && self.idle_minutes > 0
+            && idle.is_some_and(|idle| idle >= Duration::from_secs(self.idle_minutes * 60))
+    }
+
+    /// Giá trị ghi vào `cpu.max`: `<quota> <period>`
+    pub fn cpu_max(&self) -> String {
+        let quota = u64::from(self.cpu_limit_percent) * CPU_MAX_PERIOD_US / 100;
+        format!("{} {}", quota, CPU_MAX_PERIOD_US)
+    }
+}
+
+/// PID của process trong các PID namespace lồng nhau (dòng `NSpid`, bỏ PID ở namespace gốc)
+#[cfg(target_os = "linux")]
+fn read_namespace_pids(pid: u32) -> Vec<u32> {
+    let Ok(status) = std::fs::read_to_string(format!("/proc/{}/status", pid)) else {
+        return Vec::new();
+    };
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("NSpid:"))
+        .map(|pids| pids.split_whitespace().skip(1).filter_map(|pid| pid.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_namespace_pids(_pid: u32) -> Vec<u32> {
+    Vec::new()
+}
+
+/// Inode của PID namespace (`/proc/<pid>/ns/pid`)
+#[cfg(target_os = "linux")]
+fn pid_namespace(pid: u32) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::metadata(format!("/proc/{}/ns/pid", pid))
+        .ok()
+        .map(|metadata| metadata.ino())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pid_namespace(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// Session ID (`setsid`) của process
+#[cfg(target_os = "linux")]
+fn read_session_id(pid: u32) -> Option<u32> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // Sau ')': state ppid pgrp session
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    fields.get(3)?.parse().ok()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_session_id(_pid: u32) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOST: u64 = 4026531836;
+    const SANDBOX_A: u64 = 4026532001;
+    const SANDBOX_B: u64 = 4026532002;
+
+    fn member(pid: u32, name: &str, role: MemberRole, nspid: Option<NamespacedPid>) -> SessionMember {
+        SessionMember {
+            pid,
+            name: name.to_string(),
+            role,
+            nspid,
+        }
+    }
+
+    fn ns(namespace: u64, pid: u32) -> NamespacedPid {
+        NamespacedPid { namespace, pid }
+    }
+
+    /// Hai client Sober (mỗi client một sandbox, player đều là PID 2 trong sandbox) + một client Wine
+    fn sessions() -> Vec<RobloxSession> {
+        vec![
+            RobloxSession {
+                root: 1000,
+                started: 1,
+                members: vec![
+                    member(1000, "bwrap", MemberRole::Launcher, Some(ns(HOST, 1000))),
+                    member(1002, "sober", MemberRole::Player, Some(ns(SANDBOX_A, 2))),
+                ],
+            },
+            RobloxSession {
+                root: 2000,
+                started: 2,
+                members: vec![
+                    member(2000, "bwrap", MemberRole::Launcher, Some(ns(HOST, 2000))),
+                    member(2002, "sober", MemberRole::Player, Some(ns(SANDBOX_B, 2))),
+                ],
+            },
+            RobloxSession {
+                root: 3000,
+                started: 3,
+                members: vec![
+                    member(3000, "RobloxPlayerBet", MemberRole::Player, Some(ns(HOST, 3000))),
+                    member(3001, "wineserver", MemberRole::Wine, None),
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn same_pid_in_different_sandboxes_maps_to_its_own_session() {
+        let sessions = sessions();
+        assert_eq!(focused_session(ns(SANDBOX_A, 2), &sessions), Some(1000));
+        assert_eq!(focused_session(ns(SANDBOX_B, 2), &sessions), Some(2000));
+    }
+
+    #[test]
+    fn host_pids_match_only_in_the_host_namespace() {
+        let sessions = sessions();
+        assert_eq!(focused_session(ns(HOST, 3000), &sessions), Some(3000));
+        assert_eq!(focused_session(ns(HOST, 2000), &sessions), Some(2000));
+        // PID 2 của host (kthreadd) không phải player trong sandbox
+        assert_eq!(focused_session(ns(HOST, 2), &sessions), None);
+        // PID theo host của player nhưng namespace của sandbox
+        assert_eq!(focused_session(ns(SANDBOX_A, 1002), &sessions), None);
+    }
+
+    #[test]
+    fn unknown_pids_and_members_without_namespace_do_not_match() {
+        let sessions = sessions();
+        assert_eq!(focused_session(ns(HOST, 3001), &sessions), None);
+        assert_eq!(focused_session(ns(SANDBOX_A, 3), &sessions), None);
+        assert_eq!(focused_session(ns(SANDBOX_A, 2), &[]), None);
+    }
+
+    #[test]
+    fn primary_follows_focus() {
+        let sessions = sessions();
+        let window = |pid| Focus::Window { window: 0x400001, pid };
+        assert_eq!(primary(&sessions, Focus::Unknown), Some(3000));
+        assert_eq!(primary(&sessions, window(Some(ns(SANDBOX_B, 2)))), Some(2000));
+        assert_eq!(primary(&sessions, window(Some(ns(HOST, 42)))), None);
+        assert_eq!(primary(&sessions, window(None)), None);
+    }
+
+    const OWN_PID: u32 = 10;
+
+    fn process(pid: u32, ppid: u32, name: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            ppid: Some(ppid),
+            name: name.to_string(),
+            started: u64::from(pid),
+            ..ProcessInfo::default()
+        }
+    }
+
+    fn in_session(mut process: ProcessInfo, session_id: u32) -> ProcessInfo {
+        process.session_id = Some(session_id);
+        process
+    }
+
+    fn in_prefix(mut process: ProcessInfo, prefix: &str) -> ProcessInfo {
+        process.environ = vec![format!("WINEPREFIX={}", prefix), "HOME=/home/player".to_string()];
+        process
+    }
+
+    fn group_roblox(processes: &[ProcessInfo]) -> Vec<RobloxSession> {
+        group(processes, OWN_PID, |name| name.to_lowercase().contains("roblox") || name == "sober")
+    }
+
+    fn members(session: &RobloxSession) -> Vec<(u32, MemberRole)> {
+        session.members.iter().map(|member| (member.pid, member.role)).collect()
+    }
+
+    #[test]
+    fn outermost_launcher_is_the_root() {
+        let sessions = group_roblox(&[
+            process(1, 0, "systemd"),
+            process(50, 1, "bash"),
+            process(100, 50, "bwrap"),
+            process(101, 100, "bwrap"),
+            process(102, 101, "sober"),
+            process(103, 102, "sober"),
+            process(104, 102, "crashpad"),
+            // Booster không thuộc session dù là cha của client
+            process(OWN_PID, 1, "roblox_booster"),
+            process(200, OWN_PID, "RobloxPlayer"),
+        ]);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].root, 100);
+        assert_eq!(
+            members(&sessions[0]),
+            vec![
+                (100, MemberRole::Launcher),
+                (101, MemberRole::Launcher),
+                (102, MemberRole::Player),
+                (103, MemberRole::Player),
+                (104, MemberRole::Helper),
+            ]
+        );
+        assert_eq!(sessions[1].root, 200);
+        assert_eq!(members(&sessions[1]), vec![(200, MemberRole::Player)]);
+        assert!(sessions.iter().flat_map(|session| &session.members).all(|member| member.nspid.is_none()));
+    }
+
+    #[test]
+    fn crash_handler_attached_by_session_id() {
+        let sessions = group_roblox(&[
+            in_session(process(100, 1, "RobloxPlayer"), 100),
+            in_session(process(150, 1, "RobloxCrashHandler"), 100),
+            // Crash handler của session khác mà Roblox đã thoát → không thuộc session nào
+            in_session(process(160, 1, "RobloxCrashHandler"), 90),
+            process(170, 1, "RobloxCrashHandler"),
+        ]);
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(members(&sessions[0]), vec![(100, MemberRole::Player), (150, MemberRole::Helper)]);
+    }
+
+    #[test]
+    fn orphaned_crash_handler_is_not_a_session() {
+        let sessions = group_roblox(&[
+            process(1, 0, "systemd"),
+            in_session(process(150, 1, "RobloxCrashHandler"), 100),
+        ]);
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn wineserver_attached_by_prefix() {
+        let sessions = group_roblox(&[
+            in_prefix(process(100, 1, "wine64-preloader"), "/games/roblox"),
+            in_prefix(process(101, 100, "RobloxPlayerBeta.exe"), "/games/roblox"),
+            in_prefix(process(150, 1, "wineserver"), "/games/roblox"),
+            in_prefix(process(151, 1, "services.exe"), "/games/roblox"),
+            // Prefix khác → không thuộc session
+            in_prefix(process(160, 1, "wineserver"), "/games/other"),
+            // Không phải process Wine dù có WINEPREFIX
+            in_prefix(process(170, 1, "bash"), "/games/roblox"),
+        ]);
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].root, 100);
+        assert_eq!(
+            members(&sessions[0]),
+            vec![
+                (100, MemberRole::Launcher),
+                (101, MemberRole::Player),
+                (150, MemberRole::Wine),
+                (151, MemberRole::Wine),
+            ]
+        );
+        assert_eq!(sessions[0].client(), "Wine");
+    }
+
+    #[test]
+    fn wineserver_shared_by_two_clients_goes_to_the_first_instance() {
+        let mut first = in_prefix(process(300, 1, "wine64-preloader"), "/games/roblox");
+        first.started = 1;
+        let sessions = group_roblox(&[
+            in_prefix(process(150, 1, "wineserver"), "/games/roblox"),
+            in_prefix(process(200, 1, "RobloxPlayerBeta.exe"), "/games/roblox"),
+            first,
+            in_prefix(process(301, 300, "RobloxPlayerBeta.exe"), "/games/roblox"),
+        ]);
+        // Hai client cùng prefix vẫn là hai instance, wineserver chỉ thuộc một
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].root, 300);
+        assert_eq!(
+            members(&sessions[0]),
+            vec![(150, MemberRole::Wine), (300, MemberRole::Launcher), (301, MemberRole::Player)]
+        );
+        assert_eq!(sessions[1].root, 200);
+        assert_eq!(members(&sessions[1]), vec![(200, MemberRole::Player)]);
+    }
+}
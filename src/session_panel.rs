@@ -0,0 +1,114 @@
+use crate::i18n::t;
+use crate::session::SessionMember;
+use iced::widget::{button, column, row, scrollable, text, Column};
+use iced::{Alignment, Element, Length};
+
+/// Một session Roblox đang chạy, cho trang Phiên chơi (xem `RobloxBoosterEngine::session_snapshot`)
+#[derive(Debug, Clone)]
+pub struct SessionEntry {
+    /// Số thứ tự instance, từ 1 theo thứ tự mở
+    pub instance: usize,
+    /// PID gốc của session
+    pub root: u32,
+    /// Sober, Wine hoặc tên process gốc
+    pub client: String,
+    /// Instance chính (đang focus, hoặc mở gần nhất nếu không biết focus)
+    pub primary: bool,
+    pub members: Vec<SessionMember>,
+    /// Trạng thái của từng optimization đang tác động lên session
+    pub boost: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SessionPanelMessage {
+    Refresh,
+    Loaded(Vec<SessionEntry>),
+}
+
+/// State của trang Phiên chơi
+#[derive(Default)]
+pub struct SessionPanel {
+    entries: Vec<SessionEntry>,
+    /// Đang đọc snapshot → bỏ qua các lần refresh tự động tới khi xong
+    loading: bool,
+}
+
+impl SessionPanel {
+    /// `false` nếu đang đọc dở (trừ khi `force`)
+    pub fn begin_load(&mut self, force: bool) -> bool {
+        if self.loading && !force {
+            return false;
+        }
+        self.loading = true;
+        true
+    }
+
+    pub fn set_entries(&mut self, entries: Vec<SessionEntry>) {
+        self.entries = entries;
+        self.loading = false;
+    }
+
+    pub fn view(&self) -> Element<'_, SessionPanelMessage> {
+        let toolbar = row![
+            text(t!("sessions-count", count = self.entries.len()))
+                .size(12)
+                .width(Length::Fill),
+            button(text("🔄").size(14))
+                .padding(8)
+                .on_press(SessionPanelMessage::Refresh),
+        ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+
+        if self.entries.is_empty() {
+            return column![toolbar, text(t!("sessions-empty")).size(13)]
+                .spacing(8)
+                .into();
+        }
+
+        let rows = self
+            .entries
+            .iter()
+            .fold(Column::new().spacing(12), |rows, entry| rows.push(Self::entry_view(entry)));
+
+        column![toolbar, scrollable(rows).height(Length::Fill)]
+            .spacing(8)
+            .into()
+    }
+
+    fn entry_view(entry: &SessionEntry) -> Element<'_, SessionPanelMessage> {
+        let mut title = t!(
+            "sessions-title",
+            instance = entry.instance,
+            client = entry.client.as_str(),
+            pid = entry.root
+        );
+        if entry.primary {
+            title = format!("{}  {}", title, t!("sessions-primary"));
+        }
+
+        let members = entry
+            .members
+            .iter()
+            .map(|member| match member.nspid.filter(|nspid| nspid.pid != member.pid) {
+                Some(nspid) => format!("{} ({}, PID {} / {})", member.name, member.role, member.pid, nspid.pid),
+                None => format!("{} ({}, PID {})", member.name, member.role, member.pid),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let boost = if entry.boost.is_empty() {
+            t!("sessions-not-boosted")
+        } else {
+            entry.boost.join("  |  ")
+        };
+
+        column![
+            text(title).size(14),
+            text(t!("sessions-members", count = entry.members.len(), members = members)).size(11),
+            text(boost).size(12),
+        ]
+        .spacing(2)
+        .into()
+    }
+}
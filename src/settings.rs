@@ -0,0 +1,672 @@
+use crate::capabilities;
+use crate::config::{Config, Language, MAX_BOOST_INTERVAL_SECONDS};
+use crate::i18n::t;
+use crate::optimizations::{Availability, Optimization};
+use crate::threads::{ThreadClass, ThreadPolicy, FASTEST_CORES};
+use iced::widget::{button, checkbox, column, pick_list, row, scrollable, text, text_input, Column};
+use iced::{Alignment, Color, Element, Length};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const ERROR_COLOR: Color = Color::from_rgb(1.0, 0.4, 0.4);
+
+/// Nhóm field trong settings (mỗi nhóm có nút reset về mặc định riêng)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsSection {
+    General,
+    Features,
+    Matchers,
+    /// Cả cây process của session Roblox
+    Session,
+    Threads,
+    PowerSaving,
+}
+
+/// Field nhập dạng text, giữ nguyên chuỗi user gõ và chỉ parse khi lấy kết quả
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextField {
+    RobloxAffinity,
+    PrimaryAffinity,
+    BackgroundAffinity,
+    BackgroundNice,
+    PowerIdleMinutes,
+    PowerCpuLimit,
+    PowerNice,
+    ThreadNice(ThreadClass),
+    ThreadAffinity(ThreadClass),
+    ThreadTimerSlack(ThreadClass),
+}
+
+impl TextField {
+    /// Giá trị hiện tại trong config dạng text (không có → rỗng)
+    fn read(self, config: &Config) -> String {
+        match self {
+            Self::RobloxAffinity => config.roblox_affinity.clone().unwrap_or_default(),
+            Self::PrimaryAffinity => config.multi_instance.primary_affinity.clone().unwrap_or_default(),
+            Self::BackgroundAffinity => config
+                .multi_instance
+                .background_affinity
+                .clone()
+                .unwrap_or_default(),
+            Self::BackgroundNice => config
+                .multi_instance
+                .background_nice
+                .map(|nice| nice.to_string())
+                .unwrap_or_default(),
+            Self::PowerIdleMinutes => config.power_saving.idle_minutes.to_string(),
+            Self::PowerCpuLimit => config.power_saving.cpu_limit_percent.to_string(),
+            Self::PowerNice => config.power_saving.nice.to_string(),
+            Self::ThreadNice(class) => thread_policy(config, class)
+                .and_then(|policy| policy.nice)
+                .map(|nice| nice.to_string())
+                .unwrap_or_default(),
+            Self::ThreadAffinity(class) => thread_policy(config, class)
+                .and_then(|policy| policy.affinity.clone())
+                .unwrap_or_default(),
+            Self::ThreadTimerSlack(class) => thread_policy(config, class)
+                .and_then(|policy| policy.timer_slack_ns)
+                .map(|slack| slack.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Parse `input` và ghi vào config (rỗng → không có)
+    fn apply(self, input: &str, config: &mut Config) -> Result<(), String> {
+        match self {
+            Self::RobloxAffinity => config.roblox_affinity = parse_cpu_list_input(input)?,
+            Self::PrimaryAffinity => config.multi_instance.primary_affinity = parse_affinity_input(input)?,
+            Self::BackgroundAffinity => {
+                config.multi_instance.background_affinity = parse_affinity_input(input)?
+            }
+            Self::BackgroundNice => config.multi_instance.background_nice = parse_nice_input(input)?,
+            Self::PowerIdleMinutes => config.power_saving.idle_minutes = required(parse_number_input(input)?)?,
+            Self::PowerCpuLimit => {
+                let percent = required(parse_number_input(input)?)?;
+                config.power_saving.cpu_limit_percent =
+                    u32::try_from(percent).map_err(|_| t!("settings-number-invalid", value = input.trim()))?;
+            }
+            Self::PowerNice => config.power_saving.nice = required(parse_nice_input(input)?)?,
+            Self::ThreadNice(class) => {
+                let nice = parse_nice_input(input)?;
+                update_thread_policy(config, class, |policy| policy.nice = nice);
+            }
+            Self::ThreadAffinity(class) => {
+                let affinity = parse_affinity_input(input)?;
+                update_thread_policy(config, class, |policy| policy.affinity = affinity);
+            }
+            Self::ThreadTimerSlack(class) => {
+                let slack = parse_number_input(input)?;
+                update_thread_policy(config, class, |policy| policy.timer_slack_ns = slack);
+            }
+        }
+        Ok(())
+    }
+
+    fn section(self) -> SettingsSection {
+        match self {
+            Self::RobloxAffinity
+            | Self::PrimaryAffinity
+            | Self::BackgroundAffinity
+            | Self::BackgroundNice => SettingsSection::Session,
+            Self::PowerIdleMinutes | Self::PowerCpuLimit | Self::PowerNice => SettingsSection::PowerSaving,
+            Self::ThreadNice(_) | Self::ThreadAffinity(_) | Self::ThreadTimerSlack(_) => {
+                SettingsSection::Threads
+            }
+        }
+    }
+}
+
+/// List tên process có thể sửa trong settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatcherList {
+    Roblox,
+    Pause,
+}
+
+#[derive(Debug, Clone)]
+pub enum SettingsMessage {
+    AutoStart(bool),
+    DryRun(bool),
+    FollowFocus(bool),
+    PowerSaving(bool),
+    PowerWhenUnfocused(bool),
+    Language(Language),
+    IntervalChanged(String),
+    TextChanged(TextField, String),
+    /// Bật/tắt optimization theo id trong registry
+    Optimization(&'static str, bool),
+    MatcherInputChanged(MatcherList, String),
+    AddMatcher(MatcherList),
+    RemoveMatcher(MatcherList, usize),
+    ResetSection(SettingsSection),
+    /// Cài/gỡ autostart khi đăng nhập (UI xử lý, áp dụng ngay)
+    LoginAutostart(bool),
+    Save,
+    Cancel,
+}
+
+/// Bản nháp config đang sửa; chỉ áp dụng cho engine khi `Save`
+///
+/// Field dạng số được giữ nguyên chuỗi user gõ để báo lỗi thay vì âm thầm sửa giá trị.
+pub struct SettingsEditor {
+    original: Config,
+    draft: Config,
+    interval_input: String,
+    inputs: BTreeMap<TextField, String>,
+    roblox_input: String,
+    pause_input: String,
+    /// Trạng thái autostart khi đăng nhập, `None` = không hỗ trợ
+    login_autostart: Option<bool>,
+}
+
+impl SettingsEditor {
+    pub fn new(config: Config) -> Self {
+        Self {
+            interval_input: config.boost_interval_seconds.to_string(),
+            inputs: text_inputs(&config),
+            original: config.clone(),
+            draft: config,
+            roblox_input: String::new(),
+            pause_input: String::new(),
+            login_autostart: None,
+        }
+    }
+
+    /// Config lúc mở editor, dùng để biết field nào đã sửa khi lưu
+    pub fn original(&self) -> &Config {
+        &self.original
+    }
+
+    pub fn set_login_autostart(&mut self, installed: Option<bool>) {
+        self.login_autostart = installed;
+    }
+
+    /// Config sau khi sửa, hoặc lỗi validate đầu tiên
+    pub fn result(&self) -> Result<Config, String> {
+        let mut config = self.draft.clone();
+        config.boost_interval_seconds = parse_interval(&self.interval_input)?;
+        for (field, input) in &self.inputs {
+            field.apply(input, &mut config)?;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.result()
+            .map_or(true, |config| !self.original.diff(&config).is_empty())
+    }
+
+    /// Xử lý các message chỉ sửa bản nháp (`Save`/`Cancel`/`LoginAutostart` do UI xử lý)
+    pub fn update(&mut self, message: SettingsMessage) {
+        match message {
+            SettingsMessage::AutoStart(value) => self.draft.auto_start = value,
+            SettingsMessage::DryRun(value) => self.draft.dry_run = value,
+            SettingsMessage::FollowFocus(value) => self.draft.follow_focus = value,
+            SettingsMessage::PowerSaving(value) => self.draft.power_saving.enabled = value,
+            SettingsMessage::PowerWhenUnfocused(value) => self.draft.power_saving.when_unfocused = value,
+            SettingsMessage::Language(language) => self.draft.language = language,
+            SettingsMessage::IntervalChanged(value) => self.interval_input = value,
+            SettingsMessage::TextChanged(field, value) => {
+                self.inputs.insert(field, value);
+            }
+            SettingsMessage::Optimization(id, value) => self.draft.set_enabled(id, value),
+            SettingsMessage::MatcherInputChanged(list, value) => *self.input_mut(list) = value,
+            SettingsMessage::AddMatcher(list) => {
+                if let Ok(name) = self.validate_matcher(list) {
+                    self.list_mut(list).push(name);
+                    self.input_mut(list).clear();
+                }
+            }
+            SettingsMessage::RemoveMatcher(list, index) => {
+                let entries = self.list_mut(list);
+                if index < entries.len() {
+                    entries.remove(index);
+                }
+            }
+            SettingsMessage::ResetSection(section) => self.reset(section),
+            SettingsMessage::LoginAutostart(_) | SettingsMessage::Save | SettingsMessage::Cancel => {}
+        }
+    }
+
+    fn reset(&mut self, section: SettingsSection) {
+        let defaults = Config::default();
+        for (field, input) in text_inputs(&defaults) {
+            if field.section() == section {
+                self.inputs.insert(field, input);
+            }
+        }
+        match section {
+            SettingsSection::General => {
+                self.draft.auto_start = defaults.auto_start;
+                self.draft.language = defaults.language;
+                self.draft.dry_run = defaults.dry_run;
+            }
+            SettingsSection::Features => {
+                self.interval_input = defaults.boost_interval_seconds.to_string();
+                self.draft.optimizations = defaults.optimizations;
+            }
+            SettingsSection::Matchers => {
+                self.draft.roblox_process_names = defaults.roblox_process_names;
+                self.draft.pause_process_names = defaults.pause_process_names;
+                self.roblox_input.clear();
+                self.pause_input.clear();
+            }
+            SettingsSection::Session => {
+                self.draft.roblox_affinity = defaults.roblox_affinity;
+                self.draft.multi_instance = defaults.multi_instance;
+                self.draft.follow_focus = defaults.follow_focus;
+            }
+            SettingsSection::Threads => {
+                self.draft.thread_policies = defaults.thread_policies;
+            }
+            SettingsSection::PowerSaving => {
+                self.draft.power_saving = defaults.power_saving;
+            }
+        }
+    }
+
+    fn list(&self, list: MatcherList) -> &Vec<String> {
+        match list {
+            MatcherList::Roblox => &self.draft.roblox_process_names,
+            MatcherList::Pause => &self.draft.pause_process_names,
+        }
+    }
+
+    fn list_mut(&mut self, list: MatcherList) -> &mut Vec<String> {
+        match list {
+            MatcherList::Roblox => &mut self.draft.roblox_process_names,
+            MatcherList::Pause => &mut self.draft.pause_process_names,
+        }
+    }
+
+    fn input(&self, list: MatcherList) -> &String {
+        match list {
+            MatcherList::Roblox => &self.roblox_input,
+            MatcherList::Pause => &self.pause_input,
+        }
+    }
+
+    fn input_mut(&mut self, list: MatcherList) -> &mut String {
+        match list {
+            MatcherList::Roblox => &mut self.roblox_input,
+            MatcherList::Pause => &mut self.pause_input,
+        }
+    }
+
+    /// Tên process mới: không rỗng, không trùng (không phân biệt hoa thường)
+    fn validate_matcher(&self, list: MatcherList) -> Result<String, String> {
+        let name = self.input(list).trim();
+        if name.is_empty() {
+            return Err(t!("settings-matcher-empty"));
+        }
+        if self.list(list).iter().any(|entry| entry.eq_ignore_ascii_case(name)) {
+            return Err(t!("settings-matcher-duplicate", name = name));
+        }
+        Ok(name.to_string())
+    }
+
+    /// `optimizations`: registry kèm availability đã probe sẵn (cache trong app state)
+    pub fn view<'a>(
+        &'a self,
+        optimizations: &'a [(Arc<dyn Optimization>, Availability)],
+    ) -> Element<'a, SettingsMessage> {
+        let result = self.result();
+
+        let mut general = column![
+            section_header(t!("settings-general"), SettingsSection::General),
+            checkbox(t!("settings-auto-start"), self.draft.auto_start)
+                .on_toggle(SettingsMessage::AutoStart),
+        ]
+        .spacing(8);
+        if let Some(installed) = self.login_autostart {
+            general = general.push(
+                checkbox(t!("settings-login-autostart"), installed)
+                    .on_toggle(SettingsMessage::LoginAutostart),
+            );
+        }
+        general = general.push(
+            checkbox(t!("settings-dry-run"), self.draft.dry_run).on_toggle(SettingsMessage::DryRun),
+        );
+        general = general.push(
+            row![
+                text(t!("settings-language")).size(14),
+                pick_list(
+                    &Language::ALL[..],
+                    Some(self.draft.language),
+                    SettingsMessage::Language
+                ),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        );
+
+        let mut features = column![
+            section_header(t!("settings-features"), SettingsSection::Features),
+            row![
+                text(t!("settings-interval")).size(14),
+                text_input("60", &self.interval_input)
+                    .on_input(SettingsMessage::IntervalChanged)
+                    .width(Length::Fixed(90.0))
+                    .padding(6),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center),
+        ]
+        .spacing(8);
+        if let Err(err) = parse_interval(&self.interval_input) {
+            features = features.push(text(err).size(11).style(ERROR_COLOR));
+        }
+        for (optimization, availability) in optimizations {
+            let id = optimization.id();
+            features = features.push(
+                checkbox(feature_label(optimization.as_ref()), self.draft.is_enabled(id))
+                    .on_toggle(move |value| SettingsMessage::Optimization(id, value)),
+            );
+            if let Availability::Unavailable(reason) = availability {
+                features = features.push(text(format!("    ⚠️ {}", reason)).size(11).style(ERROR_COLOR));
+            }
+        }
+
+        // Profile (kèm tuning Wine) chỉ sửa trong config.json, settings chỉ liệt kê
+        let profile_names: Vec<&str> = self.draft.profiles.iter().map(|profile| profile.name.as_str()).collect();
+        features = features.push(
+            text(t!(
+                "settings-profiles-file-only",
+                count = profile_names.len(),
+                names = profile_names.join(", ")
+            ))
+            .size(12),
+        );
+
+        let matchers = column![
+            section_header(t!("settings-matchers"), SettingsSection::Matchers),
+            self.matcher_view(MatcherList::Roblox, t!("settings-roblox-names")),
+            self.matcher_view(MatcherList::Pause, t!("settings-pause-names")),
+        ]
+        .spacing(8);
+
+        let session = column![
+            section_header(t!("settings-session"), SettingsSection::Session),
+            self.text_input_view(TextField::RobloxAffinity, t!("settings-roblox-affinity"), "0-7"),
+            checkbox(t!("settings-follow-focus"), self.draft.follow_focus)
+                .on_toggle(SettingsMessage::FollowFocus),
+            text(t!("settings-multi-instance")).size(14),
+            self.text_input_view(TextField::PrimaryAffinity, t!("settings-primary-affinity"), FASTEST_CORES),
+            self.text_input_view(TextField::BackgroundAffinity, t!("settings-background-affinity"), "8-15"),
+            self.text_input_view(TextField::BackgroundNice, t!("settings-background-nice"), "5"),
+        ]
+        .spacing(8);
+
+        let threads = ThreadClass::ALL.iter().fold(
+            column![section_header(t!("settings-threads"), SettingsSection::Threads)].spacing(8),
+            |threads, class| threads.push(self.thread_policy_view(*class)),
+        );
+
+        let power_saving = column![
+            section_header(t!("settings-power-saving"), SettingsSection::PowerSaving),
+            checkbox(t!("settings-power-enabled"), self.draft.power_saving.enabled)
+                .on_toggle(SettingsMessage::PowerSaving),
+            checkbox(t!("settings-power-when-unfocused"), self.draft.power_saving.when_unfocused)
+                .on_toggle(SettingsMessage::PowerWhenUnfocused),
+            self.text_input_view(TextField::PowerIdleMinutes, t!("settings-power-idle-minutes"), "10"),
+            self.text_input_view(TextField::PowerCpuLimit, t!("settings-power-cpu-limit"), "50"),
+            self.text_input_view(TextField::PowerNice, t!("settings-power-nice"), "15"),
+        ]
+        .spacing(8);
+
+        let environment = capabilities::report().iter().fold(
+            column![text(t!("settings-environment")).size(18)].spacing(4),
+            |environment, (capability, result)| {
+                let line = text(capabilities::describe(*capability, result)).size(12);
+                environment.push(if result.is_ok() { line } else { line.style(ERROR_COLOR) })
+            },
+        );
+
+        let status = match &result {
+            Err(err) => text(err).size(12).style(ERROR_COLOR),
+            Ok(_) if self.is_dirty() => text(t!("settings-unsaved")).size(12),
+            Ok(_) => text("").size(12),
+        };
+
+        let buttons = row![
+            button(text(t!("settings-button-save")).size(16))
+                .padding(15)
+                .on_press_maybe(result.is_ok().then_some(SettingsMessage::Save)),
+            button(text(t!("settings-button-cancel")).size(16))
+                .padding(15)
+                .on_press(SettingsMessage::Cancel),
+        ]
+        .spacing(10);
+
+        let content = column![
+            text(t!("settings-title")).size(26),
+            general,
+            features,
+            matchers,
+            session,
+            threads,
+            power_saving,
+            environment,
+            text(t!("settings-info-no-admin")).size(11),
+            text(t!("settings-info-system-wide")).size(11),
+            status,
+            buttons,
+        ]
+        .spacing(16);
+
+        scrollable(content).height(Length::Fill).into()
+    }
+
+    /// Ô nhập text kèm lỗi parse ngay dưới (lỗi validate chung hiện ở cuối trang)
+    fn text_input_view(&self, field: TextField, label: String, placeholder: &str) -> Element<'_, SettingsMessage> {
+        let input = self.inputs.get(&field).map_or("", String::as_str);
+        let mut content = column![row![
+            text(label).size(14),
+            text_input(placeholder, input)
+                .on_input(move |value| SettingsMessage::TextChanged(field, value))
+                .width(Length::Fixed(140.0))
+                .padding(6),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center)]
+        .spacing(4);
+        if let Err(err) = field.apply(input, &mut self.draft.clone()) {
+            content = content.push(text(err).size(11).style(ERROR_COLOR));
+        }
+        content.into()
+    }
+
+    /// Một dòng nice / CPU / timer slack cho nhóm thread
+    fn thread_policy_view(&self, class: ThreadClass) -> Element<'_, SettingsMessage> {
+        let fields = [
+            (TextField::ThreadNice(class), "nice", "-10"),
+            (TextField::ThreadAffinity(class), "CPU", FASTEST_CORES),
+            (TextField::ThreadTimerSlack(class), "slack (ns)", "1"),
+        ];
+        let inputs = fields.iter().fold(
+            row![text(class.to_string()).size(14).width(Length::Fixed(80.0))]
+                .spacing(8)
+                .align_items(Alignment::Center),
+            |inputs, (field, label, placeholder)| {
+                let field = *field;
+                let input = self.inputs.get(&field).map_or("", String::as_str);
+                inputs.push(text(*label).size(12)).push(
+                    text_input(placeholder, input)
+                        .on_input(move |value| SettingsMessage::TextChanged(field, value))
+                        .width(Length::Fixed(80.0))
+                        .padding(6),
+                )
+            },
+        );
+
+        let mut content = column![inputs].spacing(4);
+        for (field, _, _) in fields {
+            let input = self.inputs.get(&field).map_or("", String::as_str);
+            if let Err(err) = field.apply(input, &mut self.draft.clone()) {
+                content = content.push(text(err).size(11).style(ERROR_COLOR));
+            }
+        }
+        content.into()
+    }
+
+    fn matcher_view(&self, list: MatcherList, title: String) -> Element<'_, SettingsMessage> {
+        let entries = self.list(list).iter().enumerate().fold(
+            Column::new().spacing(4),
+            |entries, (index, name)| {
+                entries.push(
+                    row![
+                        text(name).size(13).width(Length::Fill),
+                        button(text("✕").size(11))
+                            .padding(4)
+                            .on_press(SettingsMessage::RemoveMatcher(list, index)),
+                    ]
+                    .spacing(8)
+                    .align_items(Alignment::Center),
+                )
+            },
+        );
+
+        let input = self.input(list);
+        let valid = self.validate_matcher(list);
+        let mut content = column![
+            text(title).size(14),
+            entries,
+            row![
+                text_input(&t!("settings-matcher-placeholder"), input)
+                    .on_input(move |value| SettingsMessage::MatcherInputChanged(list, value))
+                    .on_submit(SettingsMessage::AddMatcher(list))
+                    .padding(6),
+                button(text(t!("settings-matcher-add")).size(12))
+                    .padding(6)
+                    .on_press_maybe(valid.is_ok().then_some(SettingsMessage::AddMatcher(list))),
+            ]
+            .spacing(8)
+            .align_items(Alignment::Center),
+        ]
+        .spacing(6);
+
+        // Input rỗng không phải lỗi, chỉ báo trùng tên
+        if let (Err(err), false) = (valid, input.trim().is_empty()) {
+            content = content.push(text(err).size(11).style(ERROR_COLOR));
+        }
+        content.into()
+    }
+}
+
+fn section_header<'a>(title: String, section: SettingsSection) -> Element<'a, SettingsMessage> {
+    row![
+        text(title).size(18).width(Length::Fill),
+        button(text(t!("settings-reset-section")).size(11))
+            .padding(4)
+            .on_press(SettingsMessage::ResetSection(section)),
+    ]
+    .align_items(Alignment::Center)
+    .into()
+}
+
+/// Label 2 dòng cho checkbox optimization: tên + mô tả
+fn feature_label(optimization: &dyn Optimization) -> String {
+    format!("{}\n    → {}", optimization.name(), optimization.description())
+}
+
+fn text_inputs(config: &Config) -> BTreeMap<TextField, String> {
+    let thread_fields = ThreadClass::ALL.into_iter().flat_map(|class| {
+        [
+            TextField::ThreadNice(class),
+            TextField::ThreadAffinity(class),
+            TextField::ThreadTimerSlack(class),
+        ]
+    });
+    [
+        TextField::RobloxAffinity,
+        TextField::PrimaryAffinity,
+        TextField::BackgroundAffinity,
+        TextField::BackgroundNice,
+        TextField::PowerIdleMinutes,
+        TextField::PowerCpuLimit,
+        TextField::PowerNice,
+    ]
+    .into_iter()
+    .chain(thread_fields)
+    .map(|field| (field, field.read(config)))
+    .collect()
+}
+
+fn thread_policy(config: &Config, class: ThreadClass) -> Option<&ThreadPolicy> {
+    config.thread_policies.get(&class)
+}
+
+/// Sửa policy của nhóm thread; policy rỗng bị xóa để config không đổi khi user không sửa gì
+fn update_thread_policy(config: &mut Config, class: ThreadClass, update: impl FnOnce(&mut ThreadPolicy)) {
+    let policy = config.thread_policies.entry(class).or_default();
+    update(policy);
+    if *policy == ThreadPolicy::default() {
+        config.thread_policies.remove(&class);
+    }
+}
+
+/// Số nguyên không âm, rỗng = không có
+fn parse_number_input(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    input
+        .parse()
+        .map(Some)
+        .map_err(|_| t!("settings-number-invalid", value = input))
+}
+
+/// Field bắt buộc: rỗng → lỗi
+fn required<T>(value: Option<T>) -> Result<T, String> {
+    value.ok_or_else(|| t!("settings-value-required"))
+}
+
+/// Nice -20..19, rỗng = không đổi
+fn parse_nice_input(input: &str) -> Result<Option<i32>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let nice: i32 = input
+        .parse()
+        .map_err(|_| t!("settings-number-invalid", value = input))?;
+    if !(-20..=19).contains(&nice) {
+        return Err(t!("thread-invalid-nice", nice = nice));
+    }
+    Ok(Some(nice))
+}
+
+/// Danh sách CPU hoặc `fastest`, rỗng = không đổi
+fn parse_affinity_input(input: &str) -> Result<Option<String>, String> {
+    if input.trim() == FASTEST_CORES {
+        return Ok(Some(FASTEST_CORES.to_string()));
+    }
+    parse_cpu_list_input(input)
+}
+
+/// Danh sách CPU (vd `0-7`), rỗng = không đổi
+fn parse_cpu_list_input(input: &str) -> Result<Option<String>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    crate::process::parse_cpu_list(input)?;
+    Ok(Some(input.to_string()))
+}
+
+fn parse_interval(input: &str) -> Result<u64, String> {
+    let value = input
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| t!("settings-interval-invalid", value = input.trim()))?;
+    if value > MAX_BOOST_INTERVAL_SECONDS {
+        return Err(t!(
+            "config-interval-too-large",
+            max = MAX_BOOST_INTERVAL_SECONDS,
+            value = value
+        ));
+    }
+    Ok(value)
+}
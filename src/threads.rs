@@ -0,0 +1,361 @@
+use crate::i18n::t;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Giá trị `affinity` đặc biệt: các core có xung nhịp tối đa cao nhất (P-core)
+pub const FASTEST_CORES: &str = "fastest";
+
+/// Timer slack tối đa chấp nhận trong config (1s)
+const MAX_TIMER_SLACK_NS: u64 = 1_000_000_000;
+
+/// Nhóm thread của Roblox, phân loại theo tên thread (`/proc/<pid>/task/<tid>/comm`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadClass {
+    /// Render/present (cả thread của DXVK)
+    Render,
+    /// Job worker của task scheduler
+    Worker,
+    Audio,
+    Network,
+    /// Thread không khớp nhóm nào (kể cả main thread)
+    Other,
+}
+
+/// Từ khóa trong tên thread (chữ thường) → nhóm, nhóm đầu tiên khớp được chọn
+const CLASS_PATTERNS: &[(ThreadClass, &[&str])] = &[
+    (ThreadClass::Render, &["render", "dxvk", "present", "swapchain", "gpu"]),
+    (ThreadClass::Audio, &["audio", "fmod", "sound", "pulse", "alsa", "pipewire"]),
+    (ThreadClass::Network, &["network", "raknet", "http", "socket", "curl", "replicat"]),
+    (ThreadClass::Worker, &["worker", "job", "task", "pool", "tbb"]),
+];
+
+impl ThreadClass {
+    pub const ALL: [ThreadClass; 5] = [
+        ThreadClass::Render,
+        ThreadClass::Worker,
+        ThreadClass::Audio,
+        ThreadClass::Network,
+        ThreadClass::Other,
+    ];
+
+    pub fn classify(comm: &str) -> Self {
+        let comm = comm.to_lowercase();
+        CLASS_PATTERNS
+            .iter()
+            .find(|(_, patterns)| patterns.iter().any(|pattern| comm.contains(pattern)))
+            .map_or(Self::Other, |(class, _)| *class)
+    }
+}
+
+impl fmt::Display for ThreadClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Render => f.write_str("render"),
+            Self::Worker => f.write_str("worker"),
+            Self::Audio => f.write_str("audio"),
+            Self::Network => f.write_str("network"),
+            Self::Other => f.write_str("other"),
+        }
+    }
+}
+
+/// Policy cho một nhóm thread, field không có = không đổi
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ThreadPolicy {
+    /// Nice của thread (-20..19)
+    #[schemars(range(min = -20, max = 19))]
+    pub nice: Option<i32>,
+    /// Danh sách CPU (vd `0-3`) hoặc `fastest` = các core nhanh nhất
+    pub affinity: Option<String>,
+    /// Timer slack (ns), nhỏ → sleep/timer chính xác hơn
+    pub timer_slack_ns: Option<u64>,
+}
+
+impl ThreadPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(nice) = self.nice {
+            if !(-20..=19).contains(&nice) {
+                return Err(t!("thread-invalid-nice", nice = nice));
+            }
+        }
+        if let Some(affinity) = self.affinity.as_deref().filter(|affinity| *affinity != FASTEST_CORES) {
+            crate::process::parse_cpu_list(affinity)?;
+        }
+        if let Some(slack) = self.timer_slack_ns {
+            if slack == 0 || slack > MAX_TIMER_SLACK_NS {
+                return Err(t!("thread-invalid-timer-slack", value = slack, max = MAX_TIMER_SLACK_NS));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Policy mặc định: render + audio ưu tiên cao, render ở core nhanh nhất
+///
+/// Nice -10 cần root/CAP_SYS_NICE hoặc `RLIMIT_NICE` ≥ 30; thiếu quyền thì nice của nhóm đó
+/// được bỏ qua, các thuộc tính khác vẫn áp dụng (xem `nice_permitted`).
+pub fn default_policies() -> BTreeMap<ThreadClass, ThreadPolicy> {
+    BTreeMap::from([
+        (
+            ThreadClass::Render,
+            ThreadPolicy {
+                nice: Some(-10),
+                affinity: Some(FASTEST_CORES.to_string()),
+                timer_slack_ns: Some(1),
+            },
+        ),
+        (
+            ThreadClass::Audio,
+            ThreadPolicy {
+                nice: Some(-10),
+                affinity: None,
+                timer_slack_ns: Some(1),
+            },
+        ),
+        (
+            ThreadClass::Worker,
+            ThreadPolicy {
+                nice: Some(crate::process::BOOST_NICE),
+                affinity: None,
+                timer_slack_ns: None,
+            },
+        ),
+    ])
+}
+
+/// Thuộc tính của thread mà booster đổi (ghi trong journal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadKnob {
+    Nice,
+    Affinity,
+    TimerSlack,
+}
+
+impl ThreadKnob {
+    /// Giá trị hiện tại dạng chuỗi (như trong journal)
+    pub fn read(self, tid: u32) -> Option<String> {
+        match self {
+            // /proc/<tid> trỏ tới đúng thread, setpriority/sched_setaffinity cũng nhận TID
+            Self::Nice => crate::process::read_nice(tid).map(|nice| nice.to_string()),
+            Self::Affinity => crate::process::read_affinity(tid),
+            Self::TimerSlack => read_timer_slack(tid).map(|slack| slack.to_string()),
+        }
+    }
+
+    pub fn set(self, tid: u32, value: &str) -> Result<(), String> {
+        match self {
+            Self::Nice => {
+                let nice = value.parse().map_err(|_| t!("thread-invalid-nice", nice = value))?;
+                crate::process::set_nice_privileged(tid, nice)
+            }
+            Self::Affinity => crate::process::set_affinity(tid, value),
+            Self::TimerSlack => {
+                let slack = value
+                    .parse()
+                    .map_err(|_| t!("thread-invalid-timer-slack", value = value, max = MAX_TIMER_SLACK_NS))?;
+                set_timer_slack(tid, slack)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ThreadKnob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nice => f.write_str("nice"),
+            Self::Affinity => f.write_str("affinity"),
+            Self::TimerSlack => f.write_str("timer slack"),
+        }
+    }
+}
+
+/// Một thread của process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub comm: String,
+    pub class: ThreadClass,
+}
+
+fn task_dir(pid: u32, tid: u32) -> PathBuf {
+    PathBuf::from(format!("/proc/{}/task/{}", pid, tid))
+}
+
+/// Các thread hiện có của process (rỗng nếu process đã thoát hoặc không phải Linux)
+pub fn list(pid: u32) -> Vec<ThreadInfo> {
+    let Ok(entries) = fs::read_dir(format!("/proc/{}/task", pid)) else {
+        return Vec::new();
+    };
+    let mut threads: Vec<ThreadInfo> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .filter_map(|tid| {
+            let comm = read_comm(pid, tid)?;
+            Some(ThreadInfo {
+                tid,
+                class: ThreadClass::classify(&comm),
+                comm,
+            })
+        })
+        .collect();
+    threads.sort_by_key(|thread| thread.tid);
+    threads
+}
+
+/// Tên thread, `None` nếu thread đã thoát
+pub fn read_comm(pid: u32, tid: u32) -> Option<String> {
+    fs::read_to_string(task_dir(pid, tid).join("comm"))
+        .ok()
+        .map(|comm| comm.trim_end().to_string())
+}
+
+/// `timerslack_ns` chỉ có ở `/proc/<tid>` (không có trong `task/<tid>`)
+pub fn read_timer_slack(tid: u32) -> Option<u64> {
+    fs::read_to_string(format!("/proc/{}/timerslack_ns", tid))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Ghi `timerslack_ns` của thread (thread của process khác cần CAP_SYS_NICE)
+pub fn set_timer_slack(tid: u32, slack: u64) -> Result<(), String> {
+    fs::write(format!("/proc/{}/timerslack_ns", tid), slack.to_string())
+        .map_err(|err| t!("thread-timerslack-failed", tid = tid, value = slack, error = err))
+}
+
+/// Đổi nice từ `current` sang `target` được không khi chỉ được giảm tới `floor`
+/// (`capabilities::nice_floor`): tăng nice luôn được, giảm thấp hơn `floor` thì không
+pub fn nice_permitted(current: i32, target: i32, floor: i32) -> bool {
+    target >= current || target >= floor
+}
+
+/// Danh sách CPU thật của `affinity` trong config: `fastest` → `fastest` (đã tính sẵn), còn lại giữ nguyên
+pub fn resolve_affinity<'a>(affinity: Option<&'a str>, fastest: Option<&'a str>) -> Option<&'a str> {
+    match affinity {
+        Some(FASTEST_CORES) => fastest,
+        other => other,
+    }
+}
+
+/// CPU có `cpuinfo_max_freq` cao nhất (vd P-core của CPU hybrid), dạng `2,3,6`
+///
+/// `None` nếu không đọc được cpufreq hoặc mọi core như nhau (không cần ghim).
+pub fn fastest_cpus() -> Option<String> {
+    fastest_cpus_in(Path::new("/sys/devices/system/cpu"))
+}
+
+fn fastest_cpus_in(cpu_dir: &Path) -> Option<String> {
+    let entries = fs::read_dir(cpu_dir).ok()?;
+    let mut cpus: Vec<(usize, u64)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let cpu = entry.file_name().to_str()?.strip_prefix("cpu")?.parse().ok()?;
+            let freq = fs::read_to_string(entry.path().join("cpufreq").join("cpuinfo_max_freq"))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()?;
+            Some((cpu, freq))
+        })
+        .collect();
+    let max = cpus.iter().map(|(_, freq)| *freq).max()?;
+    if cpus.iter().all(|(_, freq)| *freq == max) {
+        return None;
+    }
+    cpus.retain(|(_, freq)| *freq == max);
+    cpus.sort_unstable();
+    Some(
+        cpus.iter()
+            .map(|(cpu, _)| cpu.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_by_first_matching_pattern() {
+        for &(class, patterns) in CLASS_PATTERNS {
+            for pattern in patterns {
+                assert_eq!(ThreadClass::classify(pattern), class, "{}", pattern);
+            }
+        }
+        assert_eq!(ThreadClass::classify("RenderJob"), ThreadClass::Render);
+        assert_eq!(ThreadClass::classify("dxvk-submit"), ThreadClass::Render);
+        assert_eq!(ThreadClass::classify("FMOD mixer"), ThreadClass::Audio);
+        assert_eq!(ThreadClass::classify("RakNet thread"), ThreadClass::Network);
+        // "HttpWorker" khớp cả network lẫn worker → network đứng trước
+        assert_eq!(ThreadClass::classify("HttpWorker"), ThreadClass::Network);
+        assert_eq!(ThreadClass::classify("TaskPool.3"), ThreadClass::Worker);
+        assert_eq!(ThreadClass::classify("RobloxPlayer"), ThreadClass::Other);
+        assert_eq!(ThreadClass::classify(""), ThreadClass::Other);
+    }
+
+    #[test]
+    fn resolves_fastest_affinity() {
+        assert_eq!(resolve_affinity(Some(FASTEST_CORES), Some("2,3")), Some("2,3"));
+        // Không có core nhanh hơn → không ghim
+        assert_eq!(resolve_affinity(Some(FASTEST_CORES), None), None);
+        assert_eq!(resolve_affinity(Some("0-3"), Some("2,3")), Some("0-3"));
+        assert_eq!(resolve_affinity(Some("0-3"), None), Some("0-3"));
+        assert_eq!(resolve_affinity(None, Some("2,3")), None);
+    }
+
+    fn cpu_dir(freqs: &[(usize, Option<u64>)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for &(cpu, freq) in freqs {
+            let cpufreq = dir.path().join(format!("cpu{}", cpu)).join("cpufreq");
+            fs::create_dir_all(&cpufreq).unwrap();
+            if let Some(freq) = freq {
+                fs::write(cpufreq.join("cpuinfo_max_freq"), format!("{}\n", freq)).unwrap();
+            }
+        }
+        fs::create_dir_all(dir.path().join("cpuidle")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn picks_cpus_with_highest_max_freq() {
+        let dir = cpu_dir(&[
+            (0, Some(3_600_000)),
+            (1, Some(5_400_000)),
+            (10, Some(5_400_000)),
+            (2, Some(3_600_000)),
+            (3, None),
+        ]);
+        assert_eq!(fastest_cpus_in(dir.path()).as_deref(), Some("1,10"));
+    }
+
+    #[test]
+    fn no_fastest_cpus_when_uniform_or_unreadable() {
+        let dir = cpu_dir(&[(0, Some(4_000_000)), (1, Some(4_000_000))]);
+        assert_eq!(fastest_cpus_in(dir.path()), None);
+        let dir = cpu_dir(&[(0, None), (1, None)]);
+        assert_eq!(fastest_cpus_in(dir.path()), None);
+        assert_eq!(fastest_cpus_in(&dir.path().join("missing")), None);
+    }
+
+    #[test]
+    fn nice_below_floor_is_not_permitted() {
+        // Root/CAP_SYS_NICE
+        assert!(nice_permitted(0, -10, -20));
+        // RLIMIT_NICE = 25 → chỉ giảm tới -5
+        assert!(!nice_permitted(0, -10, -5));
+        assert!(nice_permitted(0, -5, -5));
+        // RLIMIT_NICE = 0: không giảm được, tăng nice vẫn được
+        assert!(!nice_permitted(0, -5, 20));
+        assert!(nice_permitted(0, 5, 20));
+        assert!(nice_permitted(-10, -5, 20));
+    }
+}
@@ -1,262 +1,575 @@
+use crate::autostart::{Autostart, AutostartKind};
 use crate::booster::RobloxBoosterEngine;
 use crate::config::Config;
+use crate::dashboard::Dashboard;
+use crate::events::{EngineEvent, EngineStatus};
+use crate::explorer::{ExplorerMessage, ProcessExplorer, ProcessList};
+use crate::i18n::t;
+use crate::log_panel::{LogPanel, LogPanelMessage};
+use crate::logging::{self, log_error, log_info, LogEntry};
+use crate::optimizations::{self, Availability, Optimization};
+use crate::paths;
+use crate::session_panel::{SessionPanel, SessionPanelMessage};
+use crate::settings::{SettingsEditor, SettingsMessage};
 use iced::{
-    widget::{button, checkbox, column, container, row, text, Column},
-    Alignment, Application, Command, Element, Length, Theme,
+    widget::{button, column, container, row, scrollable, text},
+    Alignment, Application, Color, Command, Element, Length, Subscription, Theme,
 };
 use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Các trang (tab) của app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Dashboard,
+    Processes,
+    Sessions,
+    Logs,
+    Settings,
+}
+
+impl Page {
+    pub const ALL: [Page; 5] = [
+        Page::Dashboard,
+        Page::Processes,
+        Page::Sessions,
+        Page::Logs,
+        Page::Settings,
+    ];
+
+    fn label(self) -> String {
+        t!(match self {
+            Page::Dashboard => "page-dashboard",
+            Page::Processes => "page-processes",
+            Page::Sessions => "page-sessions",
+            Page::Logs => "page-logs",
+            Page::Settings => "page-settings",
+        })
+    }
+}
 
 /// Message types cho UI
 #[derive(Debug, Clone)]
 pub enum Message {
     ToggleBooster,
-    OpenSettings,
-    CloseSettings,
-    
-    // Config toggles
-    ToggleAutoStart(bool),
-    ToggleTimerResolution(bool),
-    ToggleMemoryCleanup(bool),
-    ToggleAutoDetection(bool),
-    
-    SaveSettings,
+    /// Kết quả của `start()`/`stop()` chạy trong `Command`
+    BoosterToggled(Result<(), String>),
+    Navigate(Page),
+    Settings(SettingsMessage),
+    /// Config đã lưu từ Settings được engine áp dụng xong
+    ConfigApplied,
+    Explorer(ExplorerMessage),
+    Sessions(SessionPanelMessage),
+    Logs(LogPanelMessage),
+    Log(LogEntry),
+
+    /// Event từ engine (hot reload, ...)
+    Engine(EngineEvent),
 }
 
 /// Main application struct
 pub struct RobloxBooster {
     booster: Arc<RobloxBoosterEngine>,
-    is_boosting: bool,
-    show_settings: bool,
+    /// Cập nhật từ `EngineEvent::StatusChanged` qua subscription
+    status: EngineStatus,
+    /// Lỗi bật/tắt gần nhất, hiện trên header
+    status_error: Option<String>,
+    page: Page,
+    /// Bản nháp settings, giữ lại khi chuyển tab cho tới khi lưu/hủy
+    settings: Option<SettingsEditor>,
     config: Config,
+    /// Registry kèm availability trên máy này (probe tốn syscall/đọc file → cache, cập nhật khi
+    /// mở Cài đặt hoặc config đổi/được áp dụng)
+    optimizations: Vec<(Arc<dyn Optimization>, Availability)>,
+    dashboard: Dashboard,
+    explorer: ProcessExplorer,
+    sessions: SessionPanel,
+    logs: LogPanel,
+    /// `None` khi không xác định được binary đang chạy
+    autostart: Option<Autostart>,
 }
 
 impl Application for RobloxBooster {
     type Executor = iced::executor::Default;
     type Message = Message;
     type Theme = Theme;
-    type Flags = ();
+    type Flags = Config;
+
+    fn new(config: Config) -> (Self, Command<Message>) {
+        let booster = Arc::new(RobloxBoosterEngine::new(config.clone()));
+        booster.recover_journal();
+        let app_paths = paths::get();
+        let config_files = [app_paths.system_config_file.clone(), app_paths.config_file.clone()];
+        if let Err(err) = booster.watch_config(&config_files) {
+            log_error!("{}", t!("ui-watch-config-failed", error = err));
+        }
+        if let Err(err) = booster.watch_focus() {
+            log_info!("ℹ️  {}", err);
+        }
+        booster.start_sampling();
+
+        let autostart = match Autostart::resolve(app_paths) {
+            Ok(autostart) => Some(autostart),
+            Err(err) => {
+                log_error!("{}", t!("autostart-failed", error = err));
+                None
+            }
+        };
+
+        let command = if config.auto_start {
+            log_info!("{}", t!("autostart-booster-on-launch"));
+            Command::perform(async {}, |_| Message::ToggleBooster)
+        } else {
+            Command::none()
+        };
 
-    fn new(_flags: ()) -> (Self, Command<Message>) {
-        let config = Config::load();
-        
         (
             Self {
-                booster: Arc::new(RobloxBoosterEngine::new(config.clone())),
-                is_boosting: false,
-                show_settings: false,
+                booster,
+                status: EngineStatus::Stopped,
+                status_error: None,
+                page: Page::Dashboard,
+                settings: None,
                 config,
+                optimizations: optimization_availability(),
+                dashboard: Dashboard::default(),
+                explorer: ProcessExplorer::default(),
+                sessions: SessionPanel::default(),
+                logs: LogPanel::new(logging::history()),
+                autostart,
             },
-            Command::none(),
+            command,
         )
     }
 
     fn title(&self) -> String {
-        String::from("Roblox Booster - No Admin Required")
+        t!("ui-window-title")
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::ToggleBooster => {
-                if self.is_boosting {
-                    self.booster.stop();
-                    self.is_boosting = false;
+                let booster = Arc::clone(&self.booster);
+                self.status_error = None;
+                return if self.status.is_active() {
+                    self.status = EngineStatus::Stopping;
+                    Command::perform(async move { booster.stop() }, Message::BoosterToggled)
                 } else {
-                    // Recreate booster với config hiện tại
-                    self.booster = Arc::new(RobloxBoosterEngine::new(self.config.clone()));
-                    
-                    let booster = Arc::clone(&self.booster);
-                    tokio::spawn(async move {
-                        booster.start().await;
-                    });
-                    self.is_boosting = true;
-                }
-            }
-            Message::OpenSettings => {
-                self.show_settings = true;
+                    self.status = EngineStatus::Starting;
+                    Command::perform(async move { booster.start().await }, Message::BoosterToggled)
+                };
             }
-            Message::CloseSettings => {
-                self.show_settings = false;
+            Message::BoosterToggled(result) => {
+                if let Err(err) = result {
+                    log_error!("✗ {}", err);
+                    self.status_error = Some(err);
+                }
+                // Trạng thái thật của engine (event StatusChanged có thể tới trước/sau)
+                self.status = if self.booster.is_running() {
+                    EngineStatus::Running
+                } else {
+                    EngineStatus::Stopped
+                };
             }
-            
-            // Config updates
-            Message::ToggleAutoStart(value) => {
-                self.config.auto_start = value;
+            Message::Navigate(page) => return self.navigate(page),
+            Message::Settings(message) => return self.update_settings(message),
+            Message::ConfigApplied => {
+                self.config = self.booster.config();
+                self.optimizations = optimization_availability();
+                self.reset_settings();
             }
-            Message::ToggleTimerResolution(value) => {
-                self.config.enable_timer_resolution = value;
+            Message::Explorer(message) => return self.update_explorer(message),
+            Message::Sessions(SessionPanelMessage::Refresh) => return self.load_sessions(true),
+            Message::Sessions(SessionPanelMessage::Loaded(entries)) => self.sessions.set_entries(entries),
+            Message::Logs(message) => return self.logs.update(message),
+            Message::Log(entry) => {
+                self.logs.push(entry);
             }
-            Message::ToggleMemoryCleanup(value) => {
-                self.config.enable_memory_cleanup = value;
+
+            Message::Engine(EngineEvent::ConfigChanged { .. }) => {
+                // Bản nháp trong settings giữ nguyên cho tới khi user lưu/hủy
+                self.config = self.booster.config();
+                self.optimizations = optimization_availability();
             }
-            Message::ToggleAutoDetection(value) => {
-                self.config.enable_auto_detection = value;
+            Message::Engine(EngineEvent::StatusChanged(status)) => {
+                self.status = status;
             }
-            
-            Message::SaveSettings => {
-                let _ = self.config.save();
-                self.show_settings = false;
-                
-                // Nếu đang chạy, restart với config mới
-                if self.is_boosting {
-                    self.booster.stop();
-                    self.booster = Arc::new(RobloxBoosterEngine::new(self.config.clone()));
-                    
-                    let booster = Arc::clone(&self.booster);
-                    tokio::spawn(async move {
-                        booster.start().await;
-                    });
+            Message::Engine(EngineEvent::ConfigRejected { .. }) => {}
+            Message::Engine(EngineEvent::Metrics(sample)) => {
+                self.dashboard.push(sample);
+                match self.page {
+                    Page::Processes => return self.load_explorer(false),
+                    Page::Sessions => return self.load_sessions(false),
+                    _ => {}
                 }
             }
         }
         Command::none()
     }
 
-    fn view(&self) -> Element<Message> {
-        if self.show_settings {
-            self.settings_view()
-        } else {
-            self.main_view()
-        }
+    fn view(&self) -> Element<'_, Message> {
+        let page = match self.page {
+            Page::Dashboard => self.dashboard_view(),
+            Page::Processes => self.explorer.view().map(Message::Explorer),
+            Page::Sessions => self.sessions.view().map(Message::Sessions),
+            Page::Logs => self.logs.view().map(Message::Logs),
+            Page::Settings => match &self.settings {
+                Some(editor) => editor.view(&self.optimizations).map(Message::Settings),
+                None => text("").into(),
+            },
+        };
+
+        column![
+            self.header_view(),
+            self.tabs_view(),
+            container(page)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding([0, 16, 16, 16]),
+        ]
+        .spacing(12)
+        .into()
     }
 
     fn theme(&self) -> Theme {
         Theme::Dark
     }
+
+    fn subscription(&self) -> Subscription<Message> {
+        struct EngineEvents;
+        struct LogEntries;
+
+        let engine_events = iced::subscription::unfold(
+            std::any::TypeId::of::<EngineEvents>(),
+            self.booster.subscribe(),
+            |mut events| async move {
+                loop {
+                    match events.recv().await {
+                        Ok(event) => return (Message::Engine(event), events),
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => {
+                            std::future::pending::<()>().await;
+                        }
+                    }
+                }
+            },
+        );
+
+        let log_entries = iced::subscription::unfold(
+            std::any::TypeId::of::<LogEntries>(),
+            logging::subscribe(),
+            |mut entries| async move {
+                loop {
+                    match entries.recv().await {
+                        Ok(entry) => return (Message::Log(entry), entries),
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => {
+                            std::future::pending::<()>().await;
+                        }
+                    }
+                }
+            },
+        );
+
+        Subscription::batch([engine_events, log_entries])
+    }
 }
 
 impl RobloxBooster {
-    /// View chính
-    fn main_view(&self) -> Element<Message> {
-        let status_text = if self.is_boosting {
-            "🚀 AUTO BOOSTER: ĐANG CHẠY"
-        } else {
-            "⏸️ AUTO BOOSTER: TẮT"
+    fn navigate(&mut self, page: Page) -> Command<Message> {
+        self.page = page;
+        match page {
+            Page::Processes => return self.load_explorer(true),
+            Page::Sessions => return self.load_sessions(true),
+            Page::Settings => {
+                self.optimizations = optimization_availability();
+                if self.settings.is_none() {
+                    self.reset_settings();
+                }
+            }
+            _ => {}
+        }
+        Command::none()
+    }
+
+    /// Đọc snapshot process cho explorer trên thread blocking (lọc, sắp xếp, đọc `/proc` cho dòng hiển thị)
+    ///
+    /// `force` = false (refresh tự động mỗi giây) → bỏ qua nếu lần đọc trước chưa xong.
+    fn load_explorer(&mut self, force: bool) -> Command<Message> {
+        let Some(query) = self.explorer.begin_load(force) else {
+            return Command::none();
         };
+        let booster = Arc::clone(&self.booster);
+        Command::perform(
+            async move {
+                tokio::task::spawn_blocking(move || query.select(booster.process_snapshot()))
+                    .await
+                    .unwrap_or_default()
+            },
+            |(total, entries)| Message::Explorer(ExplorerMessage::Loaded(total, entries)),
+        )
+    }
+
+    /// Đọc các session Roblox cho trang Phiên chơi trên thread blocking
+    ///
+    /// `force` = false (refresh tự động mỗi giây) → bỏ qua nếu lần đọc trước chưa xong.
+    fn load_sessions(&mut self, force: bool) -> Command<Message> {
+        if !self.sessions.begin_load(force) {
+            return Command::none();
+        }
+        let booster = Arc::clone(&self.booster);
+        Command::perform(
+            async move {
+                tokio::task::spawn_blocking(move || booster.session_snapshot())
+                    .await
+                    .unwrap_or_default()
+            },
+            |entries| Message::Sessions(SessionPanelMessage::Loaded(entries)),
+        )
+    }
 
+    /// Bản nháp mới từ config hiện tại (có thể đã hot reload từ file)
+    fn reset_settings(&mut self) {
+        let mut editor = SettingsEditor::new(self.booster.config());
+        editor.set_login_autostart(self.login_autostart_installed());
+        self.settings = Some(editor);
+    }
+
+    /// Header: trạng thái booster + nút bật/tắt (hiện trên mọi trang)
+    fn header_view(&self) -> Element<'_, Message> {
         let toggle_button = button(
-            text(if self.is_boosting {
-                "TẮT AUTO BOOSTER"
+            text(if self.status.is_active() {
+                t!("ui-button-stop")
             } else {
-                "BẬT AUTO BOOSTER"
+                t!("ui-button-start")
             })
-            .size(20),
+            .size(16),
         )
-        .padding(20)
-        .on_press(Message::ToggleBooster);
-
-        let settings_button = button(text("⚙️ SETTINGS").size(16))
-            .padding(15)
-            .on_press(Message::OpenSettings);
-        
-        // Active features
-        let features_text = self.get_features_summary();
-
-        let content = column![
-            text("ROBLOX BOOSTER").size(28),
-            text("(Không cần Admin)").size(14),
-            text("").size(5),
-            text(status_text).size(18),
-            text("").size(10),
-            text(features_text).size(12),
-            text("").size(15),
-            toggle_button,
-            settings_button,
-            text("").size(10),
-            text("💡 Tip: Mở Roblox và app sẽ tự tối ưu").size(11),
+        .padding(12)
+        // Đang bật/tắt dở → khóa nút tới khi Command trả kết quả
+        .on_press_maybe(
+            matches!(self.status, EngineStatus::Stopped | EngineStatus::Running)
+                .then_some(Message::ToggleBooster),
+        );
+
+        let mut info = column![
+            text("ROBLOX BOOSTER").size(24),
+            text(t!("ui-status", status = self.status)).size(16),
+            text(self.get_features_summary()).size(12),
         ]
-        .spacing(12)
-        .align_items(Alignment::Center);
+        .spacing(4)
+        .width(Length::Fill);
+        if self.config.dry_run {
+            info = info.push(text(t!("ui-dry-run-badge")).size(12).style(Color::from_rgb(1.0, 0.75, 0.3)));
+        }
+        if let Some(err) = &self.status_error {
+            info = info.push(text(format!("✗ {}", err)).size(12).style(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
 
-        container(content)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .center_x()
-            .center_y()
-            .into()
+        row![
+            info,
+            toggle_button,
+        ]
+        .spacing(10)
+        .padding([16, 16, 0, 16])
+        .align_items(Alignment::Center)
+        .into()
     }
 
-    /// View settings
-    fn settings_view(&self) -> Element<Message> {
-        let content: Column<Message> = column![
-            text("⚙️ CÀI ĐẶT").size(26),
-            text("").size(5),
-            
-            // General
-            text("Chung:").size(18),
-            checkbox("Auto Start khi mở app", self.config.auto_start)
-                .on_toggle(Message::ToggleAutoStart),
-            
-            text("").size(10),
-            
-            // Features (KHÔNG CẦN ADMIN)
-            text("Tính Năng:").size(18),
-            
-            checkbox(
-                "⏱️  Timer Resolution (1ms)\n    → Giảm lag, game mượt hơn",
-                self.config.enable_timer_resolution
-            )
-            .on_toggle(Message::ToggleTimerResolution),
-            
-            checkbox(
-                "🧹 Memory Cleanup (60s)\n    → Giải phóng RAM cho Roblox",
-                self.config.enable_memory_cleanup
-            )
-            .on_toggle(Message::ToggleMemoryCleanup),
-            
-            checkbox(
-                "🔍 Auto-Detection\n    → Tự động phát hiện Roblox",
-                self.config.enable_auto_detection
+    fn tabs_view(&self) -> Element<'_, Message> {
+        let errors = self.logs.error_count();
+        let tabs = Page::ALL.iter().fold(row![].spacing(4), |tabs, &page| {
+            let mut label = page.label();
+            if page == Page::Logs && errors > 0 {
+                label = t!("page-logs-errors", count = errors);
+            }
+            if page == self.page {
+                label = format!("• {}", label);
+            }
+            tabs.push(
+                button(text(label).size(13))
+                    .padding([6, 10])
+                    .on_press(Message::Navigate(page)),
             )
-            .on_toggle(Message::ToggleAutoDetection),
-            
-            text("").size(15),
-            
-            // Info
-            text("ℹ️  Tất cả features KHÔNG cần Admin").size(11),
-            text("ℹ️  Tối ưu system-wide cho mọi app").size(11),
-            
-            text("").size(10),
-            
-            // Buttons
-            row![
-                button(text("💾 LƯU").size(16))
-                    .padding(15)
-                    .on_press(Message::SaveSettings),
-                button(text("❌ HỦY").size(16))
-                    .padding(15)
-                    .on_press(Message::CloseSettings),
-            ]
-            .spacing(10),
-        ]
-        .spacing(10)
-        .align_items(Alignment::Center);
+        });
 
-        container(content)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .center_x()
-            .center_y()
+        scrollable(container(tabs).padding([0, 16]))
+            .direction(scrollable::Direction::Horizontal(
+                scrollable::Properties::new().width(4).scroller_width(4),
+            ))
             .into()
     }
-    
-    /// Summary của active features
-    fn get_features_summary(&self) -> String {
-        let mut active = Vec::new();
-        
-        if self.config.enable_timer_resolution {
-            active.push("⏱️ Timer 1ms");
-        }
-        if self.config.enable_memory_cleanup {
-            active.push("🧹 RAM Cleanup");
+
+    fn dashboard_view(&self) -> Element<'_, Message> {
+        scrollable(
+            column![text(t!("ui-tip")).size(11), self.dashboard.view()].spacing(12),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    /// Chạy thao tác của engine trên thread blocking, gửi kết quả qua `done`
+    ///
+    /// Nice/setting hệ thống có thể fallback sang pkexec và đợi hộp thoại polkit →
+    /// chạy thẳng trong `update` sẽ treo cả UI.
+    fn engine_task(
+        &self,
+        task: impl FnOnce(&RobloxBoosterEngine) -> Result<String, String> + Send + 'static,
+        done: impl FnOnce(Result<String, String>) -> Message + Send + 'static,
+    ) -> Command<Message> {
+        let booster = Arc::clone(&self.booster);
+        Command::perform(
+            async move {
+                tokio::task::spawn_blocking(move || task(&booster))
+                    .await
+                    .unwrap_or_else(|err| Err(err.to_string()))
+            },
+            done,
+        )
+    }
+
+    fn update_explorer(&mut self, message: ExplorerMessage) -> Command<Message> {
+        let explorer_done = |result| Message::Explorer(ExplorerMessage::ActionDone(result));
+        match message {
+            ExplorerMessage::Loaded(total, entries) => {
+                self.explorer.set_entries(total, entries);
+                Command::none()
+            }
+            ExplorerMessage::Refresh => self.load_explorer(true),
+            ExplorerMessage::ActionDone(result) => {
+                match result {
+                    Ok(status) => self.explorer.set_status(status),
+                    Err(err) => self.explorer.set_status(format!("✗ {}", err)),
+                }
+                self.load_explorer(true)
+            }
+            ExplorerMessage::Boost(pid) => self.engine_task(
+                move |booster| {
+                    booster.boost_process(pid).map(|planned| match planned {
+                        Some(action) => t!("explorer-boost-planned", action = action),
+                        None => t!("explorer-boosted", pid = pid),
+                    })
+                },
+                explorer_done,
+            ),
+            ExplorerMessage::Revert(pid) => self.engine_task(
+                move |booster| booster.revert_process(pid).map(|_| t!("explorer-reverted", pid = pid)),
+                explorer_done,
+            ),
+            ExplorerMessage::AddToList(list, name) => {
+                let original = self.booster.config();
+                let mut config = original.clone();
+                let (entries, label) = match list {
+                    ProcessList::Roblox => (&mut config.roblox_process_names, "Roblox"),
+                    ProcessList::Pause => (&mut config.pause_process_names, "Pause"),
+                };
+                if !entries.iter().any(|entry| entry.eq_ignore_ascii_case(&name)) {
+                    entries.push(name.clone());
+                }
+
+                let saved = config.save(&original).map_err(|err| err.to_string());
+                self.config = config.clone();
+                self.engine_task(
+                    move |booster| {
+                        booster.apply_config(config);
+                        saved.map(|_| t!("explorer-added", name = name, list = label))
+                    },
+                    explorer_done,
+                )
+            }
+            message @ (ExplorerMessage::FilterChanged(_) | ExplorerMessage::SortBy(_)) => {
+                self.explorer.update(&message);
+                self.load_explorer(true)
+            }
         }
-        if self.config.enable_auto_detection {
-            active.push("🔍 Auto-Detect");
+    }
+
+    fn update_settings(&mut self, message: SettingsMessage) -> Command<Message> {
+        let Some(editor) = &mut self.settings else {
+            return Command::none();
+        };
+
+        match message {
+            SettingsMessage::Save => {
+                let config = match editor.result() {
+                    Ok(config) => config,
+                    Err(err) => {
+                        log_error!("✗ {}", err);
+                        return Command::none();
+                    }
+                };
+                if let Err(err) = config.save(editor.original()) {
+                    log_error!("{}", t!("settings-save-failed", error = err));
+                    return Command::none();
+                }
+
+                // Áp dụng trực tiếp, không cần restart booster
+                return self.engine_task(
+                    move |booster| {
+                        booster.apply_config(config);
+                        Ok(String::new())
+                    },
+                    |_| Message::ConfigApplied,
+                );
+            }
+            SettingsMessage::Cancel => {
+                self.settings = None;
+                self.page = Page::Dashboard;
+            }
+            SettingsMessage::LoginAutostart(enabled) => {
+                if let Some(autostart) = &self.autostart {
+                    let result = if enabled {
+                        autostart
+                            .install(AutostartKind::Desktop)
+                            .map(|_| t!("autostart-installed", path = autostart.desktop_file.display()))
+                    } else {
+                        autostart.remove().map(|_| t!("autostart-removed"))
+                    };
+                    match result {
+                        Ok(message) => log_info!("{}", message),
+                        Err(err) => log_error!("{}", t!("autostart-failed", error = err)),
+                    }
+                }
+                let installed = self.login_autostart_installed();
+                if let Some(editor) = &mut self.settings {
+                    editor.set_login_autostart(installed);
+                }
+            }
+            message => editor.update(message),
         }
-        
+        Command::none()
+    }
+
+    fn login_autostart_installed(&self) -> Option<bool> {
+        self.autostart.as_ref().map(Autostart::is_installed)
+    }
+
+    /// Summary của active features
+    fn get_features_summary(&self) -> String {
+        let active: Vec<_> = self
+            .optimizations
+            .iter()
+            .filter(|(optimization, availability)| {
+                availability.is_available() && self.config.is_enabled(optimization.id())
+            })
+            .map(|(optimization, _)| optimization.name())
+            .collect();
+
         if active.is_empty() {
-            "❌ Không có feature nào được bật".to_string()
+            t!("summary-none")
         } else {
-            format!("Features: {}", active.join(" | "))
+            t!("summary-features", list = active.join(" | "))
         }
     }
-}
\ No newline at end of file
+}
+
+fn optimization_availability() -> Vec<(Arc<dyn Optimization>, Availability)> {
+    optimizations::registry()
+        .into_iter()
+        .map(|optimization| {
+            let availability = optimization.availability();
+            (optimization, availability)
+        })
+        .collect()
+}
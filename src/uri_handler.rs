@@ -0,0 +1,460 @@
+use crate::i18n::t;
+use crate::paths::AppPaths;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const DESKTOP_FILE_NAME: &str = "roblox_booster-handler.desktop";
+const STATE_FILE_NAME: &str = "uri_handler.json";
+const MIMEAPPS_FILE_NAME: &str = "mimeapps.list";
+const DEFAULT_SECTION: &str = "[Default Applications]";
+
+/// Scheme booster nhận (nút Play trên web mở `roblox-player:`)
+pub const SCHEMES: [&str; 2] = ["roblox-player", "roblox"];
+
+fn mime_type(scheme: &str) -> String {
+    format!("x-scheme-handler/{}", scheme)
+}
+
+/// Handler mặc định trước khi booster được đăng ký (để forward và khôi phục khi gỡ)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct HandlerState {
+    /// mime type → desktop file ID, vd `x-scheme-handler/roblox-player` → `org.vinegarhq.Sober.desktop`
+    previous: BTreeMap<String, String>,
+}
+
+/// Đăng ký booster làm handler cho URI `roblox-player:` / `roblox:` (Linux/XDG)
+///
+/// Ghi một file `.desktop` chạy `roblox_booster launch %u` và đặt nó làm mặc định
+/// trong `mimeapps.list` của user.
+#[derive(Debug, Clone)]
+pub struct UriHandler {
+    pub desktop_file: PathBuf,
+    pub mimeapps_file: PathBuf,
+    /// Handler cũ đã ghi lại lúc cài
+    pub state_file: PathBuf,
+    /// Binary được chạy khi mở URI
+    pub exec: PathBuf,
+}
+
+impl UriHandler {
+    /// Paths theo thư mục của user (`$XDG_DATA_HOME`, `$XDG_CONFIG_HOME`) và binary đang chạy
+    pub fn resolve(app_paths: &AppPaths) -> io::Result<Self> {
+        Ok(Self::with_exec(app_paths, std::env::current_exe()?))
+    }
+
+    /// `app_paths` có thể resolve từ HOME tạm (xem `AppPaths::resolve_with`)
+    pub fn with_exec(app_paths: &AppPaths, exec: PathBuf) -> Self {
+        // config_dir = <config base>/roblox_booster
+        let config_base = app_paths
+            .config_dir
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| app_paths.config_dir.clone());
+
+        Self {
+            desktop_file: app_paths.data_home.join("applications").join(DESKTOP_FILE_NAME),
+            mimeapps_file: config_base.join(MIMEAPPS_FILE_NAME),
+            state_file: app_paths.state_dir.join(STATE_FILE_NAME),
+            exec,
+        }
+    }
+
+    /// Scheme đang trỏ tới booster trong `mimeapps.list`
+    pub fn registered_schemes(&self) -> Vec<&'static str> {
+        let mimeapps = fs::read_to_string(&self.mimeapps_file).unwrap_or_default();
+        SCHEMES
+            .into_iter()
+            .filter(|scheme| {
+                default_handler(&mimeapps, &mime_type(scheme)).as_deref() == Some(DESKTOP_FILE_NAME)
+            })
+            .collect()
+    }
+
+    /// Handler cũ của `scheme` (desktop file ID), `None` nếu trước đó chưa có
+    pub fn previous_handler(&self, scheme: &str) -> Option<String> {
+        self.load_state().previous.get(&mime_type(scheme)).cloned()
+    }
+
+    /// Ghi file `.desktop` và đặt làm handler mặc định, giữ lại handler cũ để forward
+    pub fn install(&self) -> io::Result<()> {
+        if !cfg!(all(unix, not(target_os = "macos"))) {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, t!("handler-unsupported")));
+        }
+
+        let mut mimeapps = fs::read_to_string(&self.mimeapps_file).unwrap_or_default();
+        // Cài lại → giữ handler cũ đã ghi, không ghi đè bằng chính booster
+        let mut state = self.load_state();
+        for scheme in SCHEMES {
+            let mime = mime_type(scheme);
+            match default_handler(&mimeapps, &mime) {
+                Some(id) if id != DESKTOP_FILE_NAME => {
+                    state.previous.insert(mime.clone(), id);
+                }
+                _ => {}
+            }
+            mimeapps = set_default_handler(&mimeapps, &mime, Some(DESKTOP_FILE_NAME));
+        }
+
+        write_file(&self.desktop_file, &self.desktop_entry())?;
+        write_file(&self.state_file, &serde_json::to_string_pretty(&state)?)?;
+        write_file(&self.mimeapps_file, &mimeapps)
+    }
+
+    /// Khôi phục handler cũ (hoặc bỏ dòng của booster), xóa file `.desktop`
+    pub fn remove(&self) -> io::Result<()> {
+        let state = self.load_state();
+        match fs::read_to_string(&self.mimeapps_file) {
+            Ok(mut mimeapps) => {
+                for scheme in SCHEMES {
+                    let mime = mime_type(scheme);
+                    // User đã tự chọn handler khác → không đụng vào
+                    if default_handler(&mimeapps, &mime).as_deref() != Some(DESKTOP_FILE_NAME) {
+                        continue;
+                    }
+                    let previous = state.previous.get(&mime).map(String::as_str);
+                    mimeapps = set_default_handler(&mimeapps, &mime, previous);
+                }
+                fs::write(&self.mimeapps_file, mimeapps)?;
+            }
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            Err(_) => {}
+        }
+
+        for path in [&self.desktop_file, &self.state_file] {
+            match fs::remove_file(path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Nội dung file `.desktop` (XDG Desktop Entry spec)
+    pub fn desktop_entry(&self) -> String {
+        let mime_types: String = SCHEMES.iter().map(|scheme| format!("{};", mime_type(scheme))).collect();
+        format!(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=Roblox Booster\n\
+             Comment={}\n\
+             Exec={} launch %u\n\
+             MimeType={}\n\
+             NoDisplay=true\n\
+             Terminal=false\n",
+            t!("handler-comment"),
+            crate::autostart::quote_exec(&self.exec),
+            mime_types
+        )
+    }
+
+    fn load_state(&self) -> HandlerState {
+        fs::read_to_string(&self.state_file)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Handler mặc định của `mime` trong `[Default Applications]` (ID đầu tiên)
+fn default_handler(mimeapps: &str, mime: &str) -> Option<String> {
+    let mut in_defaults = false;
+    for line in mimeapps.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_defaults = line == DEFAULT_SECTION;
+            continue;
+        }
+        if !in_defaults {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == mime {
+                return value
+                    .split(';')
+                    .map(str::trim)
+                    .find(|id| !id.is_empty())
+                    .map(str::to_string);
+            }
+        }
+    }
+    None
+}
+
+/// Đặt (`Some`) hoặc bỏ (`None`) dòng `mime=` trong `[Default Applications]`, giữ nguyên phần còn lại
+fn set_default_handler(mimeapps: &str, mime: &str, handler: Option<&str>) -> String {
+    let mut lines: Vec<String> = mimeapps.lines().map(str::to_string).collect();
+    let new_line = handler.map(|id| format!("{}={};", mime, id));
+
+    let section = lines.iter().position(|line| line.trim() == DEFAULT_SECTION);
+    let Some(section) = section else {
+        if let Some(new_line) = new_line {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(DEFAULT_SECTION.to_string());
+            lines.push(new_line);
+        }
+        return lines.join("\n") + "\n";
+    };
+
+    let end = lines[section + 1..]
+        .iter()
+        .position(|line| line.trim().starts_with('['))
+        .map_or(lines.len(), |offset| section + 1 + offset);
+    let existing = (section + 1..end).find(|&i| {
+        lines[i]
+            .split_once('=')
+            .is_some_and(|(key, _)| key.trim() == mime)
+    });
+
+    match (existing, new_line) {
+        (Some(i), Some(new_line)) => lines[i] = new_line,
+        (Some(i), None) => {
+            lines.remove(i);
+        }
+        (None, Some(new_line)) => {
+            // Chèn sau dòng cuối không trống của section
+            let mut insert = end;
+            while insert > section + 1 && lines[insert - 1].trim().is_empty() {
+                insert -= 1;
+            }
+            lines.insert(insert, new_line);
+        }
+        (None, None) => {}
+    }
+    lines.join("\n") + "\n"
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// File `.desktop` của một desktop file ID trong các thư mục `applications` chuẩn
+pub fn find_desktop_file(app_paths: &AppPaths, id: &str) -> Option<PathBuf> {
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+
+    std::iter::once(app_paths.data_home.clone())
+        .chain(std::iter::once(app_paths.data_home.join("flatpak").join("exports").join("share")))
+        .chain(data_dirs.split(':').map(PathBuf::from))
+        .chain(std::iter::once(PathBuf::from("/var/lib/flatpak/exports/share")))
+        .map(|dir| dir.join("applications").join(id))
+        .find(|path| path.is_file())
+}
+
+/// Lệnh trong `Exec=` của file `.desktop`, thay `%u`/`%U` bằng `uri`
+pub fn desktop_exec(path: &Path, uri: &str) -> Option<Vec<String>> {
+    let contents = fs::read_to_string(path).ok()?;
+    let mut in_entry = false;
+    let exec = contents.lines().find_map(|line| {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            return None;
+        }
+        in_entry.then(|| line.strip_prefix("Exec=")).flatten()
+    })?;
+
+    let mut args = Vec::new();
+    for arg in split_exec(exec) {
+        match arg.as_str() {
+            "%u" | "%U" | "%f" | "%F" => args.push(uri.to_string()),
+            // Field code khác (%i, %c, %k...) bị bỏ
+            code if code.len() == 2 && code.starts_with('%') => {}
+            _ => args.push(arg.replace("%%", "%")),
+        }
+    }
+    (!args.is_empty()).then_some(args)
+}
+
+/// Tách `Exec=` theo quy tắc quote của Desktop Entry spec
+fn split_exec(exec: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut has_arg = false;
+    let mut quoted = false;
+    let mut chars = exec.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                has_arg = true;
+            }
+            '\\' if quoted => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            c if c.is_whitespace() && !quoted => {
+                if has_arg {
+                    args.push(std::mem::take(&mut current));
+                    has_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_arg = true;
+            }
+        }
+    }
+    if has_arg {
+        args.push(current);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOBER: &str = "org.vinegarhq.Sober.desktop";
+
+    fn handler(home: &Path, exec: &str) -> (AppPaths, UriHandler) {
+        let app_paths = AppPaths::resolve_with(None, |key| (key == "HOME").then(|| home.to_path_buf()));
+        let handler = UriHandler::with_exec(&app_paths, PathBuf::from(exec));
+        (app_paths, handler)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn install_writes_desktop_entry_and_mimeapps_defaults() {
+        let home = tempfile::tempdir().unwrap();
+        let (_, handler) = handler(home.path(), "/usr/bin/roblox_booster");
+
+        handler.install().unwrap();
+        assert_eq!(
+            handler.desktop_file,
+            home.path().join(".local/share/applications").join(DESKTOP_FILE_NAME)
+        );
+        assert_eq!(handler.mimeapps_file, home.path().join(".config").join(MIMEAPPS_FILE_NAME));
+
+        let entry = fs::read_to_string(&handler.desktop_file).unwrap();
+        assert!(entry.contains("Exec=/usr/bin/roblox_booster launch %u\n"));
+        assert!(entry.contains("MimeType=x-scheme-handler/roblox-player;x-scheme-handler/roblox;\n"));
+
+        let mimeapps = fs::read_to_string(&handler.mimeapps_file).unwrap();
+        assert!(mimeapps.contains(&format!("{}\n", DEFAULT_SECTION)));
+        assert!(mimeapps.contains(&format!("x-scheme-handler/roblox-player={};\n", DESKTOP_FILE_NAME)));
+        assert!(mimeapps.contains(&format!("x-scheme-handler/roblox={};\n", DESKTOP_FILE_NAME)));
+        assert_eq!(handler.registered_schemes(), SCHEMES.to_vec());
+        assert_eq!(handler.previous_handler("roblox-player"), None);
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn install_saves_previous_handler_and_remove_restores_it() {
+        let home = tempfile::tempdir().unwrap();
+        let (_, handler) = handler(home.path(), "/usr/bin/roblox_booster");
+        let original = format!(
+            "[Added Associations]\ntext/plain=org.gnome.TextEditor.desktop;\n\n\
+             {}\nx-scheme-handler/roblox-player={};\ntext/html=firefox.desktop;\n",
+            DEFAULT_SECTION, SOBER
+        );
+        write_file(&handler.mimeapps_file, &original).unwrap();
+
+        handler.install().unwrap();
+        assert_eq!(handler.previous_handler("roblox-player").as_deref(), Some(SOBER));
+        assert_eq!(handler.previous_handler("roblox"), None);
+        let state = fs::read_to_string(&handler.state_file).unwrap();
+        assert!(state.contains(SOBER));
+
+        // Cài lại không được ghi đè handler cũ bằng chính booster
+        handler.install().unwrap();
+        assert_eq!(handler.previous_handler("roblox-player").as_deref(), Some(SOBER));
+
+        handler.remove().unwrap();
+        let mimeapps = fs::read_to_string(&handler.mimeapps_file).unwrap();
+        assert_eq!(default_handler(&mimeapps, "x-scheme-handler/roblox-player").as_deref(), Some(SOBER));
+        assert_eq!(default_handler(&mimeapps, "x-scheme-handler/roblox"), None);
+        assert!(mimeapps.contains("text/plain=org.gnome.TextEditor.desktop;\n"));
+        assert!(mimeapps.contains("text/html=firefox.desktop;\n"));
+        assert!(!handler.desktop_file.exists());
+        assert!(!handler.state_file.exists());
+        assert!(handler.registered_schemes().is_empty());
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn remove_keeps_handler_chosen_by_user() {
+        let home = tempfile::tempdir().unwrap();
+        let (_, handler) = handler(home.path(), "/usr/bin/roblox_booster");
+        handler.install().unwrap();
+
+        let mimeapps = fs::read_to_string(&handler.mimeapps_file).unwrap();
+        let mimeapps = set_default_handler(&mimeapps, "x-scheme-handler/roblox", Some("other.desktop"));
+        fs::write(&handler.mimeapps_file, mimeapps).unwrap();
+
+        handler.remove().unwrap();
+        let mimeapps = fs::read_to_string(&handler.mimeapps_file).unwrap();
+        assert_eq!(default_handler(&mimeapps, "x-scheme-handler/roblox").as_deref(), Some("other.desktop"));
+        assert_eq!(default_handler(&mimeapps, "x-scheme-handler/roblox-player"), None);
+    }
+
+    #[test]
+    fn remove_without_files_is_ok() {
+        let home = tempfile::tempdir().unwrap();
+        handler(home.path(), "/usr/bin/roblox_booster").1.remove().unwrap();
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn desktop_exec_round_trips_paths_with_spaces_and_percent() {
+        let home = tempfile::tempdir().unwrap();
+        let exec = "/opt/My Apps/100% \"fast\"/roblox_booster";
+        let (app_paths, handler) = handler(home.path(), exec);
+        handler.install().unwrap();
+
+        let uri = "roblox-player:1+launchmode:play+placelauncherurl:https%3A%2F%2Fexample";
+        assert_eq!(
+            find_desktop_file(&app_paths, DESKTOP_FILE_NAME).as_deref(),
+            Some(handler.desktop_file.as_path())
+        );
+        assert_eq!(
+            desktop_exec(&handler.desktop_file, uri),
+            Some(vec![exec.to_string(), "launch".to_string(), uri.to_string()])
+        );
+    }
+
+    #[test]
+    fn desktop_exec_reads_desktop_entry_section_only() {
+        let home = tempfile::tempdir().unwrap();
+        let path = home.path().join(SOBER);
+        fs::write(
+            &path,
+            "[Desktop Action new]\nExec=wrong\n\n\
+             [Desktop Entry]\nName=Sober\nExec=/usr/bin/flatpak run --command=sober org.vinegarhq.Sober -- %u %i\n",
+        )
+        .unwrap();
+        assert_eq!(
+            desktop_exec(&path, "roblox://placeId=1"),
+            Some(vec![
+                "/usr/bin/flatpak".to_string(),
+                "run".to_string(),
+                "--command=sober".to_string(),
+                "org.vinegarhq.Sober".to_string(),
+                "--".to_string(),
+                "roblox://placeId=1".to_string(),
+            ])
+        );
+        assert_eq!(desktop_exec(&home.path().join("missing.desktop"), "roblox:"), None);
+    }
+
+    #[test]
+    fn split_exec_handles_quotes_and_escapes() {
+        assert_eq!(split_exec("a  b\tc"), vec!["a", "b", "c"]);
+        assert_eq!(
+            split_exec(r#""/opt/My Apps/run" "" "say \"hi\" \$HOME \\ \`x\`""#),
+            vec!["/opt/My Apps/run", "", r#"say "hi" $HOME \ `x`"#]
+        );
+        assert!(split_exec("   ").is_empty());
+    }
+}
@@ -0,0 +1,667 @@
+use crate::i18n::t;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysinfo::{Pid, ProcessRefreshKind, System, UpdateKind};
+
+const PLAYER_EXE: &str = "RobloxPlayerBeta.exe";
+/// Đuôi file backup (bản gốc trước lần sửa đầu tiên của booster)
+const BACKUP_SUFFIX: &str = ".roblox_booster.bak";
+/// Đuôi file ghi giá trị gốc của từng registry value booster đã sửa (`user.reg` không backup
+/// cả file: wineserver ghi lại nó liên tục, khôi phục cả file làm mất thay đổi của app khác)
+const REGISTRY_ORIGINALS_SUFFIX: &str = ".roblox_booster.json";
+/// Dòng đầu của `dxvk.conf` do booster tạo (không có backup → gỡ thì xóa file)
+const DXVK_HEADER: &str = "# Generated by roblox_booster";
+const DXVK_CONFIG_NAME: &str = "dxvk.conf";
+const USER_REG_NAME: &str = "user.reg";
+
+/// Kiểu giá trị registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegKind {
+    Dword,
+    String,
+}
+
+/// Registry value được phép sửa (dưới HKCU)
+struct RegistryEntry {
+    /// Tên trong config (`registry` của `WineSettings`)
+    name: &'static str,
+    key: &'static str,
+    value: &'static str,
+    kind: RegKind,
+    /// Giá trị hợp lệ, rỗng = số bất kỳ
+    allowed: &'static [&'static str],
+}
+
+const REGISTRY_WHITELIST: &[RegistryEntry] = &[
+    RegistryEntry {
+        name: "csmt",
+        key: r"Software\Wine\Direct3D",
+        value: "csmt",
+        kind: RegKind::Dword,
+        allowed: &["0", "1"],
+    },
+    RegistryEntry {
+        name: "renderer",
+        key: r"Software\Wine\Direct3D",
+        value: "renderer",
+        kind: RegKind::String,
+        allowed: &["gl", "vulkan", "no3d"],
+    },
+    RegistryEntry {
+        name: "video_memory_size",
+        key: r"Software\Wine\Direct3D",
+        value: "VideoMemorySize",
+        kind: RegKind::String,
+        allowed: &[],
+    },
+    RegistryEntry {
+        name: "mouse_warp_override",
+        key: r"Software\Wine\DirectInput",
+        value: "MouseWarpOverride",
+        kind: RegKind::String,
+        allowed: &["enable", "disable", "force"],
+    },
+    RegistryEntry {
+        name: "grab_fullscreen",
+        key: r"Software\Wine\X11 Driver",
+        value: "GrabFullscreen",
+        kind: RegKind::String,
+        allowed: &["Y", "N"],
+    },
+    RegistryEntry {
+        name: "use_take_focus",
+        key: r"Software\Wine\X11 Driver",
+        value: "UseTakeFocus",
+        kind: RegKind::String,
+        allowed: &["Y", "N"],
+    },
+];
+
+/// Tuning cho Roblox chạy trong Wine, khai báo theo profile
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WineSettings {
+    /// Wine prefix, không có → mọi prefix có Roblox tìm thấy
+    pub prefix: Option<PathBuf>,
+    /// `WINEESYNC` (cần `ulimit -n` cao)
+    pub esync: Option<bool>,
+    /// `WINEFSYNC` (cần kernel hỗ trợ futex_waitv)
+    pub fsync: Option<bool>,
+    /// Option trong `<prefix>/dxvk.conf`, vd `{"dxgi.maxFrameLatency": "1"}`
+    pub dxvk: BTreeMap<String, String>,
+    /// Registry Wine, vd `{"renderer": "vulkan", "csmt": "1"}` (xem `REGISTRY_WHITELIST`)
+    pub registry: BTreeMap<String, String>,
+    /// Biến môi trường thêm khi launch, vd `{"DXVK_ASYNC": "1"}`
+    pub env: BTreeMap<String, String>,
+}
+
+impl WineSettings {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Kiểm tra key/giá trị trước khi ghi gì vào prefix
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in &self.registry {
+            let entry = registry_entry(name).ok_or_else(|| t!("wine-unknown-registry", name = name))?;
+            let valid = if entry.allowed.is_empty() {
+                value.parse::<u32>().is_ok()
+            } else {
+                entry.allowed.contains(&value.as_str())
+            };
+            if !valid || (entry.kind == RegKind::Dword && value.parse::<u32>().is_err()) {
+                return Err(t!("wine-invalid-value", name = name, value = value));
+            }
+        }
+        for (key, value) in &self.dxvk {
+            let key_valid = !key.is_empty()
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+            if !key_valid || value.contains('\n') {
+                return Err(t!("wine-invalid-value", name = key, value = value));
+            }
+        }
+        for key in self.env.keys() {
+            if key.is_empty() || key.contains('=') || key.contains('\0') {
+                return Err(t!("wine-invalid-value", name = key, value = ""));
+            }
+        }
+        Ok(())
+    }
+
+    /// Biến môi trường khi chạy Roblox trong `prefix`
+    pub fn launch_env(&self, prefix: &Path) -> Vec<(String, String)> {
+        let flag = |enabled: bool| if enabled { "1" } else { "0" }.to_string();
+        let mut env = Vec::new();
+        if let Some(esync) = self.esync {
+            env.push(("WINEESYNC".to_string(), flag(esync)));
+        }
+        if let Some(fsync) = self.fsync {
+            env.push(("WINEFSYNC".to_string(), flag(fsync)));
+        }
+        if !self.dxvk.is_empty() {
+            let config = prefix.join(DXVK_CONFIG_NAME);
+            env.push(("DXVK_CONFIG_FILE".to_string(), config.display().to_string()));
+        }
+        env.extend(self.env.iter().map(|(key, value)| (key.clone(), value.clone())));
+        env
+    }
+}
+
+fn registry_entry(name: &str) -> Option<&'static RegistryEntry> {
+    REGISTRY_WHITELIST.iter().find(|entry| entry.name == name)
+}
+
+/// Wine prefix có cài Roblox
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinePrefix {
+    pub path: PathBuf,
+    /// `RobloxPlayerBeta.exe` mới cài nhất
+    pub player: PathBuf,
+}
+
+impl WinePrefix {
+    /// `path` là Wine prefix có Roblox không
+    pub fn open(path: &Path) -> Option<Self> {
+        let drive_c = path.join("drive_c");
+        let mut roots = vec![drive_c.join("Program Files (x86)").join("Roblox")];
+        if let Ok(users) = fs::read_dir(drive_c.join("users")) {
+            roots.extend(users.filter_map(|user| user.ok()).map(|user| {
+                user.path()
+                    .join("AppData")
+                    .join("Local")
+                    .join("Roblox")
+            }));
+        }
+        let player = newest_player(&roots)?;
+        Some(Self {
+            path: path.to_path_buf(),
+            player,
+        })
+    }
+
+    /// Ghi `dxvk.conf` và registry theo `settings`, backup file gốc trước lần sửa đầu tiên
+    ///
+    /// Trả về danh sách file đã sửa.
+    pub fn apply(&self, settings: &WineSettings) -> Result<Vec<PathBuf>, String> {
+        settings.validate()?;
+        // wineserver giữ registry trong RAM và ghi đè `user.reg` khi thoát; `dxvk.conf`
+        // cũng bỏ qua để hai file không lệch nhau → áp dụng lại ở lần launch sau
+        if (!settings.dxvk.is_empty() || !settings.registry.is_empty()) && wineserver_running(&self.path) {
+            return Err(t!("wine-server-running", path = self.path.display()));
+        }
+        let mut changed = Vec::new();
+
+        if !settings.dxvk.is_empty() {
+            let path = self.path.join(DXVK_CONFIG_NAME);
+            let mut contents = format!("{}\n", DXVK_HEADER);
+            for (key, value) in &settings.dxvk {
+                contents.push_str(&format!("{} = {}\n", key, value));
+            }
+            if fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+                backup(&path)?;
+                fs::write(&path, contents).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+                changed.push(path);
+            }
+        }
+
+        if !settings.registry.is_empty() {
+            let path = self.path.join(USER_REG_NAME);
+            let original = fs::read_to_string(&path)
+                .map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+            let mut originals = read_registry_originals(&path)?;
+            let mut contents = original.clone();
+            for (name, value) in &settings.registry {
+                if let Some(entry) = registry_entry(name) {
+                    // Chỉ giữ giá trị trước lần sửa đầu tiên của booster
+                    originals
+                        .entry(name.clone())
+                        .or_insert_with(|| registry_line(&original, entry));
+                    contents = set_registry_value(&contents, entry, value);
+                }
+            }
+            if contents != original {
+                write_registry_originals(&path, &originals)?;
+                fs::write(&path, contents).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+                changed.push(path);
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Khôi phục `dxvk.conf` từ backup (do booster tạo thì xóa) và đưa các registry value
+    /// booster đã sửa về giá trị gốc (phần còn lại của `user.reg` giữ nguyên)
+    ///
+    /// Trả về danh sách file đã khôi phục.
+    pub fn restore(&self) -> Result<Vec<PathBuf>, String> {
+        if self.has_backup() && wineserver_running(&self.path) {
+            return Err(t!("wine-server-running", path = self.path.display()));
+        }
+        let mut restored = Vec::new();
+
+        let path = self.path.join(DXVK_CONFIG_NAME);
+        let backup = backup_path(&path);
+        if backup.is_file() {
+            fs::rename(&backup, &path).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+            restored.push(path);
+        } else if fs::read_to_string(&path).is_ok_and(|contents| contents.starts_with(DXVK_HEADER)) {
+            fs::remove_file(&path).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+            restored.push(path);
+        }
+
+        let path = self.path.join(USER_REG_NAME);
+        let originals_path = registry_originals_path(&path);
+        if originals_path.is_file() {
+            let originals = read_registry_originals(&path)?;
+            let current = fs::read_to_string(&path)
+                .map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+            let mut contents = current.clone();
+            for (name, original) in &originals {
+                if let Some(entry) = registry_entry(name) {
+                    contents = set_registry_line(&contents, entry, original.as_deref());
+                }
+            }
+            if contents != current {
+                fs::write(&path, contents).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+                restored.push(path);
+            }
+            fs::remove_file(&originals_path)
+                .map_err(|err| t!("wine-write-failed", path = originals_path.display(), error = err))?;
+        }
+        Ok(restored)
+    }
+
+    /// Có file nào đang được booster sửa không
+    pub fn has_backup(&self) -> bool {
+        backup_path(&self.path.join(DXVK_CONFIG_NAME)).is_file()
+            || registry_originals_path(&self.path.join(USER_REG_NAME)).is_file()
+            || fs::read_to_string(self.path.join(DXVK_CONFIG_NAME))
+                .is_ok_and(|contents| contents.starts_with(DXVK_HEADER))
+    }
+}
+
+/// Các Wine prefix có Roblox: `$WINEPREFIX`, `~/.wine`, `~/.local/share/wineprefixes/*`,
+/// Bottles và Lutris (`~/Games/*`)
+pub fn discover() -> Vec<WinePrefix> {
+    let mut candidates = Vec::new();
+    if let Some(prefix) = std::env::var_os("WINEPREFIX") {
+        candidates.push(PathBuf::from(prefix));
+    }
+    if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+        candidates.push(home.join(".wine"));
+        let data_home = &crate::paths::get().data_home;
+        for dir in [
+            data_home.join("wineprefixes"),
+            data_home.join("bottles").join("bottles"),
+            home.join("Games"),
+        ] {
+            if let Ok(entries) = fs::read_dir(&dir) {
+                let mut entries: Vec<PathBuf> = entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect();
+                entries.sort();
+                candidates.extend(entries.iter().flat_map(|entry| [entry.clone(), entry.join("prefix")]));
+            }
+        }
+    }
+
+    let mut prefixes: Vec<WinePrefix> = Vec::new();
+    for candidate in candidates {
+        if let Some(prefix) = WinePrefix::open(&candidate) {
+            if !prefixes.iter().any(|known| known.path == prefix.path) {
+                prefixes.push(prefix);
+            }
+        }
+    }
+    prefixes
+}
+
+/// Prefix mà `settings` áp dụng: `settings.prefix` hoặc mọi prefix tìm thấy
+pub fn target_prefixes(settings: &WineSettings) -> Result<Vec<WinePrefix>, String> {
+    match &settings.prefix {
+        Some(path) => WinePrefix::open(path)
+            .map(|prefix| vec![prefix])
+            .ok_or_else(|| t!("wine-prefix-no-roblox", path = path.display())),
+        None => Ok(discover()),
+    }
+}
+
+/// `<root>/Versions/<version>/RobloxPlayerBeta.exe` mới cài nhất
+pub fn newest_player(roots: &[PathBuf]) -> Option<PathBuf> {
+    roots
+        .iter()
+        .filter_map(|root| fs::read_dir(root.join("Versions")).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join(PLAYER_EXE))
+        .filter(|exe| exe.is_file())
+        .max_by_key(|exe| modified(exe))
+}
+
+fn modified(path: &Path) -> SystemTime {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .unwrap_or(UNIX_EPOCH)
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(BACKUP_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Copy file gốc sang backup (chỉ lần đầu, để `restore` về đúng trạng thái trước booster)
+fn backup(path: &Path) -> Result<(), String> {
+    let backup = backup_path(path);
+    if backup.exists() || !path.is_file() {
+        return Ok(());
+    }
+    fs::copy(path, &backup)
+        .map(|_| ())
+        .map_err(|err| t!("wine-write-failed", path = backup.display(), error = err))
+}
+
+fn registry_originals_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(REGISTRY_ORIGINALS_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Giá trị gốc (cả dòng trong `.reg`, `None` = chưa có) của các registry value booster đã sửa, theo tên trong config
+fn read_registry_originals(path: &Path) -> Result<BTreeMap<String, Option<String>>, String> {
+    let originals = registry_originals_path(path);
+    match fs::read_to_string(&originals) {
+        Ok(data) => serde_json::from_str(&data)
+            .map_err(|err| t!("wine-write-failed", path = originals.display(), error = err)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(err) => Err(t!("wine-write-failed", path = originals.display(), error = err)),
+    }
+}
+
+fn write_registry_originals(path: &Path, originals: &BTreeMap<String, Option<String>>) -> Result<(), String> {
+    let path = registry_originals_path(path);
+    let data = serde_json::to_string_pretty(originals)
+        .map_err(|err| t!("wine-write-failed", path = path.display(), error = err))?;
+    fs::write(&path, data).map_err(|err| t!("wine-write-failed", path = path.display(), error = err))
+}
+
+/// wineserver của `prefix` có đang chạy không (wineserver của prefix khác thì bỏ qua)
+///
+/// Khớp theo `WINEPREFIX` trong environ của wineserver hoặc theo thư mục socket: wineserver
+/// chạy trong `/tmp/.wine-<uid>/server-<dev>-<inode>` của prefix.
+fn wineserver_running(prefix: &Path) -> bool {
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessRefreshKind::new()
+            .with_environ(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always),
+    );
+    let target = prefix.canonicalize().unwrap_or_else(|_| prefix.to_path_buf());
+    let server_dir = server_dir_name(prefix);
+    sys.processes()
+        .iter()
+        .filter(|(_, process)| process.name().starts_with("wineserver"))
+        .any(|(pid, process)| {
+            process_prefix(&sys, pid.as_u32()).is_some_and(|prefix| prefix == target)
+                || server_dir.as_deref().is_some_and(|dir| {
+                    process.cwd().and_then(Path::file_name) == Some(std::ffi::OsStr::new(dir))
+                })
+        })
+}
+
+/// Tên thư mục socket wineserver của prefix: `server-<st_dev>-<st_ino>` (hex, như Wine)
+#[cfg(unix)]
+fn server_dir_name(prefix: &Path) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = fs::metadata(prefix).ok()?;
+    Some(format!("server-{:x}-{:x}", metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn server_dir_name(_prefix: &Path) -> Option<String> {
+    None
+}
+
+/// `WINEPREFIX` của process (mặc định `~/.wine`), `None` nếu không đọc được environ
+pub fn process_prefix(sys: &System, pid: u32) -> Option<PathBuf> {
+    environ_prefix(sys.process(Pid::from_u32(pid))?.environ())
+}
+
+/// `WINEPREFIX` trong environ (`KEY=value`), không có thì `$HOME/.wine`; environ rỗng → `None`
+pub fn environ_prefix(environ: &[String]) -> Option<PathBuf> {
+    if environ.is_empty() {
+        return None;
+    }
+    let find = |key: &str| {
+        environ
+            .iter()
+            .find_map(|entry| entry.strip_prefix(key).and_then(|rest| rest.strip_prefix('=')))
+    };
+    find("WINEPREFIX")
+        .map(PathBuf::from)
+        .or_else(|| find("HOME").map(|home| PathBuf::from(home).join(".wine")))
+        .map(|prefix| prefix.canonicalize().unwrap_or(prefix))
+}
+
+/// Header `[key]` của section và tiền tố `"value_name"=` của dòng giá trị
+fn registry_names(entry: &RegistryEntry) -> (String, String) {
+    // Trong file .reg, `\` của tên key được escape thành `\\`
+    (
+        format!("[{}]", entry.key.replace('\\', "\\\\")),
+        format!("\"{}\"=", entry.value),
+    )
+}
+
+/// (vị trí header, cuối section) của section `header`
+fn registry_section(lines: &[String], header: &str) -> Option<(usize, usize)> {
+    let section = lines.iter().position(|existing| {
+        existing
+            .get(..header.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(header))
+    })?;
+    let end = lines[section + 1..]
+        .iter()
+        .position(|existing| existing.starts_with('['))
+        .map_or(lines.len(), |offset| section + 1 + offset);
+    Some((section, end))
+}
+
+/// Dòng giá trị hiện tại của `entry` trong file `.reg`, `None` nếu chưa có
+fn registry_line(contents: &str, entry: &RegistryEntry) -> Option<String> {
+    let (header, prefix) = registry_names(entry);
+    let lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    let (section, end) = registry_section(&lines, &header)?;
+    lines[section + 1..end]
+        .iter()
+        .find(|line| line.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(&prefix)))
+        .cloned()
+}
+
+/// Đặt `"value_name"` trong section `[key]` của file `.reg` của Wine (thêm section nếu chưa có)
+fn set_registry_value(contents: &str, entry: &RegistryEntry, value: &str) -> String {
+    let (_, prefix) = registry_names(entry);
+    let line = match entry.kind {
+        RegKind::Dword => format!("{}dword:{:08x}", prefix, value.parse::<u32>().unwrap_or(0)),
+        RegKind::String => format!("{}\"{}\"", prefix, value.replace('\\', "\\\\").replace('"', "\\\"")),
+    };
+    set_registry_line(contents, entry, Some(&line))
+}
+
+/// Thay dòng giá trị của `entry` bằng `line` (`None` = xóa giá trị)
+fn set_registry_line(contents: &str, entry: &RegistryEntry, line: Option<&str>) -> String {
+    let (header, prefix) = registry_names(entry);
+    let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+    match (registry_section(&lines, &header), line) {
+        (Some((section, end)), line) => {
+            let existing = (section + 1..end).find(|&i| {
+                lines[i]
+                    .get(..prefix.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(&prefix))
+            });
+            match (existing, line) {
+                (Some(i), Some(line)) => lines[i] = line.to_string(),
+                (Some(i), None) => {
+                    lines.remove(i);
+                }
+                (None, Some(line)) => {
+                    let mut insert = end;
+                    while insert > section + 1 && lines[insert - 1].trim().is_empty() {
+                        insert -= 1;
+                    }
+                    lines.insert(insert, line.to_string());
+                }
+                (None, None) => return contents.to_string(),
+            }
+        }
+        (None, Some(line)) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+            if lines.last().is_some_and(|last| !last.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("{} {}", header, now));
+            lines.push(line.to_string());
+        }
+        (None, None) => return contents.to_string(),
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_REG: &str = "WINE REGISTRY Version 2\n\
+                            ;; All keys relative to \\\\User\\\\S-1-5-21-0-0-0-1000\n\
+                            \n\
+                            [Software\\\\Wine\\\\Direct3D] 1700000000\n\
+                            #time=1da0000000000000\n\
+                            \"csmt\"=dword:00000000\n\
+                            \"OffscreenRenderingMode\"=\"fbo\"\n\
+                            \n\
+                            [Software\\\\Wine\\\\Fonts] 1700000000\n\
+                            \"Codepages\"=\"1252,437\"\n";
+
+    fn entry(name: &str) -> &'static RegistryEntry {
+        registry_entry(name).unwrap()
+    }
+
+    fn prefix(dir: &Path) -> WinePrefix {
+        WinePrefix {
+            path: dir.to_path_buf(),
+            player: dir.join(PLAYER_EXE),
+        }
+    }
+
+    fn settings(registry: &[(&str, &str)], dxvk: &[(&str, &str)]) -> WineSettings {
+        let map = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+        WineSettings {
+            registry: map(registry),
+            dxvk: map(dxvk),
+            ..WineSettings::default()
+        }
+    }
+
+    #[test]
+    fn replaces_and_inserts_values_in_existing_section() {
+        let contents = set_registry_value(USER_REG, entry("csmt"), "1");
+        assert!(contents.contains("\"csmt\"=dword:00000001\n"));
+        assert!(!contents.contains("dword:00000000"));
+
+        let contents = set_registry_value(&contents, entry("renderer"), "vulkan");
+        let direct3d = contents.find("[Software\\\\Wine\\\\Direct3D]").unwrap();
+        let fonts = contents.find("[Software\\\\Wine\\\\Fonts]").unwrap();
+        let renderer = contents.find("\"renderer\"=\"vulkan\"").unwrap();
+        // Thêm vào cuối section, trước dòng trống ngăn cách
+        assert!(direct3d < renderer && renderer < fonts);
+        assert!(contents.contains("\"renderer\"=\"vulkan\"\n\n[Software\\\\Wine\\\\Fonts]"));
+        assert!(contents.contains("\"Codepages\"=\"1252,437\""));
+    }
+
+    #[test]
+    fn appends_missing_section_with_escaped_key() {
+        let contents = set_registry_value(USER_REG, entry("grab_fullscreen"), "Y");
+        let section = contents
+            .lines()
+            .find(|line| line.starts_with("[Software\\\\Wine\\\\X11 Driver] "))
+            .expect("section header");
+        assert!(section["[Software\\\\Wine\\\\X11 Driver] ".len()..].parse::<u64>().is_ok());
+        assert!(contents.ends_with("\"GrabFullscreen\"=\"Y\"\n"));
+        assert_eq!(registry_line(&contents, entry("grab_fullscreen")).as_deref(), Some("\"GrabFullscreen\"=\"Y\""));
+    }
+
+    #[test]
+    fn formats_dwords_as_hex() {
+        let contents = set_registry_value("", entry("csmt"), "1");
+        assert!(contents.contains("\"csmt\"=dword:00000001"));
+        let contents = set_registry_value(USER_REG, entry("video_memory_size"), "4096");
+        assert!(contents.contains("\"VideoMemorySize\"=\"4096\""));
+        let dword = RegistryEntry {
+            name: "test",
+            key: r"Software\Wine\Direct3D",
+            value: "MaxVersionGL",
+            kind: RegKind::Dword,
+            allowed: &[],
+        };
+        assert!(set_registry_value(USER_REG, &dword, "255").contains("\"MaxVersionGL\"=dword:000000ff"));
+    }
+
+    #[test]
+    fn restore_reverts_only_changed_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let user_reg = dir.path().join(USER_REG_NAME);
+        fs::write(&user_reg, USER_REG).unwrap();
+        let prefix = prefix(dir.path());
+
+        let changed = prefix.apply(&settings(&[("csmt", "1"), ("renderer", "vulkan")], &[])).unwrap();
+        assert_eq!(changed, vec![user_reg.clone()]);
+        assert!(prefix.has_backup());
+        // Áp dụng lại không ghi đè giá trị gốc đã lưu
+        prefix.apply(&settings(&[("csmt", "0")], &[])).unwrap();
+
+        // wineserver (Roblox, app khác) ghi thêm vào user.reg sau khi booster sửa
+        let rewritten = fs::read_to_string(&user_reg)
+            .unwrap()
+            .replace("\"Codepages\"=\"1252,437\"", "\"Codepages\"=\"1252,437,850\"");
+        fs::write(&user_reg, rewritten).unwrap();
+
+        assert_eq!(prefix.restore().unwrap(), vec![user_reg.clone()]);
+        let restored = fs::read_to_string(&user_reg).unwrap();
+        assert!(restored.contains("\"csmt\"=dword:00000000"));
+        assert!(!restored.contains("\"renderer\""));
+        assert!(restored.contains("\"Codepages\"=\"1252,437,850\""));
+        assert!(!prefix.has_backup());
+        assert!(prefix.restore().unwrap().is_empty());
+    }
+
+    #[test]
+    fn restore_deletes_generated_dxvk_conf_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let prefix = prefix(dir.path());
+        let dxvk = dir.path().join(DXVK_CONFIG_NAME);
+
+        prefix.apply(&settings(&[], &[("dxgi.maxFrameLatency", "1")])).unwrap();
+        assert!(fs::read_to_string(&dxvk).unwrap().starts_with(DXVK_HEADER));
+        assert_eq!(prefix.restore().unwrap(), vec![dxvk.clone()]);
+        assert!(!dxvk.exists());
+
+        fs::write(&dxvk, "dxgi.syncInterval = 0\n").unwrap();
+        assert!(!prefix.has_backup());
+        assert!(prefix.restore().unwrap().is_empty());
+        assert_eq!(fs::read_to_string(&dxvk).unwrap(), "dxgi.syncInterval = 0\n");
+
+        // File của user bị booster ghi đè → khôi phục từ backup
+        prefix.apply(&settings(&[], &[("dxgi.maxFrameLatency", "1")])).unwrap();
+        prefix.restore().unwrap();
+        assert_eq!(fs::read_to_string(&dxvk).unwrap(), "dxgi.syncInterval = 0\n");
+    }
+}